@@ -707,34 +707,63 @@ fn make_core_code(central_items: &CentralItems) -> TokenStream {
         ..
     } = central_items;
 
-    // TODO impl Clone, Debug, PartialEq, PartialOrd, Hash for VariantDispatch
-    // TODO could use try_to().unwrap_unchecked(), since type is already verified. Also directly overload from_variant().
-    // But this requires that all the variant types support this
+    // Object and Array are dispatched by hand: objects may be null, and arrays may be typed.
+    let (value_enumerators_pascal, value_enumerators_rust): (Vec<_>, Vec<_>) =
+        variant_ty_enumerators_pascal
+            .iter()
+            .zip(variant_ty_enumerators_rust)
+            .filter(|(pascal, _)| *pascal != "Object" && *pascal != "Array")
+            .unzip();
+
     quote! {
         use crate::builtin::*;
+        use crate::builtin::meta::ToGodot;
         use crate::engine::Object;
         use crate::obj::Gd;
 
-        #[allow(dead_code)]
+        /// Owned Rust view of a [`Variant`], with one enumerator per [`VariantType`].
+        ///
+        /// Obtained through [`Variant::dispatch()`]. Allows to inspect variants of unknown type with a single `match`, instead of
+        /// checking [`Variant::get_type()`] and converting separately. Convert back with `Variant::from(dispatch)`.
+        ///
+        /// Unlike `get_type()`, a variant holding a null object is dispatched as `Object(None)`, not `Nil`. Typed arrays are
+        /// dispatched as `Array`, sharing their data with the variant.
+        #[derive(Clone, PartialEq, Debug)]
         pub enum VariantDispatch {
             Nil,
             #(
-                #variant_ty_enumerators_pascal(#variant_ty_enumerators_rust),
+                #value_enumerators_pascal(#value_enumerators_rust),
             )*
+            Object(Option<Gd<Object>>),
+            Array(VariantArray),
         }
 
-        #[cfg(FALSE)]
-        impl FromVariant for VariantDispatch {
-            fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-                let dispatch = match variant.get_type() {
+        impl VariantDispatch {
+            pub(crate) fn from_variant(variant: &Variant) -> Self {
+                // Raw type, so that null objects are not mapped to `Nil`.
+                match VariantType::from_sys(variant.sys_type()) {
                     VariantType::Nil => Self::Nil,
                     #(
-                        VariantType::#variant_ty_enumerators_pascal
-                            => Self::#variant_ty_enumerators_pascal(variant.to::<#variant_ty_enumerators_rust>()),
+                        VariantType::#value_enumerators_pascal
+                            => Self::#value_enumerators_pascal(variant.to::<#value_enumerators_rust>()),
                     )*
-                };
+                    // Conversion only fails for null objects.
+                    VariantType::Object => Self::Object(variant.try_to::<Gd<Object>>().ok()),
+                    VariantType::Array => Self::Array(VariantArray::from_variant_untyped(variant)),
+                }
+            }
+        }
 
-                Ok(dispatch)
+        impl From<VariantDispatch> for Variant {
+            fn from(dispatch: VariantDispatch) -> Self {
+                match dispatch {
+                    VariantDispatch::Nil => Variant::nil(),
+                    #(
+                        VariantDispatch::#value_enumerators_pascal(value) => value.to_variant(),
+                    )*
+                    VariantDispatch::Object(object) => object.to_variant(),
+                    VariantDispatch::Array(array) => array.to_variant(),
+                }
             }
         }
 
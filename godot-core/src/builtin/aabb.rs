@@ -223,7 +223,19 @@ impl Aabb {
     /// Returns the index of the shortest axis of the AABB (according to Vector3::AXIS* enum).
     #[inline]
     pub fn shortest_axis_index(&self) -> Vector3Axis {
-        self.size.min_axis_index()
+        // Like Godot, ties resolve to the first axis; this differs from `Vector3::min_axis_index()`.
+        let mut axis = Vector3Axis::X;
+        let mut min_size = self.size.x;
+
+        if self.size.y < min_size {
+            axis = Vector3Axis::Y;
+            min_size = self.size.y;
+        }
+        if self.size.z < min_size {
+            axis = Vector3Axis::Z;
+        }
+
+        axis
     }
 
     /// Returns the scalar length of the shortest axis of the AABB.
@@ -238,11 +250,9 @@ impl Aabb {
         let half_extents = self.size * 0.5;
         let relative_center_point = self.position + half_extents;
 
-        let signs = Vector3 {
-            x: dir.x.signum(),
-            y: dir.y.signum(),
-            z: dir.z.signum(),
-        };
+        // Like Godot, zero components select the negative side (unlike `signum()`, which would return 1 for +0.0).
+        let sign = |component: real| if component > 0.0 { 1.0 } else { -1.0 };
+        let signs = Vector3::new(sign(dir.x), sign(dir.y), sign(dir.z));
 
         half_extents * signs + relative_center_point
     }
@@ -263,6 +273,7 @@ impl Aabb {
             && self.position.y <= end_b.y
             && end.y >= b.position.y
             && self.position.z <= end_b.z
+            && end.z >= b.position.z
     }
 
     /// Checks whether two AABBs have at least one _inner_ point in common (not on the borders).
@@ -331,7 +342,8 @@ impl Aabb {
         let tnear = t1.x.max(t1.y).max(t1.z);
         let tfar = t2.y.min(t2.x).min(t2.z);
 
-        tnear <= tfar
+        // The ray starts at `from`, so a box entirely behind it is not hit.
+        tnear <= tfar && tfar >= 0.0
     }
 
     /// Returns `true` if the given ray intersects with this AABB. Segment length is finite.
@@ -443,6 +455,31 @@ mod test {
         assert_eq!(aabb.longest_axis_size(), 8.0);
         assert_eq!(aabb.shortest_axis_index(), Vector3Axis::X);
         assert_eq!(aabb.longest_axis_index(), Vector3Axis::Z);
+
+        // Ties resolve to the first axis.
+        let flat = Aabb::new(Vector3::ZERO, Vector3::new(2.0, 2.0, 8.0));
+        assert_eq!(flat.shortest_axis_index(), Vector3Axis::X);
+        assert_eq!(flat.shortest_axis(), Vector3::RIGHT);
+    }
+
+    #[test]
+    fn test_support() {
+        let aabb = Aabb {
+            position: Vector3::new(1.0, 2.0, 3.0),
+            size: Vector3::new(4.0, 4.0, 4.0),
+        };
+
+        assert_eq!(
+            aabb.support(Vector3::new(1.0, -1.0, 1.0)),
+            Vector3::new(5.0, 2.0, 7.0)
+        );
+
+        // Zero components select the negative side, like Godot.
+        assert_eq!(aabb.support(Vector3::ZERO), aabb.position);
+        assert_eq!(
+            aabb.support(Vector3::new(0.0, 1.0, -0.0)),
+            Vector3::new(1.0, 6.0, 3.0)
+        );
     }
 
     #[test]
@@ -489,6 +526,14 @@ mod test {
 
         // Check for intersection with same AABB including border
         assert!(aabb1.intersects(&aabb1));
+
+        // Check for non-intersection when only separated along Z
+        let aabb5 = Aabb {
+            position: Vector3::new(0.0, 0.0, 5.0),
+            size: Vector3::new(4.0, 4.0, 4.0),
+        };
+        assert!(!aabb1.intersects(&aabb5));
+        assert!(!aabb5.intersects(&aabb1));
     }
 
     #[test]
@@ -589,6 +634,11 @@ mod test {
         let from6 = Vector3::new(1.0, 2.0, 1.0);
         let dir6 = Vector3::new(0.0, -1.0, 0.0);
         assert!(aabb6.intersects_ray(from6, dir6));
+
+        // Test case 7: Ray points away from the AABB
+        let from7 = Vector3::new(1.0, 1.0, -1.0);
+        let dir7 = Vector3::new(0.0, 0.0, -1.0);
+        assert!(!aabb1.intersects_ray(from7, dir7));
     }
 
     #[test]
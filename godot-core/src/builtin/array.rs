@@ -8,7 +8,8 @@ use godot_ffi as sys;
 
 use crate::builtin::*;
 use crate::obj::Share;
-use crate::property::{Export, Property, PropertyHintInfo, TypeStringHint};
+use crate::property::{Export, ExportElements, Property, PropertyHintInfo, TypeStringHint};
+use std::cmp::Ordering;
 use std::fmt;
 use std::marker::PhantomData;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -136,6 +137,16 @@ impl<T: GodotType> Array<T> {
         self.as_inner().sort();
     }
 
+    /// Sorts the array using a Godot [`Callable`] as comparator. Equivalent of `sort_custom` in GDScript.
+    ///
+    /// The callable receives two elements and must return `true` if the first one should be placed before the second one.
+    /// To sort with a Rust closure, [`sort_unstable_by()`][Self::sort_unstable_by] is usually more convenient.
+    ///
+    /// Note: like [`sort_unstable()`][Self::sort_unstable], this sort is not stable.
+    pub fn sort_custom(&mut self, func: Callable) {
+        self.as_inner().sort_custom(func);
+    }
+
     /// Shuffles the array such that the items will have a random order. This method uses the
     /// global random number generator common to methods such as `randi`. Call `randomize` to
     /// ensure that a new seed will be used each time if you want non-reproducible shuffling.
@@ -156,6 +167,27 @@ impl<T: GodotType> Array<T> {
         );
     }
 
+    /// Rearranges the elements, such that the new element at position `i` is the old element at position `order[i]`.
+    ///
+    /// `order` must be a permutation of `0..len`. Variants are moved as-is, so no conversions take place and the runtime
+    /// type of a typed array is preserved.
+    fn apply_permutation(&mut self, order: &[usize]) {
+        debug_assert_eq!(order.len(), self.len());
+
+        let reordered: Vec<Variant> = order
+            .iter()
+            // SAFETY: `ptr()` checks bounds, and the variant is cloned before the array is modified.
+            .map(|&index| unsafe { (*self.ptr(index)).clone() })
+            .collect();
+
+        for (index, variant) in reordered.into_iter().enumerate() {
+            // SAFETY: `ptr_mut()` checks bounds; the variant originates from this array, so it has the right type.
+            unsafe {
+                *self.ptr_mut(index) = variant;
+            }
+        }
+    }
+
     /// Returns a pointer to the element at the given index.
     ///
     /// # Panics
@@ -337,11 +369,37 @@ impl<T: GodotType> Array<T> {
     }
 
     /// Checks that the inner array has the correct type set on it for storing elements of type `T`.
-    fn with_checked_type(self) -> Result<Self, VariantConversionError> {
-        if self.type_info() == TypeInfo::of::<T>() {
-            Ok(self)
-        } else {
-            Err(VariantConversionError::BadType)
+    ///
+    /// `variant` is the value this array was converted from, used for error reporting.
+    fn with_checked_type(self, variant: &Variant) -> Result<Self, VariantConversionError> {
+        let type_info = self.type_info();
+        if type_info == TypeInfo::of::<T>() {
+            return Ok(self);
+        }
+
+        // For untyped arrays, point to the first element that does not fit, if any.
+        if !type_info.is_typed() {
+            // SAFETY: every array can be viewed as a `VariantArray`, which is only read here.
+            let untyped = unsafe { self.assume_type::<Variant>() };
+            for (index, element) in untyped.iter_shared().enumerate() {
+                if let Err(err) = T::try_from_variant(&element) {
+                    return Err(err.with_index(index));
+                }
+            }
+        }
+
+        Err(VariantConversionError::bad_type::<Self>(variant))
+    }
+
+    /// Converts an array-typed `variant`, without checking the element type.
+    fn from_variant_unchecked(variant: &Variant) -> Self {
+        debug_assert_eq!(variant.get_type(), VariantType::Array);
+
+        unsafe {
+            sys::from_sys_init_or_init_default::<Self>(|self_ptr| {
+                let array_from_variant = sys::builtin_fn!(array_from_variant);
+                array_from_variant(self_ptr, variant.var_sys());
+            })
         }
     }
 
@@ -454,6 +512,76 @@ impl<T: GodotType + FromGodot> Array<T> {
         })
     }
 
+    /// Sorts the array with a comparator function.
+    ///
+    /// Elements are converted to `T` once and sorted on the Rust side, after which the underlying variants are rearranged.
+    /// This makes it possible to sort by properties that Godot does not know about, e.g. fields of a bound `Gd<T>`.
+    ///
+    /// Note: The sort is not stable, i.e. elements considered equal may have their order changed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut array: Array<i64> = array![3, -4, 1, -2];
+    /// array.sort_unstable_by(|a, b| a.abs().cmp(&b.abs()));
+    /// assert_eq!(array, array![1, -2, 3, -4]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let elements: Vec<T> = self.iter_shared().collect();
+
+        let mut order: Vec<usize> = (0..elements.len()).collect();
+        order.sort_unstable_by(|&a, &b| compare(&elements[a], &elements[b]));
+
+        self.apply_permutation(&order);
+    }
+
+    /// Sorts the array by a key extracted from each element.
+    ///
+    /// Like [`slice::sort_by_key()`], this sort is stable: elements with equal keys keep their relative order.
+    /// The key function may be called multiple times per element.
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let elements: Vec<T> = self.iter_shared().collect();
+
+        let mut order: Vec<usize> = (0..elements.len()).collect();
+        order.sort_by_key(|&index| key(&elements[index]));
+
+        self.apply_permutation(&order);
+    }
+
+    /// Binary searches a sorted array with a comparator function.
+    ///
+    /// The comparator returns whether an element is `Less`, `Equal` or `Greater` than the desired target, and must be
+    /// consistent with the order of the array. Semantics are the same as [`slice::binary_search_by()`]: returns `Ok` with
+    /// the index of a matching element, or `Err` with the index where a matching element could be inserted while
+    /// keeping the order.
+    ///
+    /// Only the elements visited by the search are converted to `T`.
+    pub fn binary_search_by<F>(&self, mut compare: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match compare(&self.get(mid)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
     /// Removes and returns the element at the specified index. Equivalent of `pop_at` in GDScript.
     ///
     /// On large arrays, this method is much slower than `pop_back` as it will move all the array's
@@ -575,6 +703,16 @@ impl<T: GodotType + ToGodot> Array<T> {
     }
 }
 
+impl VariantArray {
+    /// Views any array-typed `variant` as a `VariantArray`, including typed arrays.
+    ///
+    /// The result shares its data with the variant's array and keeps its element type, so writes of mismatching values are
+    /// rejected by Godot.
+    pub(crate) fn from_variant_untyped(variant: &Variant) -> Self {
+        Self::from_variant_unchecked(variant)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Traits
 
@@ -664,9 +802,13 @@ impl<T: GodotType> Clone for Array<T> {
             })
         };
 
+        assert_eq!(
+            array.type_info(),
+            TypeInfo::of::<T>(),
+            "copied array should have same type as original array"
+        );
+
         array
-            .with_checked_type()
-            .expect("copied array should have same type as original array")
     }
 }
 
@@ -727,6 +869,12 @@ impl Export for Array<Variant> {
     }
 }
 
+impl<T: GodotType + TypeStringHint> ExportElements for Array<T> {
+    fn element_variant_type() -> VariantType {
+        T::Ffi::variant_type()
+    }
+}
+
 impl<T: GodotType> Default for Array<T> {
     #[inline]
     fn default() -> Self {
@@ -783,17 +931,10 @@ impl<T: GodotType> GodotFfiVariant for Array<T> {
 
     fn ffi_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
         if variant.get_type() != Self::variant_type() {
-            return Err(VariantConversionError::BadType);
+            return Err(VariantConversionError::bad_type::<Self>(variant));
         }
 
-        let array = unsafe {
-            sys::from_sys_init_or_init_default::<Self>(|self_ptr| {
-                let array_from_variant = sys::builtin_fn!(array_from_variant);
-                array_from_variant(self_ptr, variant.var_sys());
-            })
-        };
-
-        array.with_checked_type()
+        Self::from_variant_unchecked(variant).with_checked_type(variant)
     }
 }
 
@@ -829,6 +970,22 @@ impl<T: GodotType + ToGodot> From<&[T]> for Array<T> {
     }
 }
 
+/// Serializes the elements as a sequence.
+#[cfg(feature = "serde")]
+impl<T: GodotType + serde::Serialize> serde::Serialize for Array<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_shared())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: GodotType + serde::Deserialize<'de>> serde::Deserialize<'de> for Array<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = <Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
 /// Creates a `Array` from an iterator.
 impl<T: GodotType + ToGodot> FromIterator<T> for Array<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
@@ -9,7 +9,7 @@ use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{ApproxEq, FloatExt, GlamConv, GlamType};
 use crate::builtin::real_consts::FRAC_PI_2;
-use crate::builtin::{real, Quaternion, RMat3, RQuat, RVec2, RVec3, Vector3};
+use crate::builtin::{real, Quaternion, RMat3, RVec2, RVec3, Vector3};
 
 use std::cmp::Ordering;
 use std::fmt::Display;
@@ -144,23 +144,55 @@ impl Basis {
     /// The up axis (+Y) points as close to the `up` vector as possible while
     /// staying perpendicular to the forward axis. The resulting Basis is
     /// orthonormalized. The `target` and `up` vectors cannot be zero, and
-    /// cannot be parallel to each other.
+    /// cannot be parallel to each other; like in Godot, an error is printed
+    /// and [`Basis::IDENTITY`] returned in that case.
     ///
     #[cfg(before_api = "4.1")]
     /// _Godot equivalent: `Basis.looking_at()`_
     #[doc(alias = "looking_at")]
     pub fn new_looking_at(target: Vector3, up: Vector3) -> Self {
-        super::inner::InnerBasis::looking_at(target, up)
+        Self::looking_at_impl(target, up, false)
     }
 
     /// If `use_model_front` is true, the +Z axis (asset front) is treated as forward (implies +X is left)
     /// and points toward the target position. By default, the -Z axis (camera forward) is treated as forward
     /// (implies +X is right).
     ///
+    /// If `target` or `up` is zero, or if they are parallel, an error is printed and [`Basis::IDENTITY`] returned.
+    ///
     /// _Godot equivalent: `Basis.looking_at()`_
     #[cfg(since_api = "4.1")]
     pub fn new_looking_at(target: Vector3, up: Vector3, use_model_front: bool) -> Self {
-        super::inner::InnerBasis::looking_at(target, up, use_model_front)
+        Self::looking_at_impl(target, up, use_model_front)
+    }
+
+    fn looking_at_impl(target: Vector3, up: Vector3, use_model_front: bool) -> Self {
+        if target.is_zero_approx() {
+            crate::log::godot_error!("The target vector can't be zero.");
+            return Self::IDENTITY;
+        }
+        if up.is_zero_approx() {
+            crate::log::godot_error!("The up vector can't be zero.");
+            return Self::IDENTITY;
+        }
+
+        let mut v_z = target.normalized();
+        if !use_model_front {
+            v_z = -v_z;
+        }
+
+        let v_x = up.cross(v_z);
+        if v_x.is_zero_approx() {
+            crate::log::godot_error!(
+                "The target vector and up vector can't be parallel to each other."
+            );
+            return Self::IDENTITY;
+        }
+
+        let v_x = v_x.normalized();
+        let v_y = v_z.cross(v_x);
+
+        Self::from_cols(v_x, v_y, v_z)
     }
 
     /// Creates a `[Vector3; 3]` with the columns of the `Basis`.
@@ -173,7 +205,49 @@ impl Basis {
     /// _Godot equivalent: `Basis.get_rotation_quaternion()`_
     #[doc(alias = "get_rotation_quaternion")]
     pub fn to_quat(self) -> Quaternion {
-        RQuat::from_mat3(&self.orthonormalized().to_glam()).to_front()
+        // Translated from Godot's `Basis::get_quaternion()`, instead of using glam: both pick the sign of the quaternion differently,
+        // which matters for methods that rely on the sign (e.g. spherical cubic interpolation).
+        let mut m = self.orthonormalized();
+        if m.determinant() < 0.0 {
+            m = m.scaled(Vector3::new(-1.0, -1.0, -1.0));
+        }
+
+        let rows = m.rows.map(|row| [row.x, row.y, row.z]);
+        let trace = rows[0][0] + rows[1][1] + rows[2][2];
+        let mut temp = [0.0; 4];
+
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt();
+            temp[3] = s * 0.5;
+            let s = 0.5 / s;
+            temp[0] = (rows[2][1] - rows[1][2]) * s;
+            temp[1] = (rows[0][2] - rows[2][0]) * s;
+            temp[2] = (rows[1][0] - rows[0][1]) * s;
+        } else {
+            let i = if rows[0][0] < rows[1][1] {
+                if rows[1][1] < rows[2][2] {
+                    2
+                } else {
+                    1
+                }
+            } else if rows[0][0] < rows[2][2] {
+                2
+            } else {
+                0
+            };
+            let j = (i + 1) % 3;
+            let k = (i + 2) % 3;
+
+            let s = (rows[i][i] - rows[j][j] - rows[k][k] + 1.0).sqrt();
+            temp[i] = s * 0.5;
+            let s = 0.5 / s;
+            temp[3] = (rows[k][j] - rows[j][k]) * s;
+            temp[j] = (rows[j][i] + rows[i][j]) * s;
+            temp[k] = (rows[k][i] + rows[i][k]) * s;
+        }
+
+        let [x, y, z, w] = temp;
+        Quaternion::new(x, y, z, w)
     }
 
     const fn to_rows_array(self) -> [real; 9] {
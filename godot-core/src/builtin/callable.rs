@@ -6,8 +6,10 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{impl_godot_as_self, GodotType, ToGodot};
-use crate::builtin::{inner, StringName, Variant, VariantArray};
+use crate::builtin::meta::{impl_godot_as_self, GodotType, ToGodot, VarcallSignatureTuple};
+use crate::builtin::{
+    inner, StringName, Variant, VariantArray, VariantConversionError, VariantType,
+};
 use crate::engine::Object;
 use crate::obj::mem::Memory;
 use crate::obj::{Gd, GodotClass, InstanceId};
@@ -53,23 +55,6 @@ impl Callable {
         }
     }
 
-    #[cfg(since_api = "4.2")]
-    fn default_callable_custom_info() -> sys::GDExtensionCallableCustomInfo {
-        sys::GDExtensionCallableCustomInfo {
-            callable_userdata: ptr::null_mut(),
-            token: ptr::null_mut(),
-            object_id: 0,
-            call_func: None,
-            is_valid_func: None, // could be customized, but no real use case yet.
-            free_func: None,
-            hash_func: None,
-            equal_func: None,
-            // Op < is only used in niche scenarios and default is usually good enough, see https://github.com/godotengine/godot/issues/81901.
-            less_than_func: None,
-            to_string_func: None,
-        }
-    }
-
     /// Create a callable from a Rust function or closure.
     ///
     /// `name` is used for the string representation of the closure, which helps debugging.
@@ -85,25 +70,132 @@ impl Callable {
     ///     Ok(sum.to_variant())
     /// });
     /// ```
-    #[cfg(since_api = "4.2")]
     pub fn from_fn<F, S>(name: S, rust_function: F) -> Self
     where
         F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
         S: Into<crate::builtin::GString>,
     {
-        let userdata = CallableUserdata {
-            inner: FnWrapper {
-                rust_function,
-                name: name.into(),
-            },
+        let userdata = CallableUserdata::new(FnWrapper {
+            rust_function,
+            name: name.into(),
+            thread_id: None,
+            argument_count: None,
+        });
+
+        let info = CustomCallableInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            call_func: rust_callable_call_fn::<F>,
+            free_func: rust_callable_destroy::<FnWrapper<F>>,
+            hash_func: None,
+            equal_func: None,
+            to_string_func: rust_callable_to_string_named::<F>,
+            argument_count_func: Some(rust_callable_argument_count_fn::<F>),
         };
 
-        let info = sys::GDExtensionCallableCustomInfo {
+        Self::from_custom_info(info)
+    }
+
+    /// Create a callable from a Rust function or closure that is bound to the current thread.
+    ///
+    /// Unlike [`from_fn()`][Self::from_fn], the function does not need to be `Send` or `Sync`. This allows it to capture
+    /// main-thread state such as `Gd<T>` or `Rc<T>` directly, which is typical for signal handlers.
+    ///
+    /// The thread on which the callable is created is recorded. If the callable is invoked from any other thread, the function
+    /// is not executed; instead, a Godot error is printed and the call fails. Likewise, if the last reference to the callable is
+    /// released on another thread, the function is leaked rather than dropped there.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use std::rc::Rc;
+    /// # use std::cell::Cell;
+    /// let presses = Rc::new(Cell::new(0));
+    /// let counter = presses.clone();
+    ///
+    /// let callable = Callable::from_local_fn("on_pressed", move |_args: &[&Variant]| {
+    ///     counter.set(counter.get() + 1);
+    ///     Ok(Variant::nil())
+    /// });
+    /// ```
+    pub fn from_local_fn<F, S>(name: S, rust_function: F) -> Self
+    where
+        F: 'static + FnMut(&[&Variant]) -> Result<Variant, ()>,
+        S: Into<crate::builtin::GString>,
+    {
+        let userdata = CallableUserdata::new(FnWrapper {
+            rust_function,
+            name: name.into(),
+            thread_id: Some(std::thread::current().id()),
+            argument_count: None,
+        });
+
+        let info = CustomCallableInfo {
             callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
-            call_func: Some(rust_callable_call_fn::<F>),
-            free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
-            to_string_func: Some(rust_callable_to_string_named::<F>),
-            ..Self::default_callable_custom_info()
+            call_func: rust_callable_call_fn::<F>,
+            free_func: rust_callable_destroy_local::<F>,
+            hash_func: None,
+            equal_func: None,
+            to_string_func: rust_callable_to_string_named::<F>,
+            argument_count_func: Some(rust_callable_argument_count_fn::<F>),
+        };
+
+        Self::from_custom_info(info)
+    }
+
+    /// Create a callable from a Rust function or closure with typed parameters.
+    ///
+    /// Unlike [`from_fn()`][Self::from_fn], the arguments are converted automatically to the parameter types of `rust_function`,
+    /// and its return value is converted to a `Variant`. Functions with up to 8 parameters are supported; see [`TypedFn`].
+    ///
+    /// If the callable is invoked with the wrong number of arguments, or with an argument that cannot be converted, the function
+    /// is not called. Instead, a [`CallError`] is reported back to Godot, which prints an error and returns `null`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let callable = Callable::from_typed_fn("describe", |name: GString, count: i32| -> GString {
+    ///     format!("{name} x{count}").into()
+    /// });
+    ///
+    /// let result = callable.callv(varray!["apple", 3]);
+    /// assert_eq!(result, "apple x3".to_variant());
+    /// ```
+    pub fn from_typed_fn<F, Sig, S>(name: S, mut rust_function: F) -> Self
+    where
+        F: 'static + Send + Sync + TypedFn<Sig>,
+        Sig: VarcallSignatureTuple,
+        Sig::Ret: ToGodot,
+        S: Into<crate::builtin::GString>,
+    {
+        Self::from_checked_fn(name, Some(Sig::PARAM_COUNT), move |args: &[&Variant]| {
+            let params = Sig::params_from_variants(args)?;
+            Ok(rust_function.invoke(params).to_variant())
+        })
+    }
+
+    /// Like [`from_fn()`][Self::from_fn], but reports a detailed [`CallError`] on failure.
+    ///
+    /// `argument_count` is reported to Godot, if known.
+    fn from_checked_fn<F, S>(name: S, argument_count: Option<usize>, rust_function: F) -> Self
+    where
+        F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, CallError>,
+        S: Into<crate::builtin::GString>,
+    {
+        let userdata = CallableUserdata::new(FnWrapper {
+            rust_function,
+            name: name.into(),
+            thread_id: None,
+            argument_count,
+        });
+
+        let info = CustomCallableInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            call_func: rust_callable_call_checked_fn::<F>,
+            free_func: rust_callable_destroy::<FnWrapper<F>>,
+            hash_func: None,
+            equal_func: None,
+            to_string_func: rust_callable_to_string_named::<F>,
+            argument_count_func: Some(rust_callable_argument_count_fn::<F>),
         };
 
         Self::from_custom_info(info)
@@ -112,28 +204,32 @@ impl Callable {
     /// Create a highly configurable callable from Rust.
     ///
     /// See [`RustCallable`] for requirements on the type.
-    #[cfg(since_api = "4.2")]
     pub fn from_custom<C: RustCallable>(callable: C) -> Self {
         // Could theoretically use `dyn` but would need:
         // - double boxing
         // - a type-erased workaround for PartialEq supertrait (which has a `Self` type parameter and thus is not object-safe)
-        let userdata = CallableUserdata { inner: callable };
+        let userdata = CallableUserdata {
+            bound_arguments_func: Some(rust_callable_bound_arguments::<C>),
+            inner: callable,
+        };
 
-        let info = sys::GDExtensionCallableCustomInfo {
+        let info = CustomCallableInfo {
             callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
-            call_func: Some(rust_callable_call_custom::<C>),
-            free_func: Some(rust_callable_destroy::<C>),
+            call_func: rust_callable_call_custom::<C>,
+            free_func: rust_callable_destroy::<C>,
             hash_func: Some(rust_callable_hash::<C>),
             equal_func: Some(rust_callable_equal::<C>),
-            to_string_func: Some(rust_callable_to_string_display::<C>),
-            ..Self::default_callable_custom_info()
+            to_string_func: rust_callable_to_string_display::<C>,
+            argument_count_func: Some(rust_callable_argument_count_custom::<C>),
         };
 
         Self::from_custom_info(info)
     }
 
-    #[cfg(since_api = "4.2")]
-    fn from_custom_info(mut info: sys::GDExtensionCallableCustomInfo) -> Callable {
+    #[cfg(all(since_api = "4.2", not(since_api = "4.3")))]
+    fn from_custom_info(info: CustomCallableInfo) -> Callable {
+        let mut info = info.into_sys();
+
         // SAFETY: callable_custom_create() is a valid way of creating callables.
         unsafe {
             Callable::from_sys_init(|type_ptr| {
@@ -142,6 +238,24 @@ impl Callable {
         }
     }
 
+    #[cfg(since_api = "4.3")]
+    fn from_custom_info(info: CustomCallableInfo) -> Callable {
+        let mut info = info.into_sys();
+
+        // SAFETY: callable_custom_create2() is a valid way of creating callables.
+        unsafe {
+            Callable::from_sys_init(|type_ptr| {
+                sys::interface_fn!(callable_custom_create2)(type_ptr, ptr::addr_of_mut!(info))
+            })
+        }
+    }
+
+    #[cfg(before_api = "4.2")]
+    fn from_custom_info(info: CustomCallableInfo) -> Callable {
+        // No custom callables in the GDExtension API yet; route calls through a helper object instead.
+        callable_fallback::create_callable(info)
+    }
+
     /// Creates an invalid/empty object that is not able to be called.
     ///
     /// _Godot equivalent: `Callable()`_
@@ -170,6 +284,85 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the method represented by this callable, with arguments given as a slice.
+    ///
+    /// Behaves like [`callv()`][Self::callv] in case of errors.
+    ///
+    /// _Godot equivalent: `call`_
+    pub fn call(&self, arguments: &[Variant]) -> Variant {
+        self.callv(VariantArray::from(arguments))
+    }
+
+    /// Calls the method represented by this callable in deferred mode, i.e. at the end of the current frame.
+    ///
+    /// Returns immediately; the result of the call is discarded.
+    ///
+    /// _Godot equivalent: `call_deferred`_
+    pub fn call_deferred(&self, arguments: &[Variant]) {
+        // Vararg methods of builtin types are not part of the method table, so go through a dynamic call.
+        self.to_variant().call("call_deferred", arguments);
+    }
+
+    /// Returns a copy of this callable with one or more arguments bound.
+    ///
+    /// When called, the bound arguments are passed _after_ the arguments supplied by the caller.
+    ///
+    /// _Godot equivalent: `bindv`_
+    pub fn bind(&self, arguments: &[Variant]) -> Callable {
+        self.as_inner().bindv(VariantArray::from(arguments))
+    }
+
+    /// Returns a copy of this callable with a number of arguments unbound.
+    ///
+    /// When called, the last `argument_count` arguments supplied by the caller are dropped.
+    ///
+    /// _Godot equivalent: `unbind`_
+    pub fn unbind(&self, argument_count: usize) -> Callable {
+        self.as_inner().unbind(argument_count as i64)
+    }
+
+    /// Returns the arguments bound to this callable, either with [`bind()`][Self::bind] or as declared by
+    /// [`RustCallable::bound_arguments()`].
+    ///
+    /// _Godot equivalent: `get_bound_arguments`_
+    pub fn get_bound_arguments(&self) -> VariantArray {
+        let rust_bound = self.rust_userdata().and_then(|userdata| {
+            // SAFETY: userdata of callables created by this library is always a CallableUserdata.
+            unsafe { CallableUserdata::<()>::bound_arguments_from_raw(userdata) }
+        });
+
+        rust_bound.unwrap_or_else(|| self.as_inner().get_bound_arguments())
+    }
+
+    /// Returns the total number of arguments this callable takes, accounting for bound and unbound arguments.
+    ///
+    /// Rust callables report their count through [`RustCallable::argument_count()`], or the signature of
+    /// [`from_typed_fn()`][Self::from_typed_fn]. Returns 0 if the count is not known.
+    ///
+    /// _Godot equivalent: `get_argument_count`_
+    #[cfg(since_api = "4.3")]
+    pub fn get_argument_count(&self) -> usize {
+        self.as_inner().get_argument_count() as usize
+    }
+
+    /// Userdata of a callable that was created from Rust by this library.
+    fn rust_userdata(&self) -> Option<*mut std::ffi::c_void> {
+        #[cfg(since_api = "4.2")]
+        {
+            // SAFETY: self is a valid callable; token identifies callables created by us.
+            let userdata = unsafe {
+                sys::interface_fn!(callable_custom_get_userdata)(self.sys(), callable_token())
+            };
+
+            (!userdata.is_null()).then_some(userdata)
+        }
+
+        #[cfg(before_api = "4.2")]
+        {
+            callable_fallback::userdata(self)
+        }
+    }
+
     /// Returns the name of the method represented by this callable. If the callable is a lambda function,
     /// returns the function's name.
     ///
@@ -218,6 +411,11 @@ impl Callable {
     ///
     /// _Godot equivalent: `hash`_
     pub fn hash(&self) -> u32 {
+        #[cfg(before_api = "4.2")]
+        if let Some(hash) = callable_fallback::hash(self) {
+            return hash;
+        }
+
         self.as_inner().hash().try_into().unwrap()
     }
 
@@ -227,16 +425,22 @@ impl Callable {
     /// custom callables.
     ///
     /// If a callable is not a custom callable, then it is considered a standard callable, this function is
-    /// the opposite of [`Callable.is_standard`].
+    /// the opposite of [`is_standard`][Self::is_standard].
     ///
     /// _Godot equivalent: `is_custom`_
-    ///
-    /// [`Callable.is_standard`]: https://docs.godotengine.org/en/stable/classes/class_callable.html#class-callable-method-is-standard
-    #[doc(alias = "is_standard")]
     pub fn is_custom(&self) -> bool {
         self.as_inner().is_custom()
     }
 
+    /// Returns true if this callable is a standard callable, i.e. refers to an object and a method name.
+    ///
+    /// This is the opposite of [`is_custom`][Self::is_custom].
+    ///
+    /// _Godot equivalent: `is_standard`_
+    pub fn is_standard(&self) -> bool {
+        self.as_inner().is_standard()
+    }
+
     /// Returns true if this callable has no target to call the method on.
     ///
     /// This is not the negated form of [`is_valid`][Self::is_valid], as `is_valid` will return `false` if the callable has a
@@ -273,12 +477,30 @@ impl_builtin_traits! {
         // Equality for custom callables depend on the equality implementation of that custom callable.
         // So we cannot implement `Eq` here and be confident equality will be total for all future custom callables.
         // Godot does not define a less-than operator, so there is no `PartialOrd`.
-        PartialEq => callable_operator_equal;
+        // PartialEq is implemented manually, see below.
         Clone => callable_construct_copy;
         Drop => callable_destroy;
     }
 }
 
+impl PartialEq for Callable {
+    fn eq(&self, other: &Self) -> bool {
+        // Rust callables on older Godot versions are standard callables, for which Godot would only compare the helper objects.
+        #[cfg(before_api = "4.2")]
+        if let Some(result) = callable_fallback::eq(self, other) {
+            return result;
+        }
+
+        unsafe {
+            let mut result = false;
+            sys::builtin_call! {
+                callable_operator_equal(self.sys(), other.sys(), result.sys_mut())
+            };
+            result
+        }
+    }
+}
+
 // SAFETY:
 // The `opaque` in `Callable` is just a pair of pointers, and requires no special initialization or cleanup
 // beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
@@ -323,30 +545,250 @@ impl fmt::Debug for Callable {
 
 impl fmt::Display for Callable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(before_api = "4.2")]
+        if let Some(string) = callable_fallback::to_string(self) {
+            return write!(f, "{string}");
+        }
+
         write!(f, "{}", self.to_variant())
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed Rust functions
+
+/// Rust function or closure with typed parameters, which can be turned into a callable with [`Callable::from_typed_fn()`].
+///
+/// Implemented for all `FnMut` closures and functions with up to 8 parameters. `Sig` is the signature tuple `(Ret, Params...)`,
+/// as used for `#[func]` methods. Closure parameters need type annotations, so that `Sig` can be inferred.
+pub trait TypedFn<Sig: VarcallSignatureTuple> {
+    /// Calls the function, with the parameters unpacked from the tuple `params`.
+    fn invoke(&mut self, params: Sig::Params) -> Sig::Ret;
+}
+
+macro_rules! impl_typed_fn {
+    ($($pn:ident: $Pn:ident),*) => {
+        impl<F, R, $($Pn,)*> TypedFn<(R, $($Pn,)*)> for F
+        where
+            F: FnMut($($Pn),*) -> R,
+            (R, $($Pn,)*): VarcallSignatureTuple<Params = ($($Pn,)*), Ret = R>,
+        {
+            #[inline]
+            fn invoke(&mut self, ($($pn,)*): ($($Pn,)*)) -> R {
+                self($($pn),*)
+            }
+        }
+    };
+}
+
+impl_typed_fn!();
+impl_typed_fn!(p0: P0);
+impl_typed_fn!(p0: P0, p1: P1);
+impl_typed_fn!(p0: P0, p1: P1, p2: P2);
+impl_typed_fn!(p0: P0, p1: P1, p2: P2, p3: P3);
+impl_typed_fn!(p0: P0, p1: P1, p2: P2, p3: P3, p4: P4);
+impl_typed_fn!(p0: P0, p1: P1, p2: P2, p3: P3, p4: P4, p5: P5);
+impl_typed_fn!(p0: P0, p1: P1, p2: P2, p3: P3, p4: P4, p5: P5, p6: P6);
+impl_typed_fn!(p0: P0, p1: P1, p2: P2, p3: P3, p4: P4, p5: P5, p6: P6, p7: P7);
+
+/// Error when a Rust callable is invoked with arguments that don't match its signature.
+///
+/// Reported to Godot as a `GDExtensionCallError`, from which the engine
+/// prints a message such as _"Invalid type in function 'name'. Cannot convert argument 2 from String to int."_
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum CallError {
+    /// Fewer arguments were passed than the function has parameters.
+    TooFewArguments { expected: usize, actual: usize },
+
+    /// More arguments were passed than the function has parameters.
+    TooManyArguments { expected: usize, actual: usize },
+
+    /// The argument at (0-based) `index` could not be converted to the parameter type.
+    InvalidArgument {
+        index: usize,
+        expected: VariantType,
+        error: VariantConversionError,
+    },
+}
+
+impl CallError {
+    pub(crate) fn check_arg_count(expected: usize, actual: usize) -> Result<(), Self> {
+        if actual < expected {
+            Err(Self::TooFewArguments { expected, actual })
+        } else if actual > expected {
+            Err(Self::TooManyArguments { expected, actual })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Converts to the FFI representation, which Godot uses to print the error.
+    pub(crate) fn to_sys(&self) -> sys::GDExtensionCallError {
+        let (error, argument, expected) = match self {
+            Self::TooFewArguments { expected, .. } => (
+                sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS,
+                -1,
+                *expected as i32,
+            ),
+            Self::TooManyArguments { expected, .. } => (
+                sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS,
+                -1,
+                *expected as i32,
+            ),
+            Self::InvalidArgument {
+                index, expected, ..
+            } => (
+                sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT,
+                *index as i32,
+                expected.sys() as i32,
+            ),
+        };
+
+        sys::GDExtensionCallError {
+            error,
+            argument,
+            expected,
+        }
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewArguments { expected, actual } => {
+                write!(f, "too few arguments: expected {expected}, got {actual}")
+            }
+            Self::TooManyArguments { expected, actual } => {
+                write!(f, "too many arguments: expected {expected}, got {actual}")
+            }
+            Self::InvalidArgument {
+                index,
+                expected,
+                error,
+            } => write!(
+                f,
+                "cannot convert argument #{} to {expected:?}: {error}",
+                index + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CallError {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Callbacks for custom implementations
 
-#[cfg(since_api = "4.2")]
 use custom_callable::*;
 
-#[cfg(since_api = "4.2")]
 pub use custom_callable::RustCallable;
 
-#[cfg(since_api = "4.2")]
 mod custom_callable {
     use super::*;
     use crate::builtin::GString;
     use std::hash::Hash;
+    use std::thread::ThreadId;
 
+    type CallFn = unsafe extern "C" fn(
+        *mut std::ffi::c_void,
+        *const sys::GDExtensionConstVariantPtr,
+        sys::GDExtensionInt,
+        sys::GDExtensionVariantPtr,
+        *mut sys::GDExtensionCallError,
+    );
+    type FreeFn = unsafe extern "C" fn(*mut std::ffi::c_void);
+    type HashFn = unsafe extern "C" fn(*mut std::ffi::c_void) -> u32;
+    type EqualFn =
+        unsafe extern "C" fn(*mut std::ffi::c_void, *mut std::ffi::c_void) -> sys::GDExtensionBool;
+    type ToStringFn = unsafe extern "C" fn(
+        *mut std::ffi::c_void,
+        *mut sys::GDExtensionBool,
+        sys::GDExtensionStringPtr,
+    );
+    type ArgumentCountFn = unsafe extern "C" fn(
+        *mut std::ffi::c_void,
+        *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionInt;
+    type BoundArgumentsFn = unsafe fn(*mut std::ffi::c_void) -> VariantArray;
+
+    /// Callbacks and userdata of a callable implemented in Rust.
+    ///
+    /// Mirrors `GDExtensionCallableCustomInfo`, which is only available since Godot 4.2. Before that, the same callbacks are
+    /// invoked by a helper object (see `callable_fallback` module).
+    pub(crate) struct CustomCallableInfo {
+        pub callable_userdata: *mut std::ffi::c_void,
+        pub call_func: CallFn,
+        pub free_func: FreeFn,
+        pub hash_func: Option<HashFn>,
+        pub equal_func: Option<EqualFn>,
+        pub to_string_func: ToStringFn,
+
+        // Only supported by Godot 4.3+.
+        #[cfg_attr(not(since_api = "4.3"), allow(dead_code))]
+        pub argument_count_func: Option<ArgumentCountFn>,
+    }
+
+    #[cfg(all(since_api = "4.2", not(since_api = "4.3")))]
+    type CustomCallableInfoSys = sys::GDExtensionCallableCustomInfo;
+    #[cfg(since_api = "4.3")]
+    type CustomCallableInfoSys = sys::GDExtensionCallableCustomInfo2;
+
+    impl CustomCallableInfo {
+        #[cfg(since_api = "4.2")]
+        pub fn into_sys(self) -> CustomCallableInfoSys {
+            CustomCallableInfoSys {
+                callable_userdata: self.callable_userdata,
+                token: callable_token(),
+                object_id: 0,
+                call_func: Some(self.call_func),
+                is_valid_func: None, // could be customized, but no real use case yet.
+                free_func: Some(self.free_func),
+                hash_func: self.hash_func,
+                equal_func: self.equal_func,
+                // Op < is only used in niche scenarios and default is usually good enough, see https://github.com/godotengine/godot/issues/81901.
+                less_than_func: None,
+                to_string_func: Some(self.to_string_func),
+                #[cfg(since_api = "4.3")]
+                get_argument_count_func: self.argument_count_func,
+            }
+        }
+    }
+
+    /// Token identifying callables created by this library, see `callable_custom_get_userdata()`.
+    #[cfg(since_api = "4.2")]
+    pub(crate) fn callable_token() -> *mut std::ffi::c_void {
+        sys::get_library() as *mut std::ffi::c_void
+    }
+
+    #[repr(C)] // bound_arguments_func must be the first field, see bound_arguments_from_raw().
     pub struct CallableUserdata<T> {
+        /// Type-erased access to [`RustCallable::bound_arguments()`]; `None` for functions and closures.
+        pub bound_arguments_func: Option<BoundArgumentsFn>,
         pub inner: T,
     }
 
+    impl CallableUserdata<()> {
+        /// Returns the bound arguments of a Rust callable, if it declares any.
+        ///
+        /// # Safety
+        /// `void_ptr` must be a valid pointer to a `CallableUserdata<T>`, for any `T`.
+        pub(crate) unsafe fn bound_arguments_from_raw(
+            void_ptr: *mut std::ffi::c_void,
+        ) -> Option<VariantArray> {
+            // Reading the first field is valid regardless of T, due to #[repr(C)].
+            let func = *(void_ptr as *const Option<BoundArgumentsFn>);
+            func.map(|func| func(void_ptr))
+        }
+    }
+
     impl<T> CallableUserdata<T> {
+        pub fn new(inner: T) -> Self {
+            Self {
+                bound_arguments_func: None,
+                inner,
+            }
+        }
+
         /// # Safety
         /// Returns an unbounded reference. `void_ptr` must be a valid pointer to a `CallableUserdata`.
         unsafe fn inner_from_raw<'a>(void_ptr: *mut std::ffi::c_void) -> &'a mut T {
@@ -358,11 +800,48 @@ mod custom_callable {
     pub(crate) struct FnWrapper<F> {
         pub(crate) rust_function: F,
         pub(crate) name: GString,
+
+        /// Thread the function is confined to; `None` if it is `Send + Sync` and can be used from any thread.
+        pub(crate) thread_id: Option<ThreadId>,
+
+        /// Number of parameters, if known.
+        pub(crate) argument_count: Option<usize>,
+    }
+
+    impl<F> FnWrapper<F> {
+        /// Whether the function may be accessed from the current thread.
+        fn is_on_owning_thread(&self) -> bool {
+            self.thread_id
+                .map_or(true, |id| id == std::thread::current().id())
+        }
+
+        /// Checks that the function may be invoked from the current thread; otherwise prints an error and returns `false`.
+        fn check_invoke_thread(&self) -> bool {
+            let ok = self.is_on_owning_thread();
+            if !ok {
+                crate::log::godot_error!(
+                    "Callable `{}` was created with Callable::from_local_fn() and cannot be called from another thread",
+                    self.name
+                );
+            }
+
+            ok
+        }
+    }
+
+    /// Writes the error for a call on a thread that doesn't own the function.
+    ///
+    /// # Safety
+    /// It must be safe to write a `sys::GDExtensionCallError` once to `r_error`.
+    unsafe fn write_wrong_thread_error(r_error: *mut sys::GDExtensionCallError) {
+        *r_error = sys::default_call_error();
+        (*r_error).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD;
     }
 
     /// Represents a custom callable object defined in Rust.
     ///
-    /// This trait has a single method, `invoke`, which is called upon invocation.
+    /// The only required method is `invoke`, which is called upon invocation. The other methods provide information for
+    /// introspection through `Callable`, and have default implementations.
     ///
     /// Since callables can be invoked from anywhere, they must be self-contained (`'static`) and thread-safe (`Send + Sync`).
     /// They also should implement `Display` for the Godot string representation.
@@ -373,6 +852,20 @@ mod custom_callable {
         /// Return `Ok(...)` if the call succeeded, and `Err(())` otherwise.
         /// Error handling is mostly needed in case argument number or types mismatch.
         fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()>;
+
+        /// Number of arguments that the callable expects, or `None` if it accepts a variable number (the default).
+        ///
+        /// Reported by [`Callable::get_argument_count()`], which is only available since Godot 4.3.
+        fn argument_count(&self) -> Option<usize> {
+            None
+        }
+
+        /// Arguments which are already bound to this callable, and which `invoke` appends to the passed ones.
+        ///
+        /// Reported by [`Callable::get_bound_arguments()`]. By default, no arguments are bound.
+        fn bound_arguments(&self) -> VariantArray {
+            VariantArray::new()
+        }
     }
 
     pub unsafe extern "C" fn rust_callable_call_custom<C: RustCallable>(
@@ -400,20 +893,98 @@ mod custom_callable {
     ) where
         F: FnMut(&[&Variant]) -> Result<Variant, ()>,
     {
+        let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+        if !w.check_invoke_thread() {
+            write_wrong_thread_error(r_error);
+            return;
+        }
+
         let arg_refs: &[&Variant] =
             Variant::unbounded_refs_from_sys(p_args, p_argument_count as usize);
 
-        let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
-
         let result = (w.rust_function)(arg_refs);
         crate::builtin::meta::varcall_return_checked(result, r_return, r_error);
     }
 
+    pub unsafe extern "C" fn rust_callable_call_checked_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: FnMut(&[&Variant]) -> Result<Variant, CallError>,
+    {
+        let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+        if !w.check_invoke_thread() {
+            write_wrong_thread_error(r_error);
+            return;
+        }
+
+        let arg_refs: &[&Variant] =
+            Variant::unbounded_refs_from_sys(p_args, p_argument_count as usize);
+
+        match (w.rust_function)(arg_refs) {
+            Ok(ret_val) => crate::builtin::meta::varcall_return(ret_val, r_return, r_error),
+            Err(call_error) => *r_error = call_error.to_sys(),
+        }
+    }
+
+    pub unsafe extern "C" fn rust_callable_argument_count_custom<C: RustCallable>(
+        callable_userdata: *mut std::ffi::c_void,
+        r_is_valid: *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionInt {
+        let c: &C = CallableUserdata::inner_from_raw(callable_userdata);
+
+        write_argument_count(c.argument_count(), r_is_valid)
+    }
+
+    pub unsafe extern "C" fn rust_callable_argument_count_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        r_is_valid: *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionInt {
+        let w: &FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+
+        write_argument_count(w.argument_count, r_is_valid)
+    }
+
+    unsafe fn write_argument_count(
+        count: Option<usize>,
+        r_is_valid: *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionInt {
+        *r_is_valid = count.is_some() as sys::GDExtensionBool;
+        count.unwrap_or(0) as sys::GDExtensionInt
+    }
+
+    unsafe fn rust_callable_bound_arguments<C: RustCallable>(
+        callable_userdata: *mut std::ffi::c_void,
+    ) -> VariantArray {
+        let c: &C = CallableUserdata::inner_from_raw(callable_userdata);
+
+        c.bound_arguments()
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
     }
 
+    pub unsafe extern "C" fn rust_callable_destroy_local<F>(
+        callable_userdata: *mut std::ffi::c_void,
+    ) {
+        let rust_ptr = callable_userdata as *mut CallableUserdata<FnWrapper<F>>;
+
+        if (*rust_ptr).inner.is_on_owning_thread() {
+            let _drop = Box::from_raw(rust_ptr);
+        } else {
+            // Dropping a thread-confined function elsewhere could race with state it shares with its thread (e.g. Rc).
+            crate::log::godot_error!(
+                "Callable `{}` was created with Callable::from_local_fn() and released on another thread; leaking it",
+                (*rust_ptr).inner.name
+            );
+        }
+    }
+
     pub unsafe extern "C" fn rust_callable_hash<T: Hash>(
         callable_userdata: *mut std::ffi::c_void,
     ) -> u32 {
@@ -456,3 +1027,220 @@ mod custom_callable {
         *r_is_valid = true as sys::GDExtensionBool;
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Fallback for Godot versions without custom callables
+
+/// Rust callables for Godot 4.0 and 4.1.
+///
+/// `callable_custom_create()` is only available since Godot 4.2. Before that, each Rust callable is backed by an instance of an
+/// internal `RefCounted` class, which owns the [`CustomCallableInfo`] and exposes a vararg method that forwards to `call_func`.
+/// A standard callable only stores the object's ID, so the helper object is additionally bound as the last argument. The
+/// resulting (custom) callable holds a reference to the helper and frees it, together with the Rust callable, when dropped.
+///
+/// On the Rust side, `hash()`, `==` and `to_string()` recognize such callables and use the same callbacks as custom callables
+/// do on Godot 4.2+. Godot itself sees a bound callable, so `object()` and `method_name()` reflect the helper.
+#[cfg(before_api = "4.2")]
+pub(crate) mod callable_fallback {
+    use super::*;
+    use crate::builtin::meta::registration::method::MethodInfo;
+    use crate::builtin::meta::ClassName;
+    use crate::builtin::GString;
+    use crate::engine::global::MethodFlags;
+    use crate::engine::RefCounted;
+    use crate::init::InitLevel;
+    use sys::interface_fn;
+
+    const CLASS_NAME: &[u8] = b"GdextRustCallable\0";
+    const METHOD_NAME: &str = "invoke";
+
+    fn class_name() -> ClassName {
+        ClassName::from_ascii_cstr(CLASS_NAME)
+    }
+
+    /// Instance of the helper class. Owns the Rust callable, which is freed together with the Godot object.
+    struct FallbackInstance {
+        info: CustomCallableInfo,
+    }
+
+    impl Drop for FallbackInstance {
+        fn drop(&mut self) {
+            // SAFETY: userdata was created together with free_func and is not accessed after this point.
+            unsafe { (self.info.free_func)(self.info.callable_userdata) }
+        }
+    }
+
+    /// Registers the helper class with Godot. Called once, when the first initialization level of the library is loaded.
+    pub(crate) fn register_class(init_level: InitLevel) {
+        let godot_params = sys::GDExtensionClassCreationInfo {
+            // Instances are only created from Rust, through create_callable().
+            is_abstract: true as u8,
+            free_instance_func: Some(free_instance),
+            ..crate::registry::default_creation_info()
+        };
+
+        crate::registry::register_internal_class(
+            class_name(),
+            RefCounted::class_name(),
+            godot_params,
+            register_methods,
+            init_level,
+        );
+    }
+
+    fn register_methods(_class_builder: &mut dyn std::any::Any) {
+        // SAFETY: invoke() accepts any number of arguments and always writes a Variant return value (or an error).
+        let method = unsafe {
+            MethodInfo::from_signature::<(Variant,)>(
+                class_name(),
+                StringName::from(METHOD_NAME),
+                Some(invoke),
+                None,
+                MethodFlags::METHOD_FLAGS_DEFAULT | MethodFlags::METHOD_FLAG_VARARG,
+                &[],
+                Vec::new(),
+            )
+        };
+
+        method.register_extension_class_method();
+    }
+
+    pub(crate) fn create_callable(info: CustomCallableInfo) -> Callable {
+        let instance = Box::into_raw(Box::new(FallbackInstance { info })) as *mut std::ffi::c_void;
+        let binding_data_callbacks = crate::storage::nop_instance_callbacks();
+
+        // SAFETY: the object is freshly constructed, and its instance is set exactly once. The instance is released by
+        // free_instance() when the object is destroyed.
+        let object: Gd<RefCounted> = unsafe {
+            let object_ptr =
+                interface_fn!(classdb_construct_object)(RefCounted::class_name().string_sys());
+
+            interface_fn!(object_set_instance)(object_ptr, class_name().string_sys(), instance);
+            interface_fn!(object_set_instance_binding)(
+                object_ptr,
+                sys::get_library() as *mut std::ffi::c_void,
+                instance,
+                &binding_data_callbacks,
+            );
+
+            Gd::from_obj_sys(object_ptr)
+        };
+
+        // Binding the object keeps it alive as long as the callable; invoke() drops this last argument again.
+        Callable::from_object_method(object.clone(), METHOD_NAME).bind(&[object.to_variant()])
+    }
+
+    /// Runs `f` on the helper instance, if `callable` is one created by create_callable().
+    fn with_instance<R>(callable: &Callable, f: impl FnOnce(&FallbackInstance) -> R) -> Option<R> {
+        // Only the helper itself is bound. Callables that bind or unbind further arguments are not Rust callables themselves.
+        // method_name() cannot be used, as Godot 4.0/4.1 print an error when it is called on a custom callable.
+        if !callable.is_custom() || callable.as_inner().get_bound_arguments_count() != 1 {
+            return None;
+        }
+
+        // Keeps the object (and thus the instance) alive during f.
+        let object = callable.object()?;
+        if object.get_class() != GString::from(class_name().as_str()) {
+            return None;
+        }
+
+        let binding_data_callbacks = crate::storage::nop_instance_callbacks();
+
+        // SAFETY: objects of the helper class always have a FallbackInstance as instance binding, see create_callable().
+        let instance = unsafe {
+            let binding = interface_fn!(object_get_instance_binding)(
+                object.obj_sys(),
+                sys::get_library() as *mut std::ffi::c_void,
+                &binding_data_callbacks,
+            );
+
+            &*(binding as *const FallbackInstance)
+        };
+
+        Some(f(instance))
+    }
+
+    pub(crate) fn hash(callable: &Callable) -> Option<u32> {
+        with_instance(callable, |instance| {
+            let hash_func = instance.info.hash_func?;
+
+            // SAFETY: userdata belongs to hash_func.
+            Some(unsafe { hash_func(instance.info.callable_userdata) })
+        })
+        .flatten()
+    }
+
+    pub(crate) fn eq(lhs: &Callable, rhs: &Callable) -> Option<bool> {
+        let lhs_info = with_instance(lhs, |instance| {
+            (instance.info.callable_userdata, instance.info.equal_func)
+        })?;
+        let rhs_info = with_instance(rhs, |instance| {
+            (instance.info.callable_userdata, instance.info.equal_func)
+        })?;
+
+        let result = match (lhs_info, rhs_info) {
+            ((lhs_data, _), (rhs_data, _)) if lhs_data == rhs_data => true,
+
+            // Same equal_func means same Rust type, as it is monomorphized. This mirrors Godot's behavior for custom callables.
+            ((lhs_data, Some(lhs_eq)), (rhs_data, Some(rhs_eq)))
+                if lhs_eq as usize == rhs_eq as usize =>
+            {
+                // SAFETY: both userdata pointers belong to equal_func.
+                unsafe { lhs_eq(lhs_data, rhs_data) != 0 }
+            }
+
+            _ => false,
+        };
+
+        Some(result)
+    }
+
+    pub(crate) fn userdata(callable: &Callable) -> Option<*mut std::ffi::c_void> {
+        with_instance(callable, |instance| instance.info.callable_userdata)
+    }
+
+    pub(crate) fn to_string(callable: &Callable) -> Option<GString> {
+        with_instance(callable, |instance| {
+            let mut is_valid = false as sys::GDExtensionBool;
+
+            // SAFETY: userdata belongs to to_string_func, which initializes the string.
+            unsafe {
+                sys::from_sys_init_or_init_default::<GString>(|string_ptr| {
+                    (instance.info.to_string_func)(
+                        instance.info.callable_userdata,
+                        ptr::addr_of_mut!(is_valid),
+                        string_ptr as sys::GDExtensionStringPtr,
+                    )
+                })
+            }
+        })
+    }
+
+    unsafe extern "C" fn invoke(
+        _method_userdata: *mut std::ffi::c_void,
+        instance: sys::GDExtensionClassInstancePtr,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) {
+        let instance = &*(instance as *const FallbackInstance);
+        let info = &instance.info;
+
+        // The last argument is the helper object, bound in create_callable().
+        (info.call_func)(
+            info.callable_userdata,
+            p_args,
+            p_argument_count - 1,
+            r_return,
+            r_error,
+        );
+    }
+
+    unsafe extern "C" fn free_instance(
+        _class_userdata: *mut std::ffi::c_void,
+        instance: sys::GDExtensionClassInstancePtr,
+    ) {
+        let _drop = Box::from_raw(instance as *mut FallbackInstance);
+    }
+}
@@ -6,8 +6,8 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{FromGodot, ToGodot};
-use crate::builtin::{inner, Variant};
+use crate::builtin::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::builtin::{inner, Variant, VariantConversionError};
 use crate::obj::Share;
 use crate::property::{Export, Property, PropertyHintInfo, TypeStringHint};
 use std::fmt;
@@ -396,6 +396,303 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
     }
 }
 
+/// Serializes the entries as a sequence of `(key, value)` pairs, in insertion order.
+///
+/// A map is not used because Godot keys can be any variant, while many formats (such as JSON) only support string keys.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Dictionary {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_shared())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Dictionary {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = <Vec<(Variant, Variant)> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A [`Dictionary`] whose keys are all of type `K` and values all of type `V`.
+///
+/// Godot itself does not know about the key/value types; in GDScript, this is a regular `Dictionary`. The types are validated when
+/// an untyped dictionary is converted to `TypedDictionary`, e.g. when it is passed as a `#[func]` argument or assigned to a `#[var]`.
+/// Each entry is checked once during that conversion; if one does not match, a [`VariantConversionError`] is returned.
+///
+/// Like `Dictionary`, this type has reference semantics. If the underlying dictionary is modified through another (untyped)
+/// reference -- for example from GDScript -- and entries of the wrong type are inserted, accessing them will panic.
+///
+/// # Export
+/// `TypedDictionary` is registered and exported exactly like an untyped `Dictionary`: its property carries no key/value type hint,
+/// and its [`TypeStringHint`] is the one of `Dictionary`. The Godot versions supported by this crate (up to 4.2) have no property
+/// hint for dictionary types, so the editor lets users enter keys and values of any type. Such entries are only rejected when the
+/// edited dictionary is assigned to the field, where the failed conversion is reported as an error.
+pub struct TypedDictionary<K, V> {
+    dict: Dictionary,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<K, V> TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    /// Constructs an empty `TypedDictionary`.
+    pub fn new() -> Self {
+        Self::from_untyped_unchecked(Dictionary::new())
+    }
+
+    /// Converts an untyped dictionary, checking that all keys are of type `K` and all values of type `V`.
+    ///
+    /// The resulting typed dictionary refers to the same data as `dict`.
+    pub fn try_from_untyped(dict: Dictionary) -> Result<Self, VariantConversionError> {
+        for (key, value) in dict.iter_shared() {
+            K::try_from_variant(&key).map_err(|err| err.with_key(&key))?;
+            V::try_from_variant(&value).map_err(|err| err.with_key(&key))?;
+        }
+
+        Ok(Self::from_untyped_unchecked(dict))
+    }
+
+    fn from_untyped_unchecked(dict: Dictionary) -> Self {
+        Self {
+            dict,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the underlying untyped dictionary, which refers to the same data.
+    pub fn as_untyped(&self) -> &Dictionary {
+        &self.dict
+    }
+
+    /// Converts this into the underlying untyped dictionary, which refers to the same data.
+    pub fn into_untyped(self) -> Dictionary {
+        self.dict
+    }
+
+    /// Returns the value for the given key, or `None` if the key is absent.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.dict
+            .get(key.to_variant())
+            .map(|value| V::from_variant(&value))
+    }
+
+    /// Returns `true` if the dictionary contains the given key.
+    ///
+    /// _Godot equivalent: `has`_
+    #[doc(alias = "has")]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.dict.contains_key(key.to_variant())
+    }
+
+    /// Insert a value at the given key, returning the previous value for that key (if available).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.dict
+            .insert(key.to_variant(), value.to_variant())
+            .map(|old_value| V::from_variant(&old_value))
+    }
+
+    /// Removes a key from the map, and returns the value associated with the key if the key was in the dictionary.
+    ///
+    /// _Godot equivalent: `erase`_
+    #[doc(alias = "erase")]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.dict
+            .remove(key.to_variant())
+            .map(|value| V::from_variant(&value))
+    }
+
+    /// Returns the number of entries in the dictionary.
+    #[doc(alias = "size")]
+    pub fn len(&self) -> usize {
+        self.dict.len()
+    }
+
+    /// Returns true if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.dict.is_empty()
+    }
+
+    /// Removes all key-value pairs from the dictionary.
+    pub fn clear(&mut self) {
+        self.dict.clear()
+    }
+
+    /// Returns a shallow copy of the dictionary; see [`Dictionary::duplicate_shallow()`].
+    pub fn duplicate_shallow(&self) -> Self {
+        Self::from_untyped_unchecked(self.dict.duplicate_shallow())
+    }
+
+    /// Returns a deep copy of the dictionary; see [`Dictionary::duplicate_deep()`].
+    pub fn duplicate_deep(&self) -> Self {
+        Self::from_untyped_unchecked(self.dict.duplicate_deep())
+    }
+
+    /// Returns an iterator over the `(K, V)` key-value pairs.
+    ///
+    /// The same considerations as for [`Dictionary::iter_shared()`] apply.
+    pub fn iter_shared(&self) -> TypedIter<'_, K, V> {
+        self.dict.iter_shared().typed()
+    }
+
+    /// Returns an iterator over the keys.
+    ///
+    /// The same considerations as for [`Dictionary::keys_shared()`] apply.
+    pub fn keys_shared(&self) -> TypedKeys<'_, K> {
+        self.dict.keys_shared().typed()
+    }
+}
+
+impl<K, V> GodotConvert for TypedDictionary<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K, V> ToGodot for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn to_godot(&self) -> Self::Via {
+        self.dict.clone()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.dict
+    }
+}
+
+impl<K, V> FromGodot for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    /// Discards the reason of a failed conversion; use [`try_from_untyped()`][Self::try_from_untyped] to find out which key or
+    /// value did not convert.
+    fn try_from_godot(via: Self::Via) -> Option<Self> {
+        Self::try_from_untyped(via).ok()
+    }
+
+    fn from_godot(via: Self::Via) -> Self {
+        Self::try_from_untyped(via).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        let dict = Dictionary::try_from_variant(variant)?;
+        Self::try_from_untyped(dict)
+    }
+}
+
+impl<K, V> TryFrom<Dictionary> for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    type Error = VariantConversionError;
+
+    fn try_from(dict: Dictionary) -> Result<Self, Self::Error> {
+        Self::try_from_untyped(dict)
+    }
+}
+
+impl<K, V> From<TypedDictionary<K, V>> for Dictionary {
+    fn from(typed: TypedDictionary<K, V>) -> Self {
+        typed.dict
+    }
+}
+
+impl<K, V> Default for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a new reference to the data in this dictionary. Changes to the original dictionary will be
+/// reflected in the copy and vice versa.
+impl<K, V> Clone for TypedDictionary<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            dict: self.dict.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, V> PartialEq for TypedDictionary<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dict == other.dict
+    }
+}
+
+impl<K, V> fmt::Debug for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.dict, f)
+    }
+}
+
+impl<K, V> Extend<(K, V)> for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.dict.extend(iter)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = Self::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<K, V> Property for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    type Intermediate = Self;
+
+    fn get_property(&self) -> Self::Intermediate {
+        self.clone()
+    }
+
+    fn set_property(&mut self, value: Self::Intermediate) {
+        *self = value;
+    }
+}
+
+// Untyped on purpose, see "Export" in the type docs.
+impl<K, V> TypeStringHint for TypedDictionary<K, V> {
+    fn type_string() -> String {
+        Dictionary::type_string()
+    }
+}
+
+// Exported like an untyped dictionary, as Godot has no property hint for key/value types; see "Export" in the type docs.
+impl<K, V> Export for TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn default_export_info() -> PropertyHintInfo {
+        Dictionary::default_export_info()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Internal helper for different iterator impls -- not an iterator itself
@@ -0,0 +1,715 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Geometric queries on vectors and polygons, implemented in Rust.
+//!
+//! These functions mirror a subset of Godot's `Geometry2D` and `Geometry3D` singletons. Since they do not call into
+//! the engine, they can be used from any thread.
+//!
+//! Polygons are passed as slices of points, with an implicit edge from the last point back to the first one. To pass a
+//! [`PackedVector2Array`](crate::builtin::PackedVector2Array), use its `as_slice()` method.
+//!
+//! Not yet provided: `Geometry2D.offset_polygon()`. Godot implements it with the Clipper library, which merges overlapping parts
+//! and can return several polygons; matching that requires a port of Clipper's offsetting.
+
+use crate::builtin::math::{ApproxEq, FloatExt};
+use crate::builtin::{real, Vector2, Vector3};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Segments
+
+/// Returns the point on the segment `from`..`to` that is closest to `point`.
+///
+/// _Godot equivalent: `Geometry2D.get_closest_point_to_segment()`_
+pub fn closest_point_to_segment_2d(point: Vector2, from: Vector2, to: Vector2) -> Vector2 {
+    let segment = to - from;
+    let length_squared = segment.length_squared();
+    if length_squared < 1e-20 {
+        return from;
+    }
+
+    let t = segment.dot(point - from) / length_squared;
+    if t <= 0.0 {
+        from
+    } else if t >= 1.0 {
+        to
+    } else {
+        from + segment * t
+    }
+}
+
+/// Returns the point on the segment `from`..`to` that is closest to `point`.
+///
+/// _Godot equivalent: `Geometry3D.get_closest_point_to_segment()`_
+pub fn closest_point_to_segment_3d(point: Vector3, from: Vector3, to: Vector3) -> Vector3 {
+    let segment = to - from;
+    let length_squared = segment.length_squared();
+    if length_squared < 1e-20 {
+        return from;
+    }
+
+    let t = segment.dot(point - from) / length_squared;
+    if t <= 0.0 {
+        from
+    } else if t >= 1.0 {
+        to
+    } else {
+        from + segment * t
+    }
+}
+
+/// Returns the pair of closest points between the segments `from_a`..`to_a` and `from_b`..`to_b`.
+///
+/// The first point lies on segment A, the second on segment B.
+///
+/// _Godot equivalent: `Geometry2D.get_closest_points_between_segments()`_
+pub fn closest_points_between_segments_2d(
+    from_a: Vector2,
+    to_a: Vector2,
+    from_b: Vector2,
+    to_b: Vector2,
+) -> (Vector2, Vector2) {
+    let dir_a = to_a - from_a;
+    let dir_b = to_b - from_b;
+    let r = from_a - from_b;
+
+    let (s, t) = closest_segment_params(
+        dir_a.dot(dir_a),
+        dir_a.dot(dir_b),
+        dir_b.dot(dir_b),
+        dir_a.dot(r),
+        dir_b.dot(r),
+    );
+
+    (from_a + dir_a * s, from_b + dir_b * t)
+}
+
+/// Returns the pair of closest points between the segments `from_a`..`to_a` and `from_b`..`to_b`.
+///
+/// The first point lies on segment A, the second on segment B.
+///
+/// _Godot equivalent: `Geometry3D.get_closest_points_between_segments()`_
+pub fn closest_points_between_segments_3d(
+    from_a: Vector3,
+    to_a: Vector3,
+    from_b: Vector3,
+    to_b: Vector3,
+) -> (Vector3, Vector3) {
+    // Translated from Godot (Eberly, "Distance Between Two Line Segments"), to pick the same pair for parallel segments.
+    let p = to_a - from_a;
+    let q = to_b - from_b;
+    let r = from_a - from_b;
+
+    let a = p.dot(p);
+    let b = p.dot(q);
+    let c = q.dot(q);
+    let d = p.dot(r);
+    let e = q.dot(r);
+
+    // Closest point on segment A to a point on the infinite line A, as a parameter in 0..=1.
+    let s_on_a = |numer: real| {
+        if numer <= 0.0 {
+            0.0
+        } else if numer >= a {
+            1.0
+        } else {
+            numer / a
+        }
+    };
+
+    let det = a * c - b * b;
+    let (s, t) = if det > real::CMP_EPSILON {
+        // Non-parallel segments: find the region of the (s, t) square that contains the minimum of the unclamped problem.
+        let bte = b * e;
+        let ctd = c * d;
+
+        if bte <= ctd {
+            // s <= 0
+            if e <= 0.0 {
+                (s_on_a(-d), 0.0)
+            } else if e < c {
+                (0.0, e / c)
+            } else {
+                (s_on_a(b - d), 1.0)
+            }
+        } else if bte - ctd >= det {
+            // s >= 1
+            if b + e <= 0.0 {
+                (s_on_a(-d), 0.0)
+            } else if b + e < c {
+                (1.0, (b + e) / c)
+            } else {
+                (s_on_a(b - d), 1.0)
+            }
+        } else {
+            // 0 < s < 1
+            let ate = a * e;
+            let btd = b * d;
+
+            if ate <= btd {
+                (s_on_a(-d), 0.0)
+            } else if ate - btd >= det {
+                (s_on_a(b - d), 1.0)
+            } else {
+                ((bte - ctd) / det, (ate - btd) / det)
+            }
+        }
+    } else {
+        // Parallel segments.
+        if e <= 0.0 {
+            (s_on_a(-d), 0.0)
+        } else if e >= c {
+            (s_on_a(b - d), 1.0)
+        } else {
+            (0.0, e / c)
+        }
+    };
+
+    (from_a * (1.0 - s) + to_a * s, from_b * (1.0 - t) + to_b * t)
+}
+
+/// Returns the intersection point of the segments `from_a`..`to_a` and `from_b`..`to_b`, or `None` if they don't intersect.
+///
+/// Parallel and collinear segments are never considered intersecting.
+///
+/// _Godot equivalent: `Geometry2D.segment_intersects_segment()`_
+pub fn segment_intersects_segment_2d(
+    from_a: Vector2,
+    to_a: Vector2,
+    from_b: Vector2,
+    to_b: Vector2,
+) -> Option<Vector2> {
+    // Translated from Godot: transforms B into a coordinate system where A is the unit segment on the X axis.
+    let b = to_a - from_a;
+    let c = from_b - from_a;
+    let d = to_b - from_a;
+
+    let ab_len = b.dot(b);
+    if ab_len <= 0.0 {
+        return None;
+    }
+
+    let bn = b / ab_len;
+    let c = Vector2::new(c.x * bn.x + c.y * bn.y, c.y * bn.x - c.x * bn.y);
+    let d = Vector2::new(d.x * bn.x + d.y * bn.y, d.y * bn.x - d.x * bn.y);
+
+    // Both endpoints of B on the same side of A.
+    if (c.y < -real::CMP_EPSILON && d.y < -real::CMP_EPSILON)
+        || (c.y > real::CMP_EPSILON && d.y > real::CMP_EPSILON)
+    {
+        return None;
+    }
+
+    // Parallel or collinear.
+    if c.y.approx_eq(&d.y) {
+        return None;
+    }
+
+    let ab_pos = d.x + (c.x - d.x) * d.y / (d.y - c.y);
+    if !(0.0..=1.0).contains(&ab_pos) {
+        return None;
+    }
+
+    Some(from_a + b * ab_pos)
+}
+
+/// Parameters `(s, t)` of the closest points on two segments `P(s) = p + s * dir_p` and `Q(t) = q + t * dir_q`, both clamped to `0..=1`.
+///
+/// Takes the dot products `a = dir_p·dir_p`, `b = dir_p·dir_q`, `e = dir_q·dir_q`, `c = dir_p·(p - q)` and `f = dir_q·(p - q)`.
+fn closest_segment_params(a: real, b: real, e: real, c: real, f: real) -> (real, real) {
+    // Translated from Godot (Ericson, "Real-Time Collision Detection", 5.1.9).
+    if a <= real::CMP_EPSILON && e <= real::CMP_EPSILON {
+        // Both segments degenerate into points.
+        return (0.0, 0.0);
+    }
+
+    if a <= real::CMP_EPSILON {
+        // First segment degenerates into a point.
+        return (0.0, (f / e).clamp(0.0, 1.0));
+    }
+
+    if e <= real::CMP_EPSILON {
+        // Second segment degenerates into a point.
+        return ((-c / a).clamp(0.0, 1.0), 0.0);
+    }
+
+    // Non-parallel segments: closest point on infinite line A to line B, clamped to segment A. Otherwise pick any s.
+    let denom = a * e - b * b;
+    let s = if denom != 0.0 {
+        ((b * f - c * e) / denom).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    // Closest point on line B to A(s); if outside of segment B, clamp and recompute s.
+    let t = (b * s + f) / e;
+    if t < 0.0 {
+        ((-c / a).clamp(0.0, 1.0), 0.0)
+    } else if t > 1.0 {
+        (((b - c) / a).clamp(0.0, 1.0), 1.0)
+    } else {
+        (s, t)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Triangles
+
+/// Returns the point where the ray starting at `from` in direction `dir` hits the triangle `a`, `b`, `c`, or `None` if it misses.
+///
+/// Both sides of the triangle are hit. Rays parallel to the triangle's plane never hit.
+///
+/// _Godot equivalent: `Geometry3D.ray_intersects_triangle()`_
+pub fn ray_intersects_triangle(
+    from: Vector3,
+    dir: Vector3,
+    a: Vector3,
+    b: Vector3,
+    c: Vector3,
+) -> Option<Vector3> {
+    let t = line_triangle_param(from, dir, a, b, c)?;
+
+    // Intersections behind (or very close to) the origin don't count.
+    if t > 0.00001 {
+        Some(from + dir * t)
+    } else {
+        None
+    }
+}
+
+/// Returns the point where the segment `from`..`to` intersects the triangle `a`, `b`, `c`, or `None` if it doesn't.
+///
+/// Both sides of the triangle are hit. Segments parallel to the triangle's plane never intersect.
+///
+/// _Godot equivalent: `Geometry3D.segment_intersects_triangle()`_
+pub fn segment_intersects_triangle(
+    from: Vector3,
+    to: Vector3,
+    a: Vector3,
+    b: Vector3,
+    c: Vector3,
+) -> Option<Vector3> {
+    let segment = to - from;
+    let t = line_triangle_param(from, segment, a, b, c)?;
+
+    if t > real::CMP_EPSILON && t <= 1.0 {
+        Some(from + segment * t)
+    } else {
+        None
+    }
+}
+
+/// Parameter `t` at which the line `from + t * dir` passes through the triangle `a`, `b`, `c` (Möller–Trumbore).
+fn line_triangle_param(
+    from: Vector3,
+    dir: Vector3,
+    a: Vector3,
+    b: Vector3,
+    c: Vector3,
+) -> Option<real> {
+    let e1 = b - a;
+    let e2 = c - a;
+    let h = dir.cross(e2);
+
+    let det = e1.dot(h);
+    if det.is_zero_approx() {
+        return None;
+    }
+
+    let f = 1.0 / det;
+    let s = from - a;
+    let u = f * s.dot(h);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(e1);
+    let v = f * dir.dot(q);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    Some(f * e2.dot(q))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Polygons
+
+/// Returns `true` if `point` lies inside `polygon` or on one of its edges.
+///
+/// Polygons with fewer than 3 points contain nothing.
+///
+/// _Godot equivalent: `Geometry2D.is_point_in_polygon()`_
+pub fn is_point_in_polygon(point: Vector2, polygon: &[Vector2]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    // Translated from Godot: count crossings of a segment from `point` to somewhere outside the polygon. The odd scale factors
+    // make it unlikely that this segment passes exactly through a vertex.
+    let mut max = Vector2::new(-1e20, -1e20);
+    let mut min = Vector2::new(1e20, 1e20);
+    for &vertex in polygon {
+        max = max.coord_max(vertex);
+        min = min.coord_min(vertex);
+    }
+    let outside = max + (max - min) * Vector2::new(1.221313, 1.512312);
+
+    let mut intersections = 0;
+    for (i, &from) in polygon.iter().enumerate() {
+        let to = polygon[(i + 1) % polygon.len()];
+
+        if let Some(intersection) = segment_intersects_segment_2d(from, to, point, outside) {
+            // Point lies on an edge.
+            if intersection.approx_eq(&point) {
+                return true;
+            }
+
+            intersections += 1;
+        }
+    }
+
+    intersections % 2 == 1
+}
+
+/// Returns `true` if the vertices of `polygon` are in clockwise order, assuming a Y axis pointing up.
+///
+/// In Godot's 2D coordinate system (Y pointing down), this corresponds to counter-clockwise on screen. Polygons with fewer than 3 points
+/// are never clockwise.
+///
+/// _Godot equivalent: `Geometry2D.is_polygon_clockwise()`_
+pub fn is_polygon_clockwise(polygon: &[Vector2]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let sum: real = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(v1, v2)| (v2.x - v1.x) * (v2.y + v1.y))
+        .sum();
+
+    sum > 0.0
+}
+
+/// Returns the convex hull of `points`, computed with Andrew's monotone chain algorithm.
+///
+/// Like in Godot, the hull is closed: its last point repeats the first one. Points with NaN coordinates do not cause a panic,
+/// but the resulting hull is unspecified.
+///
+/// _Godot equivalent: `Geometry2D.convex_hull()`_
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    // Use a total order, so that sorting stays consistent even if some coordinates are NaN.
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+
+    // Cross product of OA and OB; positive for a counter-clockwise turn.
+    let turn = |o: Vector2, a: Vector2, b: Vector2| (a - o).cross(b - o);
+
+    let mut hull: Vec<Vector2> = Vec::with_capacity(2 * sorted.len());
+
+    // Lower hull.
+    for &point in sorted.iter() {
+        while hull.len() >= 2 && turn(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // Upper hull.
+    let lower_len = hull.len() + 1;
+    for &point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && turn(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    hull
+}
+
+/// Triangulates `polygon` by ear clipping, and returns the triangles as consecutive triples of indices into `polygon`.
+///
+/// Returns `None` if the polygon has fewer than 3 points, or cannot be triangulated (e.g. because it intersects itself).
+///
+/// _Godot equivalent: `Geometry2D.triangulate_polygon()`_
+pub fn triangulate_polygon(polygon: &[Vector2]) -> Option<Vec<usize>> {
+    let n = polygon.len();
+    if n < 3 {
+        return None;
+    }
+
+    // Translated from Godot's `Triangulate` class, to produce the same triangles.
+    // Work on a counter-clockwise list of remaining vertex indices.
+    let mut remaining: Vec<usize> = if signed_area(polygon) > 0.0 {
+        (0..n).collect()
+    } else {
+        (0..n).rev().collect()
+    };
+
+    let mut triangles = Vec::with_capacity(3 * (n - 2));
+    let mut relaxed = false;
+    let mut count = 2 * n;
+    let mut v = n - 1;
+
+    while remaining.len() > 2 {
+        let nv = remaining.len();
+
+        // Looping without finding an ear: first retry allowing zero-area triangles (from collinear vertices), then give up.
+        if count == 0 {
+            if relaxed {
+                return None;
+            }
+            relaxed = true;
+            count = 2 * nv;
+        } else {
+            count -= 1;
+        }
+
+        // Three consecutive vertices u, v, w.
+        let u = if v < nv { v } else { 0 };
+        v = if u + 1 < nv { u + 1 } else { 0 };
+        let w = if v + 1 < nv { v + 1 } else { 0 };
+
+        if is_ear(polygon, &remaining, u, v, w, relaxed) {
+            triangles.extend([remaining[u], remaining[v], remaining[w]]);
+            remaining.remove(v);
+            count = 2 * remaining.len();
+        }
+    }
+
+    Some(triangles)
+}
+
+/// Signed area of a polygon; positive for counter-clockwise polygons (Y axis pointing up).
+fn signed_area(polygon: &[Vector2]) -> real {
+    let twice_area: real = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(p, q)| p.cross(*q))
+        .sum();
+
+    twice_area * 0.5
+}
+
+/// Whether the triangle `remaining[u]`, `remaining[v]`, `remaining[w]` is an ear, i.e. convex and not containing other vertices.
+fn is_ear(
+    polygon: &[Vector2],
+    remaining: &[usize],
+    u: usize,
+    v: usize,
+    w: usize,
+    relaxed: bool,
+) -> bool {
+    let a = polygon[remaining[u]];
+    let b = polygon[remaining[v]];
+    let c = polygon[remaining[w]];
+
+    // In relaxed mode, also accept collinear vertices, otherwise the last triangles may never be formed.
+    let threshold = if relaxed {
+        -real::CMP_EPSILON
+    } else {
+        real::CMP_EPSILON
+    };
+    if threshold > (b - a).cross(c - a) {
+        return false;
+    }
+
+    !remaining.iter().enumerate().any(|(i, &index)| {
+        i != u && i != v && i != w && is_inside_triangle(a, b, c, polygon[index], relaxed)
+    })
+}
+
+/// Whether `p` lies inside the counter-clockwise triangle `a`, `b`, `c`.
+///
+/// Like in Godot, points on the edges count as inside in strict mode, but not in relaxed mode.
+fn is_inside_triangle(a: Vector2, b: Vector2, c: Vector2, p: Vector2, relaxed: bool) -> bool {
+    let a_cross_bp = (c - b).cross(p - b);
+    let b_cross_cp = (a - c).cross(p - c);
+    let c_cross_ap = (b - a).cross(p - a);
+
+    if relaxed {
+        a_cross_bp > 0.0 && b_cross_cp > 0.0 && c_cross_ap > 0.0
+    } else {
+        a_cross_bp >= 0.0 && b_cross_cp >= 0.0 && c_cross_ap >= 0.0
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+
+    fn square() -> Vec<Vector2> {
+        vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(2.0, 2.0),
+            Vector2::new(0.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn closest_points() {
+        let (a, b) = closest_points_between_segments_2d(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(1.0, 3.0),
+        );
+        assert_eq_approx!(a, Vector2::new(1.0, 0.0));
+        assert_eq_approx!(b, Vector2::new(1.0, 1.0));
+
+        let (a, b) = closest_points_between_segments_3d(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(3.0, -1.0, 1.0),
+            Vector3::new(3.0, 1.0, 1.0),
+        );
+        assert_eq_approx!(a, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq_approx!(b, Vector3::new(3.0, 0.0, 1.0));
+
+        // Overlapping parallel segments: like Godot, pick the start of A.
+        let (a, b) = closest_points_between_segments_3d(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(-1.0, 1.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+        );
+        assert_eq_approx!(a, Vector3::new(0.0, 0.0, 0.0));
+        assert_eq_approx!(b, Vector3::new(0.0, 1.0, 0.0));
+
+        assert_eq_approx!(
+            closest_point_to_segment_2d(
+                Vector2::new(5.0, 1.0),
+                Vector2::new(0.0, 0.0),
+                Vector2::new(2.0, 0.0),
+            ),
+            Vector2::new(2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn segment_intersection() {
+        let hit = segment_intersects_segment_2d(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 2.0),
+            Vector2::new(0.0, 2.0),
+            Vector2::new(2.0, 0.0),
+        );
+        assert_eq_approx!(hit.unwrap(), Vector2::new(1.0, 1.0));
+
+        // Parallel.
+        let miss = segment_intersects_segment_2d(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(0.0, 1.0),
+            Vector2::new(2.0, 1.0),
+        );
+        assert_eq!(miss, None);
+    }
+
+    #[test]
+    fn triangle_intersection() {
+        let (a, b, c) = (
+            Vector3::new(-1.0, -1.0, 0.0),
+            Vector3::new(1.0, -1.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+
+        let hit = ray_intersects_triangle(Vector3::new(0.0, 0.0, 5.0), Vector3::FORWARD, a, b, c);
+        assert_eq_approx!(hit.unwrap(), Vector3::ZERO);
+
+        // Pointing away.
+        let miss = ray_intersects_triangle(Vector3::new(0.0, 0.0, 5.0), Vector3::BACK, a, b, c);
+        assert_eq!(miss, None);
+
+        // Too short.
+        let miss = segment_intersects_triangle(
+            Vector3::new(0.0, 0.0, 5.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            a,
+            b,
+            c,
+        );
+        assert_eq!(miss, None);
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        let polygon = square();
+
+        assert!(is_point_in_polygon(Vector2::new(1.0, 1.0), &polygon));
+        assert!(is_point_in_polygon(Vector2::new(2.0, 1.0), &polygon));
+        assert!(!is_point_in_polygon(Vector2::new(3.0, 1.0), &polygon));
+        assert!(!is_point_in_polygon(Vector2::new(1.0, 1.0), &polygon[..2]));
+    }
+
+    #[test]
+    fn clockwise() {
+        let mut polygon = square();
+        assert!(!is_polygon_clockwise(&polygon));
+
+        polygon.reverse();
+        assert!(is_polygon_clockwise(&polygon));
+    }
+
+    #[test]
+    fn hull() {
+        let mut points = square();
+        points.push(Vector2::new(1.0, 1.0));
+        points.push(Vector2::new(1.0, 0.0));
+
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(2.0, 0.0),
+                Vector2::new(2.0, 2.0),
+                Vector2::new(0.0, 2.0),
+                Vector2::new(0.0, 0.0),
+            ]
+        );
+
+        // NaN coordinates must not break sorting.
+        points.push(Vector2::new(real::NAN, 1.0));
+        points.push(Vector2::new(1.0, real::NAN));
+        let hull = convex_hull(&points);
+        assert!(hull.len() <= 2 * points.len());
+    }
+
+    #[test]
+    fn triangulate() {
+        let polygon = square();
+        let triangles = triangulate_polygon(&polygon).unwrap();
+        assert_eq!(triangles.len(), 6);
+
+        // Concave L shape: 6 vertices, 4 triangles, total area must be preserved.
+        let polygon = [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(2.0, 1.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(1.0, 2.0),
+            Vector2::new(0.0, 2.0),
+        ];
+        let triangles = triangulate_polygon(&polygon).unwrap();
+        assert_eq!(triangles.len(), 12);
+
+        let area: real = triangles
+            .chunks(3)
+            .map(|t| signed_area(&[polygon[t[0]], polygon[t[1]], polygon[t[2]]]))
+            .sum();
+        assert_eq_approx!(area, 3.0);
+
+        assert_eq!(triangulate_polygon(&polygon[..2]), None);
+    }
+}
@@ -8,6 +8,8 @@ mod approx_eq;
 mod float;
 mod glam_helpers;
 
+pub mod geometry;
+
 pub use crate::{assert_eq_approx, assert_ne_approx};
 pub use approx_eq::ApproxEq;
 pub use float::FloatExt;
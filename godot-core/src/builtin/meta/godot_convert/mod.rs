@@ -69,9 +69,12 @@ pub trait FromGodot: Sized + GodotConvert {
 
     /// Performs the conversion from a [`Variant`].
     fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-        let ffi = <Self::Via as GodotType>::Ffi::ffi_from_variant(variant)?;
-        let via = Self::Via::try_from_ffi(ffi).ok_or(VariantConversionError::BadValue)?;
-        Self::try_from_godot(via).ok_or(VariantConversionError::BadValue)
+        let ffi = <Self::Via as GodotType>::Ffi::ffi_from_variant(variant)
+            .map_err(|err| err.with_expected_type::<Self>())?;
+
+        Self::Via::try_from_ffi(ffi)
+            .and_then(Self::try_from_godot)
+            .ok_or_else(|| VariantConversionError::bad_value::<Self>(variant))
     }
 
     /// ⚠️ Performs the conversion from a [`Variant`].
@@ -14,7 +14,7 @@ use sys::{BuiltinMethodBind, ClassMethodBind, UtilityFunctionBind};
 // implement `ToGodot`, and the other way around for return values.
 
 use crate::builtin::meta::*;
-use crate::builtin::Variant;
+use crate::builtin::{CallError, Variant};
 use crate::obj::InstanceId;
 
 #[doc(hidden)]
@@ -53,6 +53,12 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     ) -> Self::Ret;
 
     fn format_args(args: &Self::Params) -> String;
+
+    /// Converts the arguments of a call from Godot, without panicking.
+    ///
+    /// Fails if the number of arguments differs from [`PARAM_COUNT`][Self::PARAM_COUNT], or if an argument cannot be converted
+    /// to its parameter type. In the latter case, the error refers to the first such argument.
+    fn params_from_variants(args: &[&Variant]) -> Result<Self::Params, CallError>;
 }
 
 #[doc(hidden)]
@@ -241,6 +247,15 @@ macro_rules! impl_varcall_signature_for_tuple {
                 string.remove(string.len() - 2); // remove trailing ", "
                 string
             }
+
+            #[inline]
+            fn params_from_variants(args: &[&Variant]) -> Result<Self::Params, CallError> {
+                CallError::check_arg_count(Self::PARAM_COUNT, args.len())?;
+
+                Ok(($(
+                    param_from_variant::<$Pn>(args[$n], $n)?,
+                )*))
+            }
         }
     };
 }
@@ -377,13 +392,22 @@ unsafe fn varcall_arg<P: FromGodot, const N: isize>(
         .unwrap_or_else(|_| param_error::<P>(method_name, N as i32, variant_ref))
 }
 
+/// Converts the argument at `index` into a value of type `P`, or reports which argument failed.
+fn param_from_variant<P: FromGodot>(arg: &Variant, index: usize) -> Result<P, CallError> {
+    P::try_from_variant(arg).map_err(|error| CallError::InvalidArgument {
+        index,
+        expected: <<P::Via as GodotType>::Ffi as sys::GodotFfi>::variant_type(),
+        error,
+    })
+}
+
 /// Moves `ret_val` into `ret`.
 ///
 /// # Safety
 /// - `ret` must be a pointer to an initialized `Variant`.
 /// - It must be safe to write a `Variant` once to `ret`.
 /// - It must be safe to write a `sys::GDExtensionCallError` once to `err`.
-unsafe fn varcall_return<R: ToGodot>(
+pub(crate) unsafe fn varcall_return<R: ToGodot>(
     ret_val: R,
     ret: sys::GDExtensionVariantPtr,
     err: *mut sys::GDExtensionCallError,
@@ -397,7 +421,6 @@ unsafe fn varcall_return<R: ToGodot>(
 ///
 /// # Safety
 /// See [`varcall_return`].
-#[cfg(since_api = "4.2")] // unused before
 pub(crate) unsafe fn varcall_return_checked<R: ToGodot>(
     ret_val: Result<R, ()>, // TODO Err should be custom CallError enum
     ret: sys::GDExtensionVariantPtr,
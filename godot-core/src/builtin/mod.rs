@@ -33,14 +33,14 @@
 //!   overloading would become impossible](https://github.com/kvark/mint/issues/75).
 
 // Re-export macros.
-pub use crate::{array, dict, real, reals, varray};
+pub use crate::{array, dict, real, reals, sname, varray};
 
 pub use aabb::*;
 pub use array_inner::{Array, VariantArray};
 pub use basis::*;
 pub use callable::*;
 pub use color::*;
-pub use dictionary_inner::Dictionary;
+pub use dictionary_inner::{Dictionary, TypedDictionary};
 pub use others::*;
 pub use packed_array::*;
 pub use plane::*;
@@ -134,7 +134,7 @@ pub(crate) fn u8_to_bool(u: u8) -> bool {
 ///
 /// _Godot equivalent: `@GlobalScope.Side`_
 #[doc(alias = "Side")]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub enum RectSide {
     Left = 0,
@@ -6,10 +6,12 @@
 
 // Stub for various other built-in classes, which are currently incomplete, but whose types
 // are required for codegen
+use std::fmt;
+
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use super::meta::impl_godot_as_self;
+use super::meta::{impl_godot_as_self, ToGodot};
 
 // TODO: Swap more inner math types with glam types
 // Note: ordered by enum ord in extension JSON
@@ -18,6 +20,13 @@ impl_builtin_stub!(Signal, OpaqueSignal);
 impl_builtin_traits! {
     for Signal {
         Clone => signal_construct_copy;
+        PartialEq => signal_operator_equal;
+    }
+}
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Signal({})", self.to_variant().stringify())
     }
 }
 
@@ -6,29 +6,124 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::ToGodot;
+use crate::builtin::meta::{GodotType, ToGodot};
 use crate::builtin::*;
+use crate::engine::file_access::CompressionMode;
+use crate::obj::EngineEnum;
 use std::fmt;
+use std::marker::PhantomData;
 use sys::types::*;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 // FIXME remove dependency on these types
 use sys::{__GdextString, __GdextType};
 
-/// Defines and implements a single packed array type. This macro is not hygienic and is meant to
-/// be used only in the current module.
-macro_rules! impl_packed_array {
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Element trait
+
+mod sealed {
+    // Only the element types supported by Godot can be stored in packed arrays.
+    pub trait Sealed {}
+}
+
+/// Element type that can be stored in a [`PackedArray`].
+///
+/// Implemented for `u8`, `i32`, `i64`, `f32`, `f64`, [`GString`], [`Vector2`], [`Vector3`] and [`Color`], which are exactly the
+/// element types of Godot's packed arrays. This trait is sealed; its methods are implementation details and not part of the public API.
+pub trait PackedArrayElement: GodotType + Clone + fmt::Debug + sealed::Sealed {
+    #[doc(hidden)]
+    fn packed_variant_type() -> sys::VariantType;
+
+    #[doc(hidden)]
+    fn op_default() -> PackedArray<Self>;
+
+    #[doc(hidden)]
+    fn op_clone(array: &PackedArray<Self>) -> PackedArray<Self>;
+
+    /// # Safety
+    /// Must be called at most once per array, when it is dropped.
+    #[doc(hidden)]
+    unsafe fn op_destroy(array: &mut PackedArray<Self>);
+
+    #[doc(hidden)]
+    fn op_equals(lhs: &PackedArray<Self>, rhs: &PackedArray<Self>) -> bool;
+
+    #[doc(hidden)]
+    fn op_from_array<U: GodotType>(array: &Array<U>) -> PackedArray<Self>;
+
+    #[doc(hidden)]
+    fn op_to_variant(array: &PackedArray<Self>) -> Variant;
+
+    #[doc(hidden)]
+    fn op_index_const(array: &PackedArray<Self>, index: usize) -> *const Self;
+
+    #[doc(hidden)]
+    fn op_index(array: &PackedArray<Self>, index: usize) -> *mut Self;
+
+    #[doc(hidden)]
+    fn op_size(array: &PackedArray<Self>) -> i64;
+
+    #[doc(hidden)]
+    fn op_is_empty(array: &PackedArray<Self>) -> bool;
+
+    #[doc(hidden)]
+    fn op_clear(array: &mut PackedArray<Self>);
+
+    #[doc(hidden)]
+    fn op_resize(array: &mut PackedArray<Self>, size: i64);
+
+    #[doc(hidden)]
+    fn op_slice(array: &PackedArray<Self>, begin: i64, end: i64) -> PackedArray<Self>;
+
+    #[doc(hidden)]
+    fn op_bsearch(array: &PackedArray<Self>, value: Self) -> i64;
+
+    #[doc(hidden)]
+    fn op_count(array: &PackedArray<Self>, value: Self) -> i64;
+
+    #[doc(hidden)]
+    fn op_has(array: &PackedArray<Self>, value: Self) -> bool;
+
+    #[doc(hidden)]
+    fn op_find(array: &PackedArray<Self>, value: Self, from: i64) -> i64;
+
+    #[doc(hidden)]
+    fn op_rfind(array: &PackedArray<Self>, value: Self, from: i64) -> i64;
+
+    #[doc(hidden)]
+    fn op_push_back(array: &mut PackedArray<Self>, value: Self);
+
+    #[doc(hidden)]
+    fn op_insert(array: &mut PackedArray<Self>, index: i64, value: Self);
+
+    #[doc(hidden)]
+    fn op_remove_at(array: &mut PackedArray<Self>, index: i64);
+
+    #[doc(hidden)]
+    fn op_fill(array: &mut PackedArray<Self>, value: Self);
+
+    #[doc(hidden)]
+    fn op_append_array(array: &mut PackedArray<Self>, other: &PackedArray<Self>);
+
+    #[doc(hidden)]
+    fn op_reverse(array: &mut PackedArray<Self>);
+
+    #[doc(hidden)]
+    fn op_sort(array: &mut PackedArray<Self>);
+}
+
+/// Implements `PackedArrayElement` for one element type, and defines the corresponding `Packed*Array` alias. This macro is not
+/// hygienic and is meant to be used only in the current module.
+macro_rules! impl_packed_array_element {
     (
-        // Name of the type to define, e.g. `PackedByteArray`.
+        // Name of the alias to define, e.g. `PackedByteArray`. Must match the `VariantType` enumerator.
         type_name: $PackedArray:ident,
         // Type of elements contained in the array, e.g. `u8`.
         element_type: $Element:ty,
-        // Name of wrapped opaque type, e.g. `OpaquePackedByteArray`.
-        opaque_type: $Opaque:ty,
         // Name of inner type, e.g. `InnerPackedByteArray`.
         inner_type: $Inner:ident,
         // Name of type that represents elements in function call arguments, e.g. `i64`. See
-        // `Self::into_arg()`.
+        // `into_arg()` below.
         argument_type: $Arg:ty,
         // Type that is returned from `$operator_index` and `$operator_index_const`.
         return_type: $IndexRetType:ty,
@@ -38,326 +133,161 @@ macro_rules! impl_packed_array {
         operator_index: $operator_index:ident,
         // Name of const index operator from FFI, e.g. `packed_byte_array_operator_index_const`.
         operator_index_const: $operator_index_const:ident,
-        // Invocation passed to `impl_builtin_traits!` macro.
-        trait_impls: {
-            $($trait_impls:tt)*
-        },
+        // Names of the default/copy constructors, destructor and equality operator from FFI.
+        construct_default: $construct_default:ident,
+        construct_copy: $construct_copy:ident,
+        destroy: $destroy:ident,
+        operator_equal: $operator_equal:ident,
     ) => {
-        // TODO expand type names in doc comments (use e.g. `paste` crate)
-        #[doc = concat!("Implements Godot's `", stringify!($PackedArray), "` type,")]
-        #[doc = concat!("which is an efficient array of `", stringify!($Element), "`s.")]
+        #[doc = concat!("Godot's `", stringify!($PackedArray), "` type, an efficient array of `", stringify!($Element), "`s.")]
         ///
-        /// Note that, unlike `Array`, this type has value semantics: each copy will be independent
-        /// of the original. (Under the hood, Godot uses copy-on-write, so copies are still cheap
-        /// to make.)
-        ///
-        /// # Thread safety
-        ///
-        #[doc = concat!("Usage is safe if the `", stringify!($PackedArray), "`")]
-        /// is used on a single thread only. Concurrent reads on different threads are also safe,
-        /// but any writes must be externally synchronized. The Rust compiler will enforce this as
-        /// long as you use only Rust threads, but it cannot protect against concurrent modification
-        /// on other threads (e.g. created through GDScript).
-        #[repr(C)]
-        pub struct $PackedArray {
-            opaque: $Opaque,
-        }
+        /// See [`PackedArray`] for the API and generic usage.
+        pub type $PackedArray = PackedArray<$Element>;
 
-        impl $PackedArray {
-            fn from_opaque(opaque: $Opaque) -> Self {
-                Self { opaque }
+        impl sealed::Sealed for $Element {}
+
+        impl PackedArrayElement for $Element {
+            fn packed_variant_type() -> sys::VariantType {
+                sys::VariantType::$PackedArray
             }
-        }
 
-        // This impl relies on `$Inner` which is not (yet) available in unit tests
-        impl $PackedArray {
-            /// Constructs an empty array.
-            pub fn new() -> Self {
-                Self::default()
-            }
-
-            /// Returns the number of elements in the array. Equivalent of `size()` in Godot.
-            pub fn len(&self) -> usize {
-                to_usize(self.as_inner().size())
-            }
-
-            /// Returns `true` if the array is empty.
-            pub fn is_empty(&self) -> bool {
-                self.as_inner().is_empty()
-            }
-
-            /// Converts this array to a Rust vector, making a copy of its contents.
-            pub fn to_vec(&self) -> Vec<$Element> {
-                let len = self.len();
-                let mut vec = Vec::with_capacity(len);
-                if len > 0 {
-                    let ptr = self.ptr(0);
-                    for offset in 0..to_isize(len) {
-                        // SAFETY: Packed arrays are stored contiguously in memory, so we can use
-                        // pointer arithmetic instead of going through `$operator_index_const` for
-                        // every index.
-                        // Note that we do need to use `.clone()` because `GString` is refcounted;
-                        // we can't just do a memcpy.
-                        let element = unsafe { (*ptr.offset(offset)).clone() };
-                        vec.push(element);
-                    }
+            fn op_default() -> PackedArray<Self> {
+                unsafe {
+                    PackedArray::from_sys_init(|self_ptr| {
+                        let ctor = sys::builtin_fn!($construct_default);
+                        ctor(self_ptr, std::ptr::null_mut())
+                    })
                 }
-                vec
             }
 
-            /// Clears the array, removing all elements.
-            pub fn clear(&mut self) {
-                self.as_inner().clear();
+            fn op_clone(array: &PackedArray<Self>) -> PackedArray<Self> {
+                unsafe {
+                    PackedArray::from_sys_init(|self_ptr| {
+                        let ctor = sys::builtin_fn!($construct_copy);
+                        let args = [array.sys_const()];
+                        ctor(self_ptr, args.as_ptr());
+                    })
+                }
             }
 
-            /// Resizes the array to contain a different number of elements. If the new size is
-            /// smaller, elements are removed from the end. If the new size is larger, new elements
-            /// are set to [`Default::default()`].
-            pub fn resize(&mut self, size: usize) {
-                self.as_inner().resize(to_i64(size));
+            unsafe fn op_destroy(array: &mut PackedArray<Self>) {
+                let destructor = sys::builtin_fn!($destroy @1);
+                destructor(array.sys_mut());
             }
 
-            /// Returns a sub-range `begin..end`, as a new packed array.
-            ///
-            /// The values of `begin` (inclusive) and `end` (exclusive) will be clamped to the array size.
-            ///
-            /// To obtain Rust slices, see [`as_slice`][Self::as_slice] and [`as_mut_slice`][Self::as_mut_slice].
-            #[doc(alias = "slice")]
-            pub fn subarray(&self, begin: usize, end: usize) -> Self {
-                let len = self.len();
-                let begin = begin.min(len);
-                let end = end.min(len);
-                self.as_inner().slice(to_i64(begin), to_i64(end))
+            fn op_equals(lhs: &PackedArray<Self>, rhs: &PackedArray<Self>) -> bool {
+                unsafe {
+                    let mut result = false;
+                    sys::builtin_call! {
+                        $operator_equal(lhs.sys(), rhs.sys(), result.sys_mut())
+                    };
+                    result
+                }
             }
 
-            /// Returns a shared Rust slice of the array.
-            ///
-            /// The resulting slice can be further subdivided or converted into raw pointers.
-            ///
-            /// See also [`as_mut_slice`][Self::as_mut_slice] to get exclusive slices, and
-            /// [`subarray`][Self::subarray] to get a sub-array as a copy.
-            pub fn as_slice(&self) -> &[$Element] {
-                if self.is_empty() {
-                    &[]
-                } else {
-                    let data = self.ptr(0);
-
-                    // SAFETY: PackedArray holds `len` elements in contiguous storage, all of which are initialized.
-                    // The array uses copy-on-write semantics, so the slice may be aliased, but copies will use a new allocation.
-                    unsafe {
-                        std::slice::from_raw_parts(data, self.len())
-                    }
+            fn op_from_array<U: GodotType>(array: &Array<U>) -> PackedArray<Self> {
+                unsafe {
+                    PackedArray::from_sys_init(|self_ptr| {
+                        let args = [array.sys_const()];
+                        sys::builtin_call! {
+                            $from_array(self_ptr, args.as_ptr())
+                        }
+                    })
                 }
             }
 
-            /// Returns an exclusive Rust slice of the array.
-            ///
-            /// The resulting slice can be further subdivided or converted into raw pointers.
-            ///
-            /// See also [`as_slice`][Self::as_slice] to get shared slices, and
-            /// [`subarray`][Self::subarray] to get a sub-array as a copy.
-            pub fn as_mut_slice(&mut self) -> &mut [$Element] {
-                if self.is_empty() {
-                    &mut []
-                } else {
-                    let data = self.ptr_mut(0);
-
-                    // SAFETY: PackedArray holds `len` elements in contiguous storage, all of which are initialized.
-                    // The array uses copy-on-write semantics. ptr_mut() triggers a copy if non-unique, after which the slice is never aliased.
-                    unsafe {
-                        std::slice::from_raw_parts_mut(data, self.len())
-                    }
+            fn op_to_variant(array: &PackedArray<Self>) -> Variant {
+                array.to_variant()
+            }
+
+            fn op_index_const(array: &PackedArray<Self>, index: usize) -> *const Self {
+                // SAFETY: The caller checked that the index is not out of bounds.
+                unsafe {
+                    let item_ptr: *const $IndexRetType =
+                        (interface_fn!($operator_index_const))(array.sys(), to_i64(index));
+                    item_ptr as *const Self
                 }
             }
 
-            /// Returns a copy of the value at the specified index.
-            ///
-            /// # Panics
-            ///
-            /// If `index` is out of bounds.
-            pub fn get(&self, index: usize) -> $Element {
-                let ptr = self.ptr(index);
-                // SAFETY: `ptr` just verified that the index is not out of bounds.
-                unsafe { (*ptr).clone() }
+            fn op_index(array: &PackedArray<Self>, index: usize) -> *mut Self {
+                // SAFETY: The caller checked that the index is not out of bounds.
+                unsafe {
+                    let item_ptr: *mut $IndexRetType =
+                        (interface_fn!($operator_index))(array.sys(), to_i64(index));
+                    item_ptr as *mut Self
+                }
             }
 
-            /// Finds the index of an existing value in a sorted array using binary search.
-            /// Equivalent of `bsearch` in GDScript.
-            ///
-            /// If the value is not present in the array, returns the insertion index that would
-            /// maintain sorting order.
-            ///
-            /// Calling `binary_search` on an unsorted array results in unspecified behavior.
-            pub fn binary_search(&self, value: $Element) -> usize {
-                to_usize(self.as_inner().bsearch(Self::into_arg(value), true))
+            fn op_size(array: &PackedArray<Self>) -> i64 {
+                array.as_inner().size()
             }
 
-            /// Returns the number of times a value is in the array.
-            pub fn count(&self, value: $Element) -> usize {
-                to_usize(self.as_inner().count(Self::into_arg(value)))
+            fn op_is_empty(array: &PackedArray<Self>) -> bool {
+                array.as_inner().is_empty()
             }
 
-            /// Returns `true` if the array contains the given value. Equivalent of `has` in
-            /// GDScript.
-            pub fn contains(&self, value: $Element) -> bool {
-                self.as_inner().has(Self::into_arg(value))
+            fn op_clear(array: &mut PackedArray<Self>) {
+                array.as_inner().clear();
             }
 
-            /// Searches the array for the first occurrence of a value and returns its index, or
-            /// `None` if not found. Starts searching at index `from`; pass `None` to search the
-            /// entire array.
-            pub fn find(&self, value: $Element, from: Option<usize>) -> Option<usize> {
-                let from = to_i64(from.unwrap_or(0));
-                let index = self.as_inner().find(Self::into_arg(value), from);
-                if index >= 0 {
-                    Some(index.try_into().unwrap())
-                } else {
-                    None
-                }
+            fn op_resize(array: &mut PackedArray<Self>, size: i64) {
+                array.as_inner().resize(size);
             }
 
-            /// Searches the array backwards for the last occurrence of a value and returns its
-            /// index, or `None` if not found. Starts searching at index `from`; pass `None` to
-            /// search the entire array.
-            pub fn rfind(&self, value: $Element, from: Option<usize>) -> Option<usize> {
-                let from = from.map(to_i64).unwrap_or(-1);
-                let index = self.as_inner().rfind(Self::into_arg(value), from);
-                // It's not documented, but `rfind` returns -1 if not found.
-                if index >= 0 {
-                    Some(to_usize(index))
-                } else {
-                    None
-                }
+            fn op_slice(array: &PackedArray<Self>, begin: i64, end: i64) -> PackedArray<Self> {
+                array.as_inner().slice(begin, end)
             }
 
-            /// Sets the value at the specified index.
-            ///
-            /// # Panics
-            ///
-            /// If `index` is out of bounds.
-            pub fn set(&mut self, index: usize, value: $Element) {
-                let ptr_mut = self.ptr_mut(index);
+            fn op_bsearch(array: &PackedArray<Self>, value: Self) -> i64 {
+                array.as_inner().bsearch($PackedArray::into_arg(value), true)
+            }
 
-                // SAFETY: `ptr_mut` just checked that the index is not out of bounds.
-                unsafe {
-                    *ptr_mut = value;
-                }
+            fn op_count(array: &PackedArray<Self>, value: Self) -> i64 {
+                array.as_inner().count($PackedArray::into_arg(value))
             }
 
-            /// Appends an element to the end of the array. Equivalent of `append` and `push_back`
-            /// in GDScript.
-            #[doc(alias = "append")]
-            #[doc(alias = "push_back")]
-            pub fn push(&mut self, value: $Element) {
-                self.as_inner().push_back(Self::into_arg(value));
+            fn op_has(array: &PackedArray<Self>, value: Self) -> bool {
+                array.as_inner().has($PackedArray::into_arg(value))
             }
 
-            /// Inserts a new element at a given index in the array. The index must be valid, or at
-            /// the end of the array (`index == len()`).
-            ///
-            /// Note: On large arrays, this method is much slower than `push` as it will move all
-            /// the array's elements after the inserted element. The larger the array, the slower
-            /// `insert` will be.
-            pub fn insert(&mut self, index: usize, value: $Element) {
-                let len = self.len();
-                assert!(
-                    index <= len,
-                    "Array insertion index {index} is out of bounds: length is {len}");
-                self.as_inner().insert(to_i64(index), Self::into_arg(value));
-            }
-
-            /// Removes and returns the element at the specified index. Similar to `remove_at` in
-            /// GDScript, but also returns the removed value.
-            ///
-            /// On large arrays, this method is much slower than `pop_back` as it will move all the array's
-            /// elements after the removed element. The larger the array, the slower `remove` will be.
-            ///
-            /// # Panics
-            ///
-            /// If `index` is out of bounds.
-            // Design note: This returns the removed value instead of `()` for consistency with
-            // `Array` and with `Vec::remove`. Compared to shifting all the subsequent array
-            // elements to their new position, the overhead of retrieving this element is trivial.
-            #[doc(alias = "remove_at")]
-            pub fn remove(&mut self, index: usize) -> $Element {
-                self.check_bounds(index);
-                let element = self.get(index);
-                self.as_inner().remove_at(to_i64(index));
-                element
+            fn op_find(array: &PackedArray<Self>, value: Self, from: i64) -> i64 {
+                array.as_inner().find($PackedArray::into_arg(value), from)
             }
 
-            /// Assigns the given value to all elements in the array. This can be used together
-            /// with `resize` to create an array with a given size and initialized elements.
-            pub fn fill(&mut self, value: $Element) {
-                self.as_inner().fill(Self::into_arg(value));
+            fn op_rfind(array: &PackedArray<Self>, value: Self, from: i64) -> i64 {
+                array.as_inner().rfind($PackedArray::into_arg(value), from)
             }
 
-            /// Appends another array at the end of this array. Equivalent of `append_array` in
-            /// GDScript.
-            pub fn extend_array(&mut self, other: &$PackedArray) {
-                self.as_inner().append_array(other.clone());
+            fn op_push_back(array: &mut PackedArray<Self>, value: Self) {
+                array.as_inner().push_back($PackedArray::into_arg(value));
             }
 
-            /// Reverses the order of the elements in the array.
-            pub fn reverse(&mut self) {
-                self.as_inner().reverse();
+            fn op_insert(array: &mut PackedArray<Self>, index: i64, value: Self) {
+                array.as_inner().insert(index, $PackedArray::into_arg(value));
             }
 
-            /// Sorts the elements of the array in ascending order.
-            // Presumably, just like `Array`, this is not a stable sort so we might call it
-            // `sort_unstable`. But Packed*Array elements that compare equal are always identical,
-            // so it doesn't matter.
-            pub fn sort(&mut self) {
-                self.as_inner().sort();
+            fn op_remove_at(array: &mut PackedArray<Self>, index: i64) {
+                array.as_inner().remove_at(index);
             }
 
-            /// Asserts that the given index refers to an existing element.
-            ///
-            /// # Panics
-            ///
-            /// If `index` is out of bounds.
-            fn check_bounds(&self, index: usize) {
-                let len = self.len();
-                assert!(
-                    index < len,
-                    "Array index {index} is out of bounds: length is {len}");
+            fn op_fill(array: &mut PackedArray<Self>, value: Self) {
+                array.as_inner().fill($PackedArray::into_arg(value));
             }
 
-            /// Returns a pointer to the element at the given index.
-            ///
-            /// # Panics
-            ///
-            /// If `index` is out of bounds.
-            fn ptr(&self, index: usize) -> *const $Element {
-                self.check_bounds(index);
-                // SAFETY: We just checked that the index is not out of bounds.
-                let ptr = unsafe {
-                    let item_ptr: *const $IndexRetType =
-                        (interface_fn!($operator_index_const))(self.sys(), to_i64(index));
-                    item_ptr as *const $Element
-                };
-                assert!(!ptr.is_null());
-                ptr
+            fn op_append_array(array: &mut PackedArray<Self>, other: &PackedArray<Self>) {
+                array.as_inner().append_array(other.clone());
             }
 
-            /// Returns a mutable pointer to the element at the given index.
-            ///
-            /// # Panics
-            ///
-            /// If `index` is out of bounds.
-            fn ptr_mut(&self, index: usize) -> *mut $Element {
-                self.check_bounds(index);
+            fn op_reverse(array: &mut PackedArray<Self>) {
+                array.as_inner().reverse();
+            }
 
-                // SAFETY: We just checked that the index is not out of bounds.
-                let ptr = unsafe {
-                    let item_ptr: *mut $IndexRetType =
-                        (interface_fn!($operator_index))(self.sys(), to_i64(index));
-                    item_ptr as *mut $Element
-                };
-                assert!(!ptr.is_null());
-                ptr
+            fn op_sort(array: &mut PackedArray<Self>) {
+                array.as_inner().sort();
             }
+        }
 
+        impl $PackedArray {
             #[doc = concat!("Converts a `", stringify!($Element), "` into a value that can be")]
             /// passed into API functions. For most types, this is a no-op. But `u8` and `i32` are
             /// widened to `i64`, and `real` is widened to `f64` if it is an `f32`.
@@ -372,267 +302,1174 @@ macro_rules! impl_packed_array {
             }
         }
 
-        impl_builtin_traits! {
-            for $PackedArray {
-                $($trait_impls)*
-            }
+        $crate::builtin::meta::impl_godot_as_self!($PackedArray);
+    };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Generic packed array
+
+/// Implements Godot's `Packed*Array` types, which are efficient arrays of a single element type.
+///
+/// Each element type `T` corresponds to one Godot type, which is available under its familiar name as a type alias:
+///
+/// | Element type | Alias                    |
+/// |--------------|--------------------------|
+/// | `u8`         | [`PackedByteArray`]      |
+/// | `i32`        | [`PackedInt32Array`]     |
+/// | `i64`        | [`PackedInt64Array`]     |
+/// | `f32`        | [`PackedFloat32Array`]   |
+/// | `f64`        | [`PackedFloat64Array`]   |
+/// | [`GString`]  | [`PackedStringArray`]    |
+/// | [`Vector2`]  | [`PackedVector2Array`]   |
+/// | [`Vector3`]  | [`PackedVector3Array`]   |
+/// | [`Color`]    | [`PackedColorArray`]     |
+///
+/// Functionality common to all of them can be used in generic code, by accepting `PackedArray<T>` with a
+/// [`PackedArrayElement`] bound:
+///
+/// ```no_run
+/// use godot::builtin::{PackedArray, PackedArrayElement};
+///
+/// fn last_two<T: PackedArrayElement>(array: &PackedArray<T>) -> PackedArray<T> {
+///     let len = array.len();
+///     array.subarray(len.saturating_sub(2), len)
+/// }
+/// ```
+///
+/// Note that, unlike `Array`, this type has value semantics: each copy will be independent
+/// of the original. (Under the hood, Godot uses copy-on-write, so copies are still cheap
+/// to make.)
+///
+/// # Thread safety
+///
+/// Usage is safe if the packed array is used on a single thread only. Concurrent reads on different threads are also safe,
+/// but any writes must be externally synchronized. The Rust compiler will enforce this as
+/// long as you use only Rust threads, but it cannot protect against concurrent modification
+/// on other threads (e.g. created through GDScript).
+// All packed arrays share the same opaque layout; `OpaquePackedByteArray` is used as storage for each of them (checked below).
+#[repr(C)]
+pub struct PackedArray<T: PackedArrayElement> {
+    opaque: OpaquePackedByteArray,
+    _phantom: PhantomData<T>,
+}
+
+const _: () = {
+    const SIZE: usize = std::mem::size_of::<OpaquePackedByteArray>();
+    assert!(std::mem::size_of::<OpaquePackedInt32Array>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedInt64Array>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedFloat32Array>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedFloat64Array>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedStringArray>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedVector2Array>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedVector3Array>() == SIZE);
+    assert!(std::mem::size_of::<OpaquePackedColorArray>() == SIZE);
+};
+
+impl<T: PackedArrayElement> PackedArray<T> {
+    fn from_opaque(opaque: OpaquePackedByteArray) -> Self {
+        Self {
+            opaque,
+            _phantom: PhantomData,
         }
+    }
 
-        #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given Rust array.")]
-        impl<const N: usize> From<&[$Element; N]> for $PackedArray {
-            fn from(arr: &[$Element; N]) -> Self {
-                Self::from(&arr[..])
-            }
+    /// Constructs an empty array.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of elements in the array. Equivalent of `size()` in Godot.
+    pub fn len(&self) -> usize {
+        to_usize(T::op_size(self))
+    }
+
+    /// Returns `true` if the array is empty.
+    pub fn is_empty(&self) -> bool {
+        T::op_is_empty(self)
+    }
+
+    /// Converts this array to a Rust vector, making a copy of its contents.
+    pub fn to_vec(&self) -> Vec<T> {
+        // Packed arrays are stored contiguously, so the whole storage is copied at once.
+        self.as_slice().to_vec()
+    }
+
+    /// Clears the array, removing all elements.
+    pub fn clear(&mut self) {
+        T::op_clear(self);
+    }
+
+    /// Resizes the array to contain a different number of elements. If the new size is
+    /// smaller, elements are removed from the end. If the new size is larger, new elements
+    /// are set to [`Default::default()`].
+    pub fn resize(&mut self, size: usize) {
+        T::op_resize(self, to_i64(size));
+    }
+
+    /// Returns a sub-range `begin..end`, as a new packed array.
+    ///
+    /// The values of `begin` (inclusive) and `end` (exclusive) will be clamped to the array size.
+    ///
+    /// To obtain Rust slices, see [`as_slice`][Self::as_slice] and [`as_mut_slice`][Self::as_mut_slice].
+    #[doc(alias = "slice")]
+    pub fn subarray(&self, begin: usize, end: usize) -> Self {
+        let len = self.len();
+        let begin = begin.min(len);
+        let end = end.min(len);
+        T::op_slice(self, to_i64(begin), to_i64(end))
+    }
+
+    /// Returns a shared Rust slice of the array.
+    ///
+    /// The resulting slice can be further subdivided or converted into raw pointers.
+    ///
+    /// See also [`as_mut_slice`][Self::as_mut_slice] to get exclusive slices, and
+    /// [`subarray`][Self::subarray] to get a sub-array as a copy.
+    pub fn as_slice(&self) -> &[T] {
+        if self.is_empty() {
+            &[]
+        } else {
+            let data = self.ptr(0);
+
+            // SAFETY: PackedArray holds `len` elements in contiguous storage, all of which are initialized.
+            // The array uses copy-on-write semantics, so the slice may be aliased, but copies will use a new allocation.
+            unsafe { std::slice::from_raw_parts(data, self.len()) }
         }
+    }
 
-        #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given slice.")]
-        impl From<&[$Element]> for $PackedArray {
-            fn from(slice: &[$Element]) -> Self {
-                let mut array = Self::new();
-                let len = slice.len();
-                if len == 0 {
-                    return array;
-                }
-                array.resize(len);
-                let ptr = array.ptr_mut(0);
-                for (i, element) in slice.iter().enumerate() {
-                    // SAFETY: The array contains exactly `len` elements, stored contiguously in memory.
-                    unsafe {
-                        // `GString` does not implement `Copy` so we have to call `.clone()`
-                        // here.
-                        *ptr.offset(to_isize(i)) = element.clone();
-                    }
-                }
-                array
-            }
+    /// Returns an exclusive Rust slice of the array.
+    ///
+    /// The resulting slice can be further subdivided or converted into raw pointers.
+    ///
+    /// See also [`as_slice`][Self::as_slice] to get shared slices, and
+    /// [`subarray`][Self::subarray] to get a sub-array as a copy.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        if self.is_empty() {
+            &mut []
+        } else {
+            let data = self.ptr_mut(0);
+
+            // SAFETY: PackedArray holds `len` elements in contiguous storage, all of which are initialized.
+            // The array uses copy-on-write semantics. ptr_mut() triggers a copy if non-unique, after which the slice is never aliased.
+            unsafe { std::slice::from_raw_parts_mut(data, self.len()) }
         }
+    }
 
-        #[doc = concat!("Creates a `", stringify!($PackedArray), "` from an iterator.")]
-        impl FromIterator<$Element> for $PackedArray {
-            fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
-                let mut array = $PackedArray::default();
-                array.extend(iter);
-                array
-            }
+    /// Returns a copy of the value at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn get(&self, index: usize) -> T {
+        let ptr = self.ptr(index);
+        // SAFETY: `ptr` just verified that the index is not out of bounds.
+        unsafe { (*ptr).clone() }
+    }
+
+    /// Finds the index of an existing value in a sorted array using binary search.
+    /// Equivalent of `bsearch` in GDScript.
+    ///
+    /// If the value is not present in the array, returns the insertion index that would
+    /// maintain sorting order.
+    ///
+    /// Calling `binary_search` on an unsorted array results in unspecified behavior.
+    pub fn binary_search(&self, value: T) -> usize {
+        to_usize(T::op_bsearch(self, value))
+    }
+
+    /// Returns the number of times a value is in the array.
+    pub fn count(&self, value: T) -> usize {
+        to_usize(T::op_count(self, value))
+    }
+
+    /// Returns `true` if the array contains the given value. Equivalent of `has` in
+    /// GDScript.
+    pub fn contains(&self, value: T) -> bool {
+        T::op_has(self, value)
+    }
+
+    /// Searches the array for the first occurrence of a value and returns its index, or
+    /// `None` if not found. Starts searching at index `from`; pass `None` to search the
+    /// entire array.
+    pub fn find(&self, value: T, from: Option<usize>) -> Option<usize> {
+        let from = to_i64(from.unwrap_or(0));
+        let index = T::op_find(self, value, from);
+        if index >= 0 {
+            Some(index.try_into().unwrap())
+        } else {
+            None
         }
+    }
 
-        #[doc = concat!("Extends a`", stringify!($PackedArray), "` with the contents of an iterator")]
-        impl Extend<$Element> for $PackedArray {
-            fn extend<I: IntoIterator<Item = $Element>>(&mut self, iter: I) {
-                // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`.
-                // Otherwise we could use it to pre-allocate based on `iter.size_hint()`.
-                //
-                // A faster implementation using `resize()` and direct pointer writes might still be
-                // possible.
-                for item in iter.into_iter() {
-                    self.push(item);
-                }
-            }
+    /// Searches the array backwards for the last occurrence of a value and returns its
+    /// index, or `None` if not found. Starts searching at index `from`; pass `None` to
+    /// search the entire array.
+    pub fn rfind(&self, value: T, from: Option<usize>) -> Option<usize> {
+        let from = from.map(to_i64).unwrap_or(-1);
+        let index = T::op_rfind(self, value, from);
+        // It's not documented, but `rfind` returns -1 if not found.
+        if index >= 0 {
+            Some(to_usize(index))
+        } else {
+            None
         }
+    }
 
-        impl_builtin_froms!($PackedArray; VariantArray => $from_array);
+    /// Sets the value at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) {
+        let ptr_mut = self.ptr_mut(index);
 
-        impl fmt::Debug for $PackedArray {
-            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                // Going through `Variant` because there doesn't seem to be a direct way.
-                write!(f, "{:?}", self.to_variant().stringify())
-            }
+        // SAFETY: `ptr_mut` just checked that the index is not out of bounds.
+        unsafe {
+            *ptr_mut = value;
         }
+    }
 
-        unsafe impl GodotFfi for $PackedArray {
-            fn variant_type() -> sys::VariantType {
-                sys::VariantType::$PackedArray
-            }
+    /// Appends an element to the end of the array. Equivalent of `append` and `push_back`
+    /// in GDScript.
+    #[doc(alias = "append")]
+    #[doc(alias = "push_back")]
+    pub fn push(&mut self, value: T) {
+        T::op_push_back(self, value);
+    }
 
-            ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque;
-                fn from_sys;
-                fn sys;
-                fn from_sys_init;
-                // SAFETY:
-                // Nothing special needs to be done beyond a `std::mem::swap` when returning a packed array.
-                fn move_return_ptr;
-            }
+    /// Inserts a new element at a given index in the array. The index must be valid, or at
+    /// the end of the array (`index == len()`).
+    ///
+    /// Note: On large arrays, this method is much slower than `push` as it will move all
+    /// the array's elements after the inserted element. The larger the array, the slower
+    /// `insert` will be.
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "Array insertion index {index} is out of bounds: length is {len}"
+        );
+        T::op_insert(self, to_i64(index), value);
+    }
 
-            // SAFETY:
-            // Packed arrays are properly initialized through a `from_sys` call, but the ref-count should be
-            // incremented as that is the callee's responsibility.
-            //
-            // Using `std::mem::forget(array.clone())` increments the ref count.
-            unsafe fn from_arg_ptr(ptr: sys::GDExtensionTypePtr, _call_type: sys::PtrcallType) -> Self {
-                let array = Self::from_sys(ptr);
-                std::mem::forget(array.clone());
-                array
-            }
+    /// Removes and returns the element at the specified index. Similar to `remove_at` in
+    /// GDScript, but also returns the removed value.
+    ///
+    /// On large arrays, this method is much slower than `pop_back` as it will move all the array's
+    /// elements after the removed element. The larger the array, the slower `remove` will be.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    // Design note: This returns the removed value instead of `()` for consistency with
+    // `Array` and with `Vec::remove`. Compared to shifting all the subsequent array
+    // elements to their new position, the overhead of retrieving this element is trivial.
+    #[doc(alias = "remove_at")]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.check_bounds(index);
+        let element = self.get(index);
+        T::op_remove_at(self, to_i64(index));
+        element
+    }
 
-            unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self {
-                let mut result = Self::default();
-                init_fn(result.sys_mut());
-                result
-            }
+    /// Assigns the given value to all elements in the array. This can be used together
+    /// with `resize` to create an array with a given size and initialized elements.
+    pub fn fill(&mut self, value: T) {
+        T::op_fill(self, value);
+    }
+
+    /// Appends all elements of a Rust slice at the end of this array.
+    ///
+    /// Unlike repeated [`push`][Self::push] calls, this resizes the array only once and then copies
+    /// the elements directly into Godot's storage.
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        if slice.is_empty() {
+            return;
         }
 
-        $crate::builtin::meta::impl_godot_as_self!($PackedArray);
+        let old_len = self.len();
+        self.resize(old_len + slice.len());
+
+        // `GString` is not `Copy`, so `clone_from_slice` is used. For all other element types, this
+        // compiles down to a memcpy.
+        self.as_mut_slice()[old_len..].clone_from_slice(slice);
+    }
+
+    /// Appends another array at the end of this array. Equivalent of `append_array` in
+    /// GDScript.
+    pub fn extend_array(&mut self, other: &Self) {
+        T::op_append_array(self, other);
+    }
+
+    /// Reverses the order of the elements in the array.
+    pub fn reverse(&mut self) {
+        T::op_reverse(self);
+    }
+
+    /// Sorts the elements of the array in ascending order.
+    // Presumably, just like `Array`, this is not a stable sort so we might call it
+    // `sort_unstable`. But Packed*Array elements that compare equal are always identical,
+    // so it doesn't matter.
+    pub fn sort(&mut self) {
+        T::op_sort(self);
+    }
+
+    /// Returns an iterator over shared references to the elements. Equivalent to `as_slice().iter()`.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Asserts that the given index refers to an existing element.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    fn check_bounds(&self, index: usize) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "Array index {index} is out of bounds: length is {len}"
+        );
+    }
+
+    /// Returns a pointer to the element at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    fn ptr(&self, index: usize) -> *const T {
+        self.check_bounds(index);
+
+        let ptr = T::op_index_const(self, index);
+        assert!(!ptr.is_null());
+        ptr
+    }
+
+    /// Returns a mutable pointer to the element at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    fn ptr_mut(&self, index: usize) -> *mut T {
+        self.check_bounds(index);
+
+        let ptr = T::op_index(self, index);
+        assert!(!ptr.is_null());
+        ptr
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Traits
+
+impl<T: PackedArrayElement> Default for PackedArray<T> {
+    #[inline]
+    fn default() -> Self {
+        T::op_default()
+    }
+}
+
+impl<T: PackedArrayElement> Clone for PackedArray<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        T::op_clone(self)
+    }
+}
+
+impl<T: PackedArrayElement> Drop for PackedArray<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: Called exactly once, when the array goes out of scope.
+        unsafe { T::op_destroy(self) }
+    }
+}
+
+impl<T: PackedArrayElement> PartialEq for PackedArray<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        T::op_equals(self, other)
+    }
+}
+
+// Arrays of floating-point values (including vectors and colors) are only `PartialEq`.
+impl Eq for PackedByteArray {}
+impl Eq for PackedInt32Array {}
+impl Eq for PackedInt64Array {}
+impl Eq for PackedStringArray {}
+
+impl<T: PackedArrayElement> fmt::Debug for PackedArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Going through `Variant` because there doesn't seem to be a direct way.
+        write!(f, "{:?}", T::op_to_variant(self).stringify())
+    }
+}
+
+/// Creates a packed array from the given Rust array.
+impl<T: PackedArrayElement, const N: usize> From<&[T; N]> for PackedArray<T> {
+    fn from(arr: &[T; N]) -> Self {
+        Self::from(&arr[..])
+    }
+}
+
+/// Creates a packed array from the given slice.
+impl<T: PackedArrayElement> From<&[T]> for PackedArray<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut array = Self::new();
+        array.extend_from_slice(slice);
+        array
+    }
+}
+
+/// Creates a packed array from the given Rust vector.
+impl<T: PackedArrayElement> From<Vec<T>> for PackedArray<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::from(vec.as_slice())
     }
 }
 
-impl_packed_array!(
+/// Converts a packed array into a Rust vector, copying its contents.
+impl<T: PackedArrayElement> From<PackedArray<T>> for Vec<T> {
+    fn from(array: PackedArray<T>) -> Self {
+        array.to_vec()
+    }
+}
+
+/// Converts a packed array into a Rust vector, copying its contents.
+impl<T: PackedArrayElement> From<&PackedArray<T>> for Vec<T> {
+    fn from(array: &PackedArray<T>) -> Self {
+        array.to_vec()
+    }
+}
+
+/// Creates a packed array from an untyped `Array`. Elements are converted by Godot.
+impl<T: PackedArrayElement> From<&VariantArray> for PackedArray<T> {
+    fn from(array: &VariantArray) -> Self {
+        T::op_from_array(array)
+    }
+}
+
+/// Creates a packed array from a typed `Array` of the same element type.
+impl<T: PackedArrayElement> From<&Array<T>> for PackedArray<T> {
+    fn from(array: &Array<T>) -> Self {
+        T::op_from_array(array)
+    }
+}
+
+/// Creates a typed `Array` from a packed array of the same element type.
+impl<T: PackedArrayElement> From<&PackedArray<T>> for Array<T> {
+    fn from(array: &PackedArray<T>) -> Self {
+        Array::from(array.as_slice())
+    }
+}
+
+/// Creates a packed array from an iterator.
+impl<T: PackedArrayElement> FromIterator<T> for PackedArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+/// Extends a packed array with the contents of an iterator.
+impl<T: PackedArrayElement> Extend<T> for PackedArray<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // The GDExtension API does not offer the equivalent of `Vec::reserve`, and pushing element by element is slow.
+        // Collect into a Rust buffer first, so that the packed array is resized only once.
+        let elements: Vec<T> = iter.into_iter().collect();
+        self.extend_from_slice(&elements);
+    }
+}
+
+/// Iterates over copies of the elements. The array's contents are copied once into a Rust vector.
+impl<T: PackedArrayElement> IntoIterator for PackedArray<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_vec().into_iter()
+    }
+}
+
+/// Iterates over shared references to the elements, without copying.
+impl<'a, T: PackedArrayElement> IntoIterator for &'a PackedArray<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: PackedArrayElement + serde::Serialize> serde::Serialize for PackedArray<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for PackedArray<T>
+where
+    T: PackedArrayElement + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = <Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Self::from(elements))
+    }
+}
+
+unsafe impl<T: PackedArrayElement> GodotFfi for PackedArray<T> {
+    fn variant_type() -> sys::VariantType {
+        T::packed_variant_type()
+    }
+
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque;
+        fn from_sys;
+        fn sys;
+        fn from_sys_init;
+        // SAFETY:
+        // Nothing special needs to be done beyond a `std::mem::swap` when returning a packed array.
+        fn move_return_ptr;
+    }
+
+    // SAFETY:
+    // Packed arrays are properly initialized through a `from_sys` call, but the ref-count should be
+    // incremented as that is the callee's responsibility.
+    //
+    // Using `std::mem::forget(array.clone())` increments the ref count.
+    unsafe fn from_arg_ptr(ptr: sys::GDExtensionTypePtr, _call_type: sys::PtrcallType) -> Self {
+        let array = Self::from_sys(ptr);
+        std::mem::forget(array.clone());
+        array
+    }
+
+    unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self {
+        let mut result = Self::default();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Element types
+
+impl_packed_array_element!(
     type_name: PackedByteArray,
     element_type: u8,
-    opaque_type: OpaquePackedByteArray,
     inner_type: InnerPackedByteArray,
     argument_type: i64,
     return_type: u8,
     from_array: packed_byte_array_from_array,
     operator_index: packed_byte_array_operator_index,
     operator_index_const: packed_byte_array_operator_index_const,
-    trait_impls: {
-        Default => packed_byte_array_construct_default;
-        Clone => packed_byte_array_construct_copy;
-        Drop => packed_byte_array_destroy;
-        Eq => packed_byte_array_operator_equal;
-    },
+    construct_default: packed_byte_array_construct_default,
+    construct_copy: packed_byte_array_construct_copy,
+    destroy: packed_byte_array_destroy,
+    operator_equal: packed_byte_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedInt32Array,
     element_type: i32,
-    opaque_type: OpaquePackedInt32Array,
     inner_type: InnerPackedInt32Array,
     argument_type: i64,
     return_type: i32,
     from_array: packed_int32_array_from_array,
     operator_index: packed_int32_array_operator_index,
     operator_index_const: packed_int32_array_operator_index_const,
-    trait_impls: {
-        Default => packed_int32_array_construct_default;
-        Clone => packed_int32_array_construct_copy;
-        Drop => packed_int32_array_destroy;
-        Eq => packed_int32_array_operator_equal;
-    },
+    construct_default: packed_int32_array_construct_default,
+    construct_copy: packed_int32_array_construct_copy,
+    destroy: packed_int32_array_destroy,
+    operator_equal: packed_int32_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedInt64Array,
     element_type: i64,
-    opaque_type: OpaquePackedInt64Array,
     inner_type: InnerPackedInt64Array,
     argument_type: i64,
     return_type: i64,
     from_array: packed_int64_array_from_array,
     operator_index: packed_int64_array_operator_index,
     operator_index_const: packed_int64_array_operator_index_const,
-    trait_impls: {
-        Default => packed_int64_array_construct_default;
-        Clone => packed_int64_array_construct_copy;
-        Drop => packed_int64_array_destroy;
-        Eq => packed_int64_array_operator_equal;
-    },
+    construct_default: packed_int64_array_construct_default,
+    construct_copy: packed_int64_array_construct_copy,
+    destroy: packed_int64_array_destroy,
+    operator_equal: packed_int64_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedFloat32Array,
     element_type: f32,
-    opaque_type: OpaquePackedFloat32Array,
     inner_type: InnerPackedFloat32Array,
     argument_type: f64,
     return_type: f32,
     from_array: packed_float32_array_from_array,
     operator_index: packed_float32_array_operator_index,
     operator_index_const: packed_float32_array_operator_index_const,
-    trait_impls: {
-        Default => packed_float32_array_construct_default;
-        Clone => packed_float32_array_construct_copy;
-        Drop => packed_float32_array_destroy;
-        PartialEq => packed_float32_array_operator_equal;
-    },
+    construct_default: packed_float32_array_construct_default,
+    construct_copy: packed_float32_array_construct_copy,
+    destroy: packed_float32_array_destroy,
+    operator_equal: packed_float32_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedFloat64Array,
     element_type: f64,
-    opaque_type: OpaquePackedFloat64Array,
     inner_type: InnerPackedFloat64Array,
     argument_type: f64,
     return_type: f64,
     from_array: packed_float64_array_from_array,
     operator_index: packed_float64_array_operator_index,
     operator_index_const: packed_float64_array_operator_index_const,
-    trait_impls: {
-        Default => packed_float64_array_construct_default;
-        Clone => packed_float64_array_construct_copy;
-        Drop => packed_float64_array_destroy;
-        PartialEq => packed_float64_array_operator_equal;
-    },
+    construct_default: packed_float64_array_construct_default,
+    construct_copy: packed_float64_array_construct_copy,
+    destroy: packed_float64_array_destroy,
+    operator_equal: packed_float64_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedStringArray,
     element_type: GString,
-    opaque_type: OpaquePackedStringArray,
     inner_type: InnerPackedStringArray,
     argument_type: GString,
     return_type: __GdextString,
     from_array: packed_string_array_from_array,
     operator_index: packed_string_array_operator_index,
     operator_index_const: packed_string_array_operator_index_const,
-    trait_impls: {
-        Default => packed_string_array_construct_default;
-        Clone => packed_string_array_construct_copy;
-        Drop => packed_string_array_destroy;
-        Eq => packed_string_array_operator_equal;
-    },
+    construct_default: packed_string_array_construct_default,
+    construct_copy: packed_string_array_construct_copy,
+    destroy: packed_string_array_destroy,
+    operator_equal: packed_string_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedVector2Array,
     element_type: Vector2,
-    opaque_type: OpaquePackedVector2Array,
     inner_type: InnerPackedVector2Array,
     argument_type: Vector2,
     return_type: __GdextType,
     from_array: packed_vector2_array_from_array,
     operator_index: packed_vector2_array_operator_index,
     operator_index_const: packed_vector2_array_operator_index_const,
-    trait_impls: {
-        Default => packed_vector2_array_construct_default;
-        Clone => packed_vector2_array_construct_copy;
-        Drop => packed_vector2_array_destroy;
-        PartialEq => packed_vector2_array_operator_equal;
-    },
+    construct_default: packed_vector2_array_construct_default,
+    construct_copy: packed_vector2_array_construct_copy,
+    destroy: packed_vector2_array_destroy,
+    operator_equal: packed_vector2_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedVector3Array,
     element_type: Vector3,
-    opaque_type: OpaquePackedVector3Array,
     inner_type: InnerPackedVector3Array,
     argument_type: Vector3,
     return_type: __GdextType,
     from_array: packed_vector3_array_from_array,
     operator_index: packed_vector3_array_operator_index,
     operator_index_const: packed_vector3_array_operator_index_const,
-    trait_impls: {
-        Default => packed_vector3_array_construct_default;
-        Clone => packed_vector3_array_construct_copy;
-        Drop => packed_vector3_array_destroy;
-        PartialEq => packed_vector3_array_operator_equal;
-    },
+    construct_default: packed_vector3_array_construct_default,
+    construct_copy: packed_vector3_array_construct_copy,
+    destroy: packed_vector3_array_destroy,
+    operator_equal: packed_vector3_array_operator_equal,
 );
 
-impl_packed_array!(
+impl_packed_array_element!(
     type_name: PackedColorArray,
     element_type: Color,
-    opaque_type: OpaquePackedColorArray,
     inner_type: InnerPackedColorArray,
     argument_type: Color,
     return_type: __GdextType,
     from_array: packed_color_array_from_array,
     operator_index: packed_color_array_operator_index,
     operator_index_const: packed_color_array_operator_index_const,
-    trait_impls: {
-        Default => packed_color_array_construct_default;
-        Clone => packed_color_array_construct_copy;
-        Drop => packed_color_array_destroy;
-        PartialEq => packed_color_array_operator_equal;
-    },
+    construct_default: packed_color_array_construct_default,
+    construct_copy: packed_color_array_construct_copy,
+    destroy: packed_color_array_destroy,
+    operator_equal: packed_color_array_operator_equal,
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Component reinterpretation
+
+/// Implements reinterpretation of packed arrays whose elements are plain `#[repr(C)]` aggregates of a single
+/// scalar type, as Rust arrays of that scalar (e.g. `Vector3` as `[real; 3]`).
+macro_rules! impl_packed_array_components {
+    (
+        type_name: $PackedArray:ident,
+        element_type: $Element:ty,
+        component_type: $Component:ty,
+        components: $N:literal,
+    ) => {
+        // Layout of the element must be identical to the component array, so that slices can be reinterpreted.
+        const _: () = {
+            assert!(std::mem::size_of::<$Element>() == std::mem::size_of::<[$Component; $N]>());
+            assert!(std::mem::align_of::<$Element>() == std::mem::align_of::<[$Component; $N]>());
+        };
+
+        impl $PackedArray {
+            #[doc = concat!("Creates a `", stringify!($PackedArray), "` from a slice of `[", stringify!($Component), "; ", stringify!($N), "]` arrays.")]
+            ///
+            /// This is a bulk copy and useful for data coming from Rust libraries that store components in plain arrays,
+            /// e.g. vertex buffers of mesh or physics crates.
+            pub fn from_components(components: &[[$Component; $N]]) -> Self {
+                let mut array = Self::new();
+                array.resize(components.len());
+                array.as_mut_components().copy_from_slice(components);
+                array
+            }
+
+            #[doc = concat!("Returns a shared slice of the array, with each element reinterpreted as `[", stringify!($Component), "; ", stringify!($N), "]`.")]
+            ///
+            /// No copy is made. See also [`as_slice`][Self::as_slice].
+            pub fn as_components(&self) -> &[[$Component; $N]] {
+                let slice = self.as_slice();
+
+                // SAFETY: The element type is `#[repr(C)]` with exactly `N` fields of the component type; size and
+                // alignment are verified at compile time above. Every bit pattern is valid for the component type.
+                unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const [$Component; $N], slice.len()) }
+            }
+
+            #[doc = concat!("Returns an exclusive slice of the array, with each element reinterpreted as `[", stringify!($Component), "; ", stringify!($N), "]`.")]
+            ///
+            /// No copy is made. See also [`as_mut_slice`][Self::as_mut_slice].
+            pub fn as_mut_components(&mut self) -> &mut [[$Component; $N]] {
+                let slice = self.as_mut_slice();
+
+                // SAFETY: See `as_components()`.
+                unsafe {
+                    std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut [$Component; $N], slice.len())
+                }
+            }
+        }
+
+        #[doc = concat!("Creates a `", stringify!($PackedArray), "` from a slice of component arrays. See [`", stringify!($PackedArray), "::from_components()`].")]
+        impl From<&[[$Component; $N]]> for $PackedArray {
+            fn from(components: &[[$Component; $N]]) -> Self {
+                Self::from_components(components)
+            }
+        }
+    };
+}
+
+impl_packed_array_components!(
+    type_name: PackedVector2Array,
+    element_type: Vector2,
+    component_type: real,
+    components: 2,
+);
+
+impl_packed_array_components!(
+    type_name: PackedVector3Array,
+    element_type: Vector3,
+    component_type: real,
+    components: 3,
+);
+
+impl_packed_array_components!(
+    type_name: PackedColorArray,
+    element_type: Color,
+    component_type: f32,
+    components: 4,
+);
+
+#[cfg(feature = "bytemuck")]
+impl PackedByteArray {
+    /// Creates a byte array from the raw bytes of a slice of [`Pod`][bytemuck::Pod] values.
+    ///
+    /// The bytes are copied in native endianness. Built-in types such as [`Vector3`] or [`Color`] implement `Pod`
+    /// when the `bytemuck` feature is enabled, so e.g. vertex or pixel data can be passed to Godot in one copy.
+    pub fn from_pod_slice<T: bytemuck::Pod>(values: &[T]) -> Self {
+        Self::from(bytemuck::cast_slice::<T, u8>(values))
+    }
+
+    /// Copies the bytes of this array into a vector of [`Pod`][bytemuck::Pod] values.
+    ///
+    /// Godot does not guarantee any particular alignment of the byte storage, so the data is always copied.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the array is not a multiple of `size_of::<T>()`.
+    pub fn to_pod_vec<T: bytemuck::Pod>(&self) -> Result<Vec<T>, bytemuck::PodCastError> {
+        let bytes = self.as_slice();
+        let size = std::mem::size_of::<T>();
+        if size == 0 || bytes.len() % size != 0 {
+            return Err(bytemuck::PodCastError::OutputSliceWouldHaveSlop);
+        }
+
+        let mut values = vec![T::zeroed(); bytes.len() / size];
+        bytemuck::cast_slice_mut::<T, u8>(&mut values).copy_from_slice(bytes);
+        Ok(values)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Binary encoding on PackedByteArray
+
+/// Defines `decode_*` and `encode_*` methods for a primitive number type, using little-endian byte order.
+macro_rules! impl_byte_array_codec {
+    ($( $Ty:ty => $decode:ident, $encode:ident; )*) => {
+        // Result<_, ()> mirrors the GDScript API, where the only failure is that the value does not fit.
+        #[allow(clippy::result_unit_err)]
+        impl PackedByteArray {
+            $(
+                #[doc = concat!("Reads a `", stringify!($Ty), "` in little-endian byte order, starting at `byte_offset`.")]
+                ///
+                /// # Errors
+                ///
+                /// If the value does not fit into the array at the given offset.
+                pub fn $decode(&self, byte_offset: usize) -> Result<$Ty, ()> {
+                    let bytes = self.byte_range(byte_offset, std::mem::size_of::<$Ty>())?;
+                    Ok(<$Ty>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+
+                #[doc = concat!("Writes a `", stringify!($Ty), "` in little-endian byte order, starting at `byte_offset`.")]
+                ///
+                /// The array is not resized; use [`resize()`][Self::resize] beforehand if necessary.
+                ///
+                /// # Errors
+                ///
+                /// If the value does not fit into the array at the given offset. The array is not modified in that case.
+                pub fn $encode(&mut self, byte_offset: usize, value: $Ty) -> Result<(), ()> {
+                    let bytes = value.to_le_bytes();
+                    self.byte_range_mut(byte_offset, bytes.len())?.copy_from_slice(&bytes);
+                    Ok(())
+                }
+            )*
+        }
+    };
+}
+
+impl_byte_array_codec! {
+    u8 => decode_u8, encode_u8;
+    i8 => decode_s8, encode_s8;
+    u16 => decode_u16, encode_u16;
+    i16 => decode_s16, encode_s16;
+    u32 => decode_u32, encode_u32;
+    i32 => decode_s32, encode_s32;
+    u64 => decode_u64, encode_u64;
+    i64 => decode_s64, encode_s64;
+    f32 => decode_float, encode_float;
+    f64 => decode_double, encode_double;
+}
+
+// Result<_, ()> mirrors the GDScript API, where failures carry no further information.
+#[allow(clippy::result_unit_err)]
+impl PackedByteArray {
+    /// Returns `true` if a valid `Variant` value can be decoded at `byte_offset`.
+    ///
+    /// If `allow_objects` is `false`, encoded objects are not considered valid.
+    pub fn has_encoded_var(&self, byte_offset: usize, allow_objects: bool) -> bool {
+        byte_offset < self.len()
+            && self
+                .as_inner()
+                .has_encoded_var(to_i64(byte_offset), allow_objects)
+    }
+
+    /// Decodes a `Variant` from the bytes starting at `byte_offset`, in Godot's binary serialization format.
+    ///
+    /// If `allow_objects` is `false`, encoded objects are rejected; note that decoding objects may instantiate arbitrary classes
+    /// and should not be used with untrusted data. Use [`decode_var_size()`][Self::decode_var_size] to find out how many bytes
+    /// the value occupies.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes at the given offset do not hold a valid encoded variant.
+    pub fn decode_var(&self, byte_offset: usize, allow_objects: bool) -> Result<Variant, ()> {
+        // Every encoded variant starts with a 4-byte header, whose lowest byte is the variant type.
+        let header = self.decode_u32(byte_offset)?;
+        let variant = self
+            .as_inner()
+            .decode_var(to_i64(byte_offset), allow_objects);
+
+        // Godot returns nil on failure, so a nil result is only valid if nil was encoded.
+        if variant.sys_type() == sys::GDEXTENSION_VARIANT_TYPE_NIL && header & 0xFF != 0 {
+            Err(())
+        } else {
+            Ok(variant)
+        }
+    }
+
+    /// Returns the number of bytes that the `Variant` encoded at `byte_offset` occupies.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes at the given offset do not hold a valid encoded variant.
+    pub fn decode_var_size(&self, byte_offset: usize, allow_objects: bool) -> Result<usize, ()> {
+        if byte_offset >= self.len() {
+            return Err(());
+        }
+
+        let size = self
+            .as_inner()
+            .decode_var_size(to_i64(byte_offset), allow_objects);
+
+        if size < 0 {
+            Err(())
+        } else {
+            Ok(to_usize(size))
+        }
+    }
+
+    /// Encodes `value` in Godot's binary serialization format, writing it at `byte_offset`.
+    ///
+    /// Returns the number of bytes written. The array is not resized; use [`resize()`][Self::resize] beforehand if necessary.
+    ///
+    /// # Errors
+    ///
+    /// If the value cannot be encoded (e.g. an object, while `allow_objects` is `false`) or if the encoded value does not fit
+    /// into the array at the given offset. The array is not modified in that case.
+    pub fn encode_var(
+        &mut self,
+        byte_offset: usize,
+        value: &Variant,
+        allow_objects: bool,
+    ) -> Result<usize, ()> {
+        if byte_offset > self.len() {
+            return Err(());
+        }
+
+        let size = self
+            .as_inner()
+            .encode_var(to_i64(byte_offset), value.clone(), allow_objects);
+
+        if size < 0 {
+            Err(())
+        } else {
+            Ok(to_usize(size))
+        }
+    }
+
+    /// Returns a new array, holding the contents of this one compressed with `compression_mode`.
+    ///
+    /// An empty array is compressed to an empty array.
+    ///
+    /// # Errors
+    ///
+    /// If compression fails.
+    pub fn compress(&self, compression_mode: CompressionMode) -> Result<PackedByteArray, ()> {
+        // Godot signals failure with an empty result, which is only valid for empty input.
+        if self.is_empty() {
+            return Ok(PackedByteArray::new());
+        }
+
+        let compressed = self.as_inner().compress(compression_mode.ord() as i64);
+        populated_or_err(compressed)
+    }
+
+    /// Returns a new array, holding the contents of this one decompressed with `compression_mode`.
+    ///
+    /// `buffer_size` must be the exact size of the uncompressed data. If it is not known, use
+    /// [`decompress_dynamic()`][Self::decompress_dynamic]. An empty array, or a `buffer_size` of 0, is decompressed to an
+    /// empty array, matching [`compress()`][Self::compress].
+    ///
+    /// # Errors
+    ///
+    /// If decompression fails or yields no data.
+    pub fn decompress(
+        &self,
+        buffer_size: usize,
+        compression_mode: CompressionMode,
+    ) -> Result<PackedByteArray, ()> {
+        // Godot signals failure with an empty result, which is only valid for empty output.
+        if self.is_empty() || buffer_size == 0 {
+            return Ok(PackedByteArray::new());
+        }
+
+        let decompressed = self
+            .as_inner()
+            .decompress(to_i64(buffer_size), compression_mode.ord() as i64);
+
+        populated_or_err(decompressed)
+    }
+
+    /// Returns a new array, holding the contents of this one decompressed with `compression_mode`, without knowing the
+    /// uncompressed size in advance.
+    ///
+    /// The output is limited to `max_output_size` bytes, or unlimited if `None`. This is only supported for
+    /// [`COMPRESSION_DEFLATE`][CompressionMode::COMPRESSION_DEFLATE] and [`COMPRESSION_GZIP`][CompressionMode::COMPRESSION_GZIP].
+    /// Be careful with data from untrusted sources, as unlimited output allows decompression bombs.
+    ///
+    /// An empty array, or a `max_output_size` of 0, is decompressed to an empty array, matching [`compress()`][Self::compress].
+    ///
+    /// # Errors
+    ///
+    /// If decompression fails or yields no data.
+    pub fn decompress_dynamic(
+        &self,
+        max_output_size: Option<usize>,
+        compression_mode: CompressionMode,
+    ) -> Result<PackedByteArray, ()> {
+        if self.is_empty() || max_output_size == Some(0) {
+            return Ok(PackedByteArray::new());
+        }
+
+        let max_output_size = max_output_size.map(to_i64).unwrap_or(-1);
+        let decompressed = self
+            .as_inner()
+            .decompress_dynamic(max_output_size, compression_mode.ord() as i64);
+
+        populated_or_err(decompressed)
+    }
+
+    /// Interprets the bytes as ASCII and returns the resulting string. Equivalent of `get_string_from_ascii` in GDScript.
+    ///
+    /// See [`get_string_from_utf8()`][Self::get_string_from_utf8] for Unicode data.
+    pub fn get_string_from_ascii(&self) -> GString {
+        self.as_inner().get_string_from_ascii()
+    }
+
+    /// Interprets the bytes as UTF-8 and returns the resulting string. Equivalent of `get_string_from_utf8` in GDScript.
+    pub fn get_string_from_utf8(&self) -> GString {
+        self.as_inner().get_string_from_utf8()
+    }
+
+    /// Interprets the bytes as UTF-16 and returns the resulting string. Equivalent of `get_string_from_utf16` in GDScript.
+    pub fn get_string_from_utf16(&self) -> GString {
+        self.as_inner().get_string_from_utf16()
+    }
+
+    /// Interprets the bytes as UTF-32 and returns the resulting string. Equivalent of `get_string_from_utf32` in GDScript.
+    pub fn get_string_from_utf32(&self) -> GString {
+        self.as_inner().get_string_from_utf32()
+    }
+
+    /// Returns a hexadecimal representation of the bytes, e.g. `"0b2eff"`. Equivalent of `hex_encode` in GDScript.
+    pub fn hex_encode(&self) -> GString {
+        self.as_inner().hex_encode()
+    }
+
+    /// Returns a [`std::io`] adapter that reads and writes this array like a file, starting at position 0.
+    ///
+    /// See [`PackedByteArrayCursor`] for details.
+    pub fn into_cursor(self) -> PackedByteArrayCursor {
+        PackedByteArrayCursor::new(self)
+    }
+
+    fn byte_range(&self, byte_offset: usize, len: usize) -> Result<&[u8], ()> {
+        let end = byte_offset.checked_add(len).ok_or(())?;
+        self.as_slice().get(byte_offset..end).ok_or(())
+    }
+
+    fn byte_range_mut(&mut self, byte_offset: usize, len: usize) -> Result<&mut [u8], ()> {
+        let end = byte_offset.checked_add(len).ok_or(())?;
+        if end > self.len() {
+            // Checked before as_mut_slice(), which would otherwise trigger a copy-on-write for nothing.
+            return Err(());
+        }
+
+        Ok(&mut self.as_mut_slice()[byte_offset..end])
+    }
+}
+
+fn populated_or_err(array: PackedByteArray) -> Result<PackedByteArray, ()> {
+    if array.is_empty() {
+        Err(())
+    } else {
+        Ok(array)
+    }
+}
+
+/// [`std::io`] adapter over a [`PackedByteArray`], similar to [`std::io::Cursor<Vec<u8>>`][std::io::Cursor].
+///
+/// The cursor owns the array (which is cheap to clone, thanks to copy-on-write) and keeps track of a position. Reads consume bytes
+/// from the position onwards, writes overwrite existing bytes and grow the array when reaching its end. Together with
+/// [`Seek`][std::io::Seek], this allows using any Rust crate that operates on readers and writers for Godot byte buffers.
+///
+/// Obtain one with [`PackedByteArray::into_cursor()`] and get the array back with [`into_inner()`][Self::into_inner].
+#[derive(Clone, Debug, Default)]
+pub struct PackedByteArrayCursor {
+    array: PackedByteArray,
+    position: usize,
+}
+
+impl PackedByteArrayCursor {
+    /// Creates a cursor at position 0 of the given array.
+    pub fn new(array: PackedByteArray) -> Self {
+        Self { array, position: 0 }
+    }
+
+    /// Returns the current position, in bytes from the start of the array.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the cursor to the given position. Positions past the end are allowed; a subsequent write fills the gap with zeros.
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// Returns a reference to the underlying array.
+    pub fn get_ref(&self) -> &PackedByteArray {
+        &self.array
+    }
+
+    /// Consumes the cursor and returns the underlying array.
+    pub fn into_inner(self) -> PackedByteArray {
+        self.array
+    }
+}
+
+impl std::io::Read for PackedByteArrayCursor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = self
+            .array
+            .as_slice()
+            .get(self.position..)
+            .unwrap_or_default();
+        let len = buf.len().min(remaining.len());
+
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+impl std::io::Write for PackedByteArrayCursor {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let end = self.position.checked_add(buf.len()).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "cursor position overflow")
+        })?;
+
+        if end > self.array.len() {
+            self.array.resize(end);
+        }
+
+        self.array.as_mut_slice()[self.position..end].copy_from_slice(buf);
+        self.position = end;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl std::io::Seek for PackedByteArrayCursor {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            std::io::SeekFrom::Start(offset) => usize::try_from(offset).ok(),
+            std::io::SeekFrom::End(offset) => isize::try_from(offset)
+                .ok()
+                .and_then(|offset| self.array.len().checked_add_signed(offset)),
+            std::io::SeekFrom::Current(offset) => isize::try_from(offset)
+                .ok()
+                .and_then(|offset| self.position.checked_add_signed(offset)),
+        };
+
+        let position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        self.position = position;
+        Ok(position as u64)
+    }
+}
@@ -7,9 +7,10 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use crate::builtin::inner::InnerProjection;
 use crate::builtin::math::{ApproxEq, GlamConv, GlamType};
-use crate::builtin::{real, Plane, RMat4, RealConv, Transform3D, Vector2, Vector4, Vector4Axis};
+use crate::builtin::{
+    real, Aabb, Plane, RMat4, Rect2, Transform3D, Vector2, Vector3, Vector4, Vector4Axis,
+};
 
 use std::ops::Mul;
 
@@ -24,9 +25,6 @@ use super::meta::impl_godot_as_self;
 /// memory footprint.
 ///
 /// Used internally as Camera3D's projection matrix.
-///
-/// Note: The current implementation largely makes calls to godot for its
-/// methods and as such are not as performant as other types.
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -84,6 +82,40 @@ impl Projection {
         )
     }
 
+    /// Creates a new Projection that scales a given [`Aabb`] to fit within the viewport, i.e. maps it to the
+    /// cube from `(-1, -1, -1)` to `(1, 1, 1)`.
+    ///
+    /// _Godot equivalent: Projection.create_fit_aabb()_
+    pub fn create_fit_aabb(aabb: Aabb) -> Self {
+        let min = aabb.position;
+        let max = aabb.position + aabb.size;
+        let extent = max - min;
+
+        Self::from_cols(
+            Vector4::new(2.0 / extent.x, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 2.0 / extent.y, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 2.0 / extent.z, 0.0),
+            Vector4::new(
+                -(max.x + min.x) / extent.x,
+                -(max.y + min.y) / extent.y,
+                -(max.z + min.z) / extent.z,
+                1.0,
+            ),
+        )
+    }
+
+    /// Creates a new Projection that transforms the unit square into the given [`Rect2`], as used for light atlases.
+    ///
+    /// _Godot equivalent: Projection.create_light_atlas_rect()_
+    pub fn create_light_atlas_rect(rect: Rect2) -> Self {
+        Self::from_cols(
+            Vector4::new(rect.size.x, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, rect.size.y, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(rect.position.x, rect.position.y, 0.0, 1.0),
+        )
+    }
+
     /// Creates a new Projection for projecting positions onto a head-mounted
     /// display with the given X:Y aspect ratio, distance between eyes, display
     /// width, distance to lens, oversampling factor, and depth clipping planes.
@@ -271,7 +303,7 @@ impl Projection {
         let ymax = if flip_fov {
             (fov_y * 0.5).tan() / aspect
         } else {
-            fov_y.tan()
+            (fov_y * 0.5).tan()
         } * near;
         let xmax = ymax * aspect;
         let frustumshift = (intraocular_dist * near * 0.5) / convergence_dist;
@@ -289,8 +321,9 @@ impl Projection {
             ),
         };
 
+        // Translate by the model offset, i.e. multiply with a translation matrix from the right.
         let mut ret = Self::create_frustum(left, right, -ymax, ymax, near, far);
-        ret.cols[0] += ret.cols[3] * model_translation;
+        ret.cols[3] += ret.cols[0] * model_translation;
         ret
     }
 
@@ -314,7 +347,8 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_aspect()_
     pub fn aspect(&self) -> real {
-        real::from_f64(self.as_inner().get_aspect())
+        let half_extents = self.viewport_half_extents();
+        half_extents.x / half_extents.y
     }
 
     /// Returns the dimensions of the far clipping plane of the projection,
@@ -322,14 +356,27 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_far_plane_half_extents()_
     pub fn far_plane_half_extents(&self) -> Vector2 {
-        self.as_inner().get_far_plane_half_extents()
+        let m = self.to_flat();
+        let far = normalized_plane(m[3] - m[2], m[7] - m[6], m[11] - m[10], -m[15] + m[14]);
+
+        self.plane_half_extents(far)
     }
 
     /// Returns the horizontal field of view of the projection (in degrees).
     ///
     /// _Godot equivalent: Projection.get_fov()_
     pub fn fov(&self) -> real {
-        real::from_f64(self.as_inner().get_fov())
+        let m = self.to_flat();
+        let right = normalized_plane(m[3] - m[0], m[7] - m[4], m[11] - m[8], -m[15] + m[12]);
+
+        if m[8] == 0.0 && m[9] == 0.0 {
+            right.normal.x.abs().acos().to_degrees() * 2.0
+        } else {
+            // The frustum is asymmetrical, so the angle of the left plane is calculated separately.
+            let left = normalized_plane(m[3] + m[0], m[7] + m[4], m[11] + m[8], m[15] + m[12]);
+
+            left.normal.x.abs().acos().to_degrees() + right.normal.x.abs().acos().to_degrees()
+        }
     }
 
     /// Returns the vertical field of view of a projection (in degrees) which
@@ -337,7 +384,10 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_fovy()_
     pub fn fovy_of(fov_x: real, aspect: real) -> real {
-        real::from_f64(InnerProjection::get_fovy(fov_x.as_f64(), aspect.as_f64()))
+        (aspect * (fov_x.to_radians() * 0.5).tan())
+            .atan()
+            .to_degrees()
+            * 2.0
     }
 
     /// Returns the factor by which the visible level of detail is scaled by
@@ -345,7 +395,12 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_lod_multiplier()_
     pub fn lod_multiplier(&self) -> real {
-        real::from_f64(self.as_inner().get_lod_multiplier())
+        if self.is_orthogonal() {
+            self.viewport_half_extents().x
+        } else {
+            let width = self.viewport_half_extents().x * 2.0;
+            1.0 / (self.z_near() / width)
+        }
     }
 
     /// Returns the number of pixels with the given pixel width displayed per
@@ -353,7 +408,8 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_pixels_per_meter()_
     pub fn pixels_per_meter(&self, pixel_width: i64) -> i64 {
-        self.as_inner().get_pixels_per_meter(pixel_width)
+        let result = self.xform(Vector3::new(1.0, 0.0, -1.0));
+        ((result.x * 0.5 + 0.5) * pixel_width as real) as i64
     }
 
     /// Returns the clipping plane of this Projection whose index is given by
@@ -361,7 +417,19 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_projection_plane()_
     pub fn projection_plane(&self, plane: ProjectionPlane) -> Plane {
-        self.as_inner().get_projection_plane(plane as i64)
+        let m = self.to_flat();
+
+        // All planes are computed as in Godot: the normal of the raw plane is negated before normalization.
+        let (a, b, c, d) = match plane {
+            ProjectionPlane::Near => (m[3] + m[2], m[7] + m[6], m[11] + m[10], m[15] + m[14]),
+            ProjectionPlane::Far => (m[3] - m[2], m[7] - m[6], m[11] - m[10], m[15] - m[14]),
+            ProjectionPlane::Left => (m[3] + m[0], m[7] + m[4], m[11] + m[8], m[15] + m[12]),
+            ProjectionPlane::Top => (m[3] - m[1], m[7] - m[5], m[11] - m[9], m[15] - m[13]),
+            ProjectionPlane::Right => (m[3] - m[0], m[7] - m[4], m[11] - m[8], m[15] - m[12]),
+            ProjectionPlane::Bottom => (m[3] + m[1], m[7] + m[5], m[11] + m[9], m[15] + m[13]),
+        };
+
+        normalized_plane(-a, -b, -c, d)
     }
 
     /// Returns the dimensions of the viewport plane that this Projection
@@ -369,7 +437,10 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_viewport_half_extents()_
     pub fn viewport_half_extents(&self) -> Vector2 {
-        self.as_inner().get_viewport_half_extents()
+        let m = self.to_flat();
+        let near = normalized_plane(m[3] + m[2], m[7] + m[6], m[11] + m[10], -m[15] - m[14]);
+
+        self.plane_half_extents(near)
     }
 
     /// Returns the distance for this Projection beyond which positions are
@@ -377,7 +448,14 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_z_far()_
     pub fn z_far(&self) -> real {
-        real::from_f64(self.as_inner().get_z_far())
+        let m = self.to_flat();
+        normalized_plane(
+            -(m[3] - m[2]),
+            -(m[7] - m[6]),
+            -(m[11] - m[10]),
+            m[15] - m[14],
+        )
+        .d
     }
 
     /// Returns the distance for this Projection before which positions are
@@ -385,7 +463,8 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_z_near()_
     pub fn z_near(&self) -> real {
-        real::from_f64(self.as_inner().get_z_near())
+        let m = self.to_flat();
+        normalized_plane(m[3] + m[2], m[7] + m[6], m[11] + m[10], -m[15] - m[14]).d
     }
 
     /// Returns a Projection that performs the inverse of this Projection's
@@ -431,13 +510,67 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.perspective_znear_adjusted()_
     pub fn perspective_znear_adjusted(&self, new_znear: real) -> Self {
-        self.as_inner()
-            .perspective_znear_adjusted(new_znear.as_f64())
+        let z_far = self.z_far();
+        let delta_z = z_far - new_znear;
+
+        let mut result = *self;
+        result.cols[2].z = -(z_far + new_znear) / delta_z;
+        result.cols[3].z = -2.0 * new_znear * z_far / delta_z;
+        result
+    }
+
+    /// Transforms a 3D point with this projection, including the perspective division.
+    ///
+    /// _Godot equivalent: `Projection.xform()` (C++ only)_
+    fn xform(&self, v: Vector3) -> Vector3 {
+        let [a, b, c, d] = self.cols;
+        let x = a.x * v.x + b.x * v.y + c.x * v.z + d.x;
+        let y = a.y * v.x + b.y * v.y + c.y * v.z + d.y;
+        let z = a.z * v.x + b.z * v.y + c.z * v.z + d.z;
+        let w = a.w * v.x + b.w * v.y + c.w * v.z + d.w;
+
+        Vector3::new(x, y, z) / w
+    }
+
+    /// Returns the half extents of the given near or far plane, as intersection with the right and top planes.
+    fn plane_half_extents(&self, plane: Plane) -> Vector2 {
+        let m = self.to_flat();
+        let right = normalized_plane(m[3] - m[0], m[7] - m[4], m[11] - m[8], -m[15] + m[12]);
+        let top = normalized_plane(m[3] - m[1], m[7] - m[5], m[11] - m[9], -m[15] + m[13]);
+
+        // Godot leaves the result at zero if the planes do not intersect.
+        let point = plane.intersect_3(&right, &top).unwrap_or(Vector3::ZERO);
+        Vector2::new(point.x, point.y)
+    }
+
+    /// Returns the matrix in column-major order, matching the memory layout that Godot's formulas index into.
+    fn to_flat(self) -> [real; 16] {
+        let [a, b, c, d] = self.cols;
+        [
+            a.x, a.y, a.z, a.w, b.x, b.y, b.z, b.w, c.x, c.y, c.z, c.w, d.x, d.y, d.z, d.w,
+        ]
+    }
+}
+
+/// Creates a plane from raw, not necessarily normalized components and normalizes it.
+///
+/// Unlike [`Plane::normalized()`], this does not panic for degenerate or non-finite matrices: like Godot, the components are
+/// divided by the length of the normal, and a zero normal yields a zero plane.
+///
+/// _Godot equivalent: `Plane(a, b, c, d).normalized()`_
+fn normalized_plane(a: real, b: real, c: real, d: real) -> Plane {
+    let normal = Vector3::new(a, b, c);
+    let length = normal.length();
+    if length == 0.0 {
+        return Plane {
+            normal: Vector3::ZERO,
+            d: 0.0,
+        };
     }
 
-    #[doc(hidden)]
-    pub(crate) fn as_inner(&self) -> InnerProjection {
-        InnerProjection::from_outer(self)
+    Plane {
+        normal: normal / length,
+        d: d / length,
     }
 }
 
@@ -44,6 +44,26 @@ impl Quaternion {
         }
     }
 
+    /// Constructs a quaternion representing the shortest arc between `arc_from` and `arc_to`.
+    ///
+    /// Both vectors are expected to be normalized. If they point in opposite directions, a rotation of 180 degrees
+    /// around the Y axis is returned, like in Godot.
+    ///
+    /// _Godot equivalent: `Quaternion(Vector3 arc_from, Vector3 arc_to)`_
+    pub fn from_vectors(arc_from: Vector3, arc_to: Vector3) -> Self {
+        let c = arc_from.cross(arc_to);
+        let d = arc_from.dot(arc_to);
+
+        if d < -1.0 + real::CMP_EPSILON {
+            Self::new(0.0, 1.0, 0.0, 0.0)
+        } else {
+            let s = ((1.0 + d) * 2.0).sqrt();
+            let rs = 1.0 / s;
+
+            Self::new(c.x * rs, c.y * rs, c.z * rs, s * 0.5)
+        }
+    }
+
     pub fn angle_to(self, to: Self) -> real {
         self.glam2(&to, RQuat::angle_between)
     }
@@ -172,21 +192,106 @@ impl Quaternion {
         inv_factor * self + new_factor * to
     }
 
-    // pub fn spherical_cubic_interpolate(self, b: Self, pre_a: Self, post_b: Self, weight: real) -> Self {}
-    // TODO: Implement godot's function in Rust
-    /*
-        pub fn spherical_cubic_interpolate_in_time(
-            self,
-            b: Self,
-            pre_a: Self,
-            post_b: Self,
-            weight: real,
-            b_t: real,
-            pre_a_t: real,
-            post_b_t: real,
-        ) -> Self {
+    /// Performs a spherical cubic interpolation between quaternions `pre_a`, `self`, `b`, and `post_b`, by the given
+    /// amount `weight`.
+    ///
+    /// _Godot equivalent: `Quaternion.spherical_cubic_interpolate()`_
+    pub fn spherical_cubic_interpolate(
+        self,
+        b: Self,
+        pre_a: Self,
+        post_b: Self,
+        weight: real,
+    ) -> Self {
+        self.spherical_cubic_interpolate_with(b, pre_a, post_b, weight, |from, to, pre, post| {
+            from.cubic_interpolate(to, pre, post, weight)
+        })
+    }
+
+    /// Performs a spherical cubic interpolation like [`spherical_cubic_interpolate()`](Self::spherical_cubic_interpolate),
+    /// but takes the time values `b_t`, `pre_a_t` and `post_b_t` into account. This can produce smoother results.
+    ///
+    /// _Godot equivalent: `Quaternion.spherical_cubic_interpolate_in_time()`_
+    #[allow(clippy::too_many_arguments)]
+    pub fn spherical_cubic_interpolate_in_time(
+        self,
+        b: Self,
+        pre_a: Self,
+        post_b: Self,
+        weight: real,
+        b_t: real,
+        pre_a_t: real,
+        post_b_t: real,
+    ) -> Self {
+        self.spherical_cubic_interpolate_with(b, pre_a, post_b, weight, |from, to, pre, post| {
+            from.cubic_interpolate_in_time(to, pre, post, weight, b_t, pre_a_t, post_b_t)
+        })
+    }
+
+    /// Shared implementation of the spherical cubic interpolations, which only differ in the scalar interpolation.
+    fn spherical_cubic_interpolate_with(
+        self,
+        b: Self,
+        pre_a: Self,
+        post_b: Self,
+        weight: real,
+        interpolate: impl Fn(real, real, real, real) -> real,
+    ) -> Self {
+        // Align flip phases.
+        let from_q = Basis::from_quat(self).to_quat();
+        let mut pre_q = Basis::from_quat(pre_a).to_quat();
+        let mut to_q = Basis::from_quat(b).to_quat();
+        let mut post_q = Basis::from_quat(post_b).to_quat();
+
+        // Flip quaternions to shortest path if necessary.
+        let flip1 = from_q.dot(pre_q).is_sign_negative();
+        if flip1 {
+            pre_q = -pre_q;
         }
-    */
+        let flip2 = from_q.dot(to_q).is_sign_negative();
+        if flip2 {
+            to_q = -to_q;
+        }
+        let flip3 = if flip2 {
+            to_q.dot(post_q) <= 0.0
+        } else {
+            to_q.dot(post_q).is_sign_negative()
+        };
+        if flip3 {
+            post_q = -post_q;
+        }
+
+        let interpolate_ln = |ln_from: Self, ln_to: Self, ln_pre: Self, ln_post: Self| {
+            Self::new(
+                interpolate(ln_from.x, ln_to.x, ln_pre.x, ln_post.x),
+                interpolate(ln_from.y, ln_to.y, ln_pre.y, ln_post.y),
+                interpolate(ln_from.z, ln_to.z, ln_pre.z, ln_post.z),
+                0.0,
+            )
+        };
+        let zero = Self::new(0.0, 0.0, 0.0, 0.0);
+
+        // Calc by Expmap in from_q space.
+        let ln = interpolate_ln(
+            zero,
+            (from_q.inverse() * to_q).log(),
+            (from_q.inverse() * pre_q).log(),
+            (from_q.inverse() * post_q).log(),
+        );
+        let q1 = from_q * ln.to_exp();
+
+        // Calc by Expmap in to_q space.
+        let ln = interpolate_ln(
+            (to_q.inverse() * from_q).log(),
+            zero,
+            (to_q.inverse() * pre_q).log(),
+            (to_q.inverse() * post_q).log(),
+        );
+        let q2 = to_q * ln.to_exp();
+
+        // To cancel error made by Expmap ambiguity, do blending.
+        q1.slerp(q2, weight)
+    }
 
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerQuaternion {
@@ -136,8 +136,8 @@ impl Rect2 {
         Self::from_components(
             self.position.x - left,
             self.position.y - top,
-            self.size.x + left + right,
-            self.size.y + top + bottom,
+            self.size.x + (left + right),
+            self.size.y + (top + bottom),
         )
     }
 
@@ -166,15 +166,23 @@ impl Rect2 {
     /// Note: This method is not reliable for Rect2 with a negative size. Use `abs` to get a positive sized equivalent rectangle to check for contained points.
     #[inline]
     pub fn has_point(&self, point: Vector2) -> bool {
-        let point = point - self.position;
+        // Compare against the end, like Godot: subtracting the position first rounds differently near the edges.
+        let end = self.end();
 
-        point.abs() == point && point.x < self.size.x && point.y < self.size.y
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < end.x
+            && point.y < end.y
     }
 
-    /// Returns the intersection of this Rect2 and `b`. If the rectangles do not intersect, an empty Rect2 is returned.
+    /// Returns the intersection of this Rect2 and `b`.
+    ///
+    /// If the rectangles do not intersect, `None` is returned.
+    ///
+    /// Note that rectangles that only share a border do not intersect.
     #[inline]
     pub fn intersection(&self, b: Self) -> Option<Self> {
-        if !self.intersects(b) {
+        if !self.intersects_exclude_borders(b) {
             return None;
         }
 
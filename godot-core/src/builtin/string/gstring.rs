@@ -10,11 +10,11 @@ use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::inner;
-use crate::builtin::meta::impl_godot_as_self;
+use crate::builtin::meta::{impl_godot_as_self, ToGodot};
+use crate::builtin::{inner, PackedStringArray};
 
 use super::string_chars::validate_unicode_scalar_sequence;
-use super::{NodePath, StringName};
+use super::{found_to_option, AsArg, NodePath, StringName};
 
 #[deprecated = "Renamed to `GString`, will soon be removed."]
 pub type GodotString = GString;
@@ -89,6 +89,19 @@ impl GString {
     /// Note: This operation is *O*(*n*). Consider using [`chars_unchecked`][Self::chars_unchecked]
     /// if you can make sure the string is a valid UTF-32.
     pub fn chars_checked(&self) -> &[char] {
+        validate_unicode_scalar_sequence(self.raw_chars())
+            .expect("GString::chars_checked: string contains invalid unicode scalar values")
+    }
+
+    /// Returns an iterator over the characters of the string, borrowing Godot's UTF-32 buffer without copying.
+    ///
+    /// Validation is performed up-front, like in [`chars_checked`][Self::chars_checked].
+    pub fn chars(&self) -> std::iter::Copied<std::slice::Iter<'_, char>> {
+        self.chars_checked().iter().copied()
+    }
+
+    /// Returns the UTF-32 buffer, which may contain invalid code points.
+    fn raw_chars(&self) -> &[u32] {
         unsafe {
             let s = self.string_sys();
             let len = interface_fn!(string_to_utf32_chars)(s, std::ptr::null_mut(), 0);
@@ -99,11 +112,18 @@ impl GString {
                 return &[];
             }
 
-            validate_unicode_scalar_sequence(std::slice::from_raw_parts(ptr, len as usize))
-                .expect("GString::chars_checked: string contains invalid unicode scalar values")
+            std::slice::from_raw_parts(ptr, len as usize)
         }
     }
 
+    /// Compares with a Rust string, without allocations or panics on invalid code points.
+    pub(super) fn eq_str(&self, other: &str) -> bool {
+        self.raw_chars()
+            .iter()
+            .copied()
+            .eq(other.chars().map(u32::from))
+    }
+
     /// Gets the internal chars slice from a [`GString`].
     ///
     /// # Safety
@@ -172,6 +192,7 @@ unsafe impl GodotFfi for GString {
 }
 
 impl_godot_as_self!(GString);
+impl_shared_string_api!(GString);
 
 impl_builtin_traits! {
     for GString {
@@ -184,6 +205,9 @@ impl_builtin_traits! {
     }
 }
 
+impl_str_eq!(GString);
+impl_string_serde!(GString);
+
 impl fmt::Display for GString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s: String = self.chars_checked().iter().collect();
@@ -30,3 +30,298 @@ macro_rules! impl_rust_string_conv {
         }
     };
 }
+
+/// Implements the string-processing API shared by `GString` and `StringName`.
+///
+/// Both types provide the same methods in Godot; results are always returned as `GString`.
+macro_rules! impl_shared_string_api {
+    ($Ty:ty) => {
+        impl $Ty {
+            /// Returns the number of characters (Unicode scalar values) in the string.
+            ///
+            /// _Godot equivalent: `length`_
+            #[doc(alias = "length")]
+            pub fn len(&self) -> usize {
+                self.as_inner().length().try_into().unwrap()
+            }
+
+            /// Returns `true` if this is the empty string.
+            ///
+            /// _Godot equivalent: `is_empty`_
+            pub fn is_empty(&self) -> bool {
+                self.as_inner().is_empty()
+            }
+
+            // ----------------------------------------------------------------------------------------------------------------------------------------------
+            // Searching
+
+            /// Returns the character index of the first occurrence of `what`, or `None` if not found.
+            ///
+            /// Like all indices in this API, the result counts characters, not UTF-8 bytes.
+            ///
+            /// _Godot equivalent: `find`_
+            pub fn find(&self, what: impl AsArg<GString>) -> Option<usize> {
+                self.find_from(what, 0)
+            }
+
+            /// Like [`find()`][Self::find], but starts searching at character index `from`.
+            ///
+            /// _Godot equivalent: `find` with `from` parameter_
+            pub fn find_from(&self, what: impl AsArg<GString>, from: usize) -> Option<usize> {
+                let index = self
+                    .as_inner()
+                    .find(what.into_arg(), from.try_into().unwrap());
+                found_to_option(index)
+            }
+
+            /// Returns the character index of the last occurrence of `what`, or `None` if not found.
+            ///
+            /// _Godot equivalent: `rfind`_
+            pub fn rfind(&self, what: impl AsArg<GString>) -> Option<usize> {
+                let index = self.as_inner().rfind(what.into_arg(), -1);
+                found_to_option(index)
+            }
+
+            /// Returns `true` if the string contains `what`.
+            ///
+            /// _Godot equivalent: `contains`_
+            pub fn contains(&self, what: impl AsArg<GString>) -> bool {
+                self.find(what).is_some()
+            }
+
+            /// Returns `true` if the string starts with `prefix`.
+            ///
+            /// _Godot equivalent: `begins_with`_
+            #[doc(alias = "begins_with")]
+            pub fn starts_with(&self, prefix: impl AsArg<GString>) -> bool {
+                self.as_inner().begins_with(prefix.into_arg())
+            }
+
+            /// Returns `true` if the string ends with `suffix`.
+            ///
+            /// _Godot equivalent: `ends_with`_
+            pub fn ends_with(&self, suffix: impl AsArg<GString>) -> bool {
+                self.as_inner().ends_with(suffix.into_arg())
+            }
+
+            /// Returns the number of non-overlapping occurrences of `what`.
+            ///
+            /// _Godot equivalent: `count`_
+            pub fn count(&self, what: impl AsArg<GString>) -> usize {
+                self.as_inner()
+                    .count(what.into_arg(), 0, 0)
+                    .try_into()
+                    .unwrap()
+            }
+
+            // ----------------------------------------------------------------------------------------------------------------------------------------------
+            // Transformations
+
+            /// Splits the string at each occurrence of `delimiter`.
+            ///
+            /// Empty parts are kept, so `"a,,b"` split at `","` yields `["a", "", "b"]`.
+            ///
+            /// _Godot equivalent: `split`_
+            pub fn split(&self, delimiter: impl AsArg<GString>) -> PackedStringArray {
+                self.as_inner().split(delimiter.into_arg(), true, 0)
+            }
+
+            /// Returns a copy of the string with all occurrences of `what` replaced by `with`.
+            ///
+            /// _Godot equivalent: `replace`_
+            pub fn replace(&self, what: impl AsArg<GString>, with: impl AsArg<GString>) -> GString {
+                self.as_inner().replace(what.into_arg(), with.into_arg())
+            }
+
+            /// Returns the characters in the given index range as a new string.
+            ///
+            /// Out-of-bounds ranges are clamped by Godot.
+            ///
+            /// _Godot equivalent: `substr`_
+            pub fn substr(&self, range: impl std::ops::RangeBounds<usize>) -> GString {
+                use std::ops::Bound;
+
+                let begin = match range.start_bound() {
+                    Bound::Included(&begin) => begin,
+                    Bound::Excluded(&begin) => begin + 1,
+                    Bound::Unbounded => 0,
+                };
+                let len: i64 = match range.end_bound() {
+                    Bound::Included(&end) => (end + 1).saturating_sub(begin).try_into().unwrap(),
+                    Bound::Excluded(&end) => end.saturating_sub(begin).try_into().unwrap(),
+                    Bound::Unbounded => -1,
+                };
+
+                self.as_inner().substr(begin.try_into().unwrap(), len)
+            }
+
+            /// Returns the string converted to uppercase.
+            ///
+            /// _Godot equivalent: `to_upper`_
+            pub fn to_upper(&self) -> GString {
+                self.as_inner().to_upper()
+            }
+
+            /// Returns the string converted to lowercase.
+            ///
+            /// _Godot equivalent: `to_lower`_
+            pub fn to_lower(&self) -> GString {
+                self.as_inner().to_lower()
+            }
+
+            /// Returns the string with leading and trailing whitespace (including control characters) removed.
+            ///
+            /// _Godot equivalent: `strip_edges`_
+            #[doc(alias = "strip_edges")]
+            pub fn trim(&self) -> GString {
+                self.as_inner().strip_edges(true, true)
+            }
+
+            /// Substitutes `{key}` placeholders with the entries of a `Dictionary`, or `{0}`, `{1}`, ... with the elements of an `Array`.
+            ///
+            /// _Godot equivalent: `format`_
+            pub fn format(&self, values: impl ToGodot) -> GString {
+                self.as_inner()
+                    .format(values.to_variant(), GString::from("{_}"))
+            }
+
+            // ----------------------------------------------------------------------------------------------------------------------------------------------
+            // File paths
+
+            /// Returns the extension of a file path without the dot, e.g. `"png"` for `"res://icon.png"`, or an empty string.
+            ///
+            /// _Godot equivalent: `get_extension`_
+            #[doc(alias = "get_extension")]
+            pub fn extension(&self) -> GString {
+                self.as_inner().get_extension()
+            }
+
+            /// Returns the file path without the extension, e.g. `"res://icon"` for `"res://icon.png"`.
+            ///
+            /// _Godot equivalent: `get_basename`_
+            #[doc(alias = "get_basename")]
+            pub fn base_name(&self) -> GString {
+                self.as_inner().get_basename()
+            }
+
+            /// Returns the directory of a file path, e.g. `"res://sprites"` for `"res://sprites/icon.png"`.
+            ///
+            /// _Godot equivalent: `get_base_dir`_
+            #[doc(alias = "get_base_dir")]
+            pub fn base_dir(&self) -> GString {
+                self.as_inner().get_base_dir()
+            }
+
+            /// Returns the last component of a file path, e.g. `"icon.png"` for `"res://sprites/icon.png"`.
+            ///
+            /// _Godot equivalent: `get_file`_
+            #[doc(alias = "get_file")]
+            pub fn file_name(&self) -> GString {
+                self.as_inner().get_file()
+            }
+
+            /// Appends `file` to this path, inserting a `/` separator if necessary.
+            ///
+            /// _Godot equivalent: `path_join`_
+            pub fn path_join(&self, file: impl AsArg<GString>) -> GString {
+                self.as_inner().path_join(file.into_arg())
+            }
+
+            /// Returns `true` if the string is an absolute path, e.g. `"res://icon.png"` or `"/tmp/file"`.
+            ///
+            /// _Godot equivalent: `is_absolute_path`_
+            pub fn is_absolute_path(&self) -> bool {
+                self.as_inner().is_absolute_path()
+            }
+
+            /// Returns `true` if the string is a relative path.
+            ///
+            /// _Godot equivalent: `is_relative_path`_
+            pub fn is_relative_path(&self) -> bool {
+                self.as_inner().is_relative_path()
+            }
+
+            // ----------------------------------------------------------------------------------------------------------------------------------------------
+            // Number parsing
+
+            /// Returns `true` if the string represents a valid integer, e.g. `"-12"` or `"+7"`.
+            ///
+            /// _Godot equivalent: `is_valid_int`_
+            pub fn is_valid_int(&self) -> bool {
+                self.as_inner().is_valid_int()
+            }
+
+            /// Returns `true` if the string represents a valid floating-point number, e.g. `"1.5e3"` or `"7"`.
+            ///
+            /// _Godot equivalent: `is_valid_float`_
+            pub fn is_valid_float(&self) -> bool {
+                self.as_inner().is_valid_float()
+            }
+
+            /// Parses the string as an integer, or returns `None` if it is not [a valid integer][Self::is_valid_int].
+            ///
+            /// Unlike Godot's `to_int`, this does not silently return `0` or ignore non-digit characters.
+            ///
+            /// _Godot equivalent: `to_int`_
+            pub fn to_int(&self) -> Option<i64> {
+                self.is_valid_int().then(|| self.as_inner().to_int())
+            }
+
+            /// Parses the string as a float, or returns `None` if it is not [a valid float][Self::is_valid_float].
+            ///
+            /// _Godot equivalent: `to_float`_
+            pub fn to_float(&self) -> Option<f64> {
+                self.is_valid_float().then(|| self.as_inner().to_float())
+            }
+        }
+    };
+}
+
+/// Implements comparisons with `&str` and `str` in both directions. Requires an `eq_str(&self, &str) -> bool` method.
+macro_rules! impl_str_eq {
+    ($Ty:ty) => {
+        impl PartialEq<str> for $Ty {
+            fn eq(&self, other: &str) -> bool {
+                self.eq_str(other)
+            }
+        }
+
+        impl PartialEq<&str> for $Ty {
+            fn eq(&self, other: &&str) -> bool {
+                self.eq_str(other)
+            }
+        }
+
+        impl PartialEq<$Ty> for str {
+            fn eq(&self, other: &$Ty) -> bool {
+                other.eq_str(self)
+            }
+        }
+
+        impl PartialEq<$Ty> for &str {
+            fn eq(&self, other: &$Ty) -> bool {
+                other.eq_str(self)
+            }
+        }
+    };
+}
+
+/// Implements `Serialize` and `Deserialize` (with the `serde` feature), representing the type as a plain string.
+macro_rules! impl_string_serde {
+    ($Ty:ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $Ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&String::from(self))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $Ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let string = <String as serde::Deserialize>::deserialize(deserializer)?;
+                Ok(Self::from(string.as_str()))
+            }
+        }
+    };
+}
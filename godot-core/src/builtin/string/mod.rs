@@ -6,8 +6,10 @@
 
 //! Godot-types that are Strings.
 
-mod gstring;
+// Must be declared first, so the macros are available in the other modules.
 mod macros;
+
+mod gstring;
 mod node_path;
 mod string_chars;
 mod string_name;
@@ -43,3 +45,64 @@ impl FromGodot for String {
         Some(via.to_string())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Argument passing
+
+/// Types that can be passed as string arguments, such as the pattern in [`GString::find()`].
+///
+/// This plays a role similar to [`std::str::pattern::Pattern`]: methods accept `&str`, `String`, `char`, as well as Godot's
+/// own string types (including `NodePath`), without explicit conversions at the call site.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::GString;
+///
+/// let path = GString::from("res://sprites/icon.png");
+/// assert!(path.ends_with(".png"));
+/// assert_eq!(path.find('/'), Some(4));
+/// assert_eq!(path.rfind(&GString::from("/")), Some(13));
+/// ```
+pub trait AsArg<T>: sealed::Sealed {
+    /// Converts the argument into the type expected by Godot.
+    #[doc(hidden)]
+    fn into_arg(self) -> T;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_as_arg_gstring {
+    ($( $Arg:ty => |$arg:ident| $conversion:expr; )*) => {
+        $(
+            impl sealed::Sealed for $Arg {}
+
+            impl AsArg<GString> for $Arg {
+                fn into_arg(self) -> GString {
+                    let $arg = self;
+                    $conversion
+                }
+            }
+        )*
+    };
+}
+
+impl_as_arg_gstring! {
+    GString => |s| s;
+    &GString => |s| s.clone();
+    &str => |s| GString::from(s);
+    String => |s| GString::from(s);
+    &String => |s| GString::from(s);
+    char => |c| GString::from(c.encode_utf8(&mut [0; 4]));
+    StringName => |s| GString::from(&s);
+    &StringName => |s| GString::from(s);
+    NodePath => |p| GString::from(&p);
+    &NodePath => |p| GString::from(p);
+}
+
+/// Converts Godot's "index or -1" results to `Option`.
+fn found_to_option(index: i64) -> Option<usize> {
+    // Godot returns -1 if not found.
+    (index >= 0).then(|| index.try_into().unwrap())
+}
@@ -12,7 +12,7 @@ use godot_ffi::{ffi_methods, GDExtensionTypePtr, GodotFfi};
 use crate::builtin::inner;
 use crate::builtin::meta::impl_godot_as_self;
 
-use super::{GString, StringName};
+use super::{AsArg, GString, StringName};
 
 /// A pre-parsed scene tree path.
 #[repr(C)]
@@ -25,6 +25,181 @@ impl NodePath {
         Self { opaque }
     }
 
+    /// Returns `true` if the node path is empty.
+    ///
+    /// _Godot equivalent: `is_empty`_
+    pub fn is_empty(&self) -> bool {
+        self.as_inner().is_empty()
+    }
+
+    /// Returns a copy of this path in which all node names are turned into subnames, e.g. `":Sprite:modulate"` for `"Sprite:modulate"`.
+    ///
+    /// The result can be used to address a property of the node itself, for example in `Tween` or `AnimationPlayer` tracks.
+    ///
+    /// _Godot equivalent: `get_as_property_path`_
+    pub fn as_property_path(&self) -> NodePath {
+        self.as_inner().get_as_property_path()
+    }
+
+    /// Returns the names and subnames in the given index range as a new path.
+    ///
+    /// Indices count node names first, then subnames: for `"Player/Sprite:modulate"`, index 2 is the subname `modulate`.
+    /// Out-of-bounds ranges are clamped by Godot.
+    ///
+    /// _Godot equivalent: `slice`_
+    #[cfg(since_api = "4.3")]
+    pub fn subpath(&self, range: impl std::ops::RangeBounds<usize>) -> NodePath {
+        use std::ops::Bound;
+
+        let begin = match range.start_bound() {
+            Bound::Included(&begin) => begin,
+            Bound::Excluded(&begin) => begin + 1,
+            Bound::Unbounded => 0,
+        };
+        let end: i64 = match range.end_bound() {
+            Bound::Included(&end) => (end + 1).try_into().unwrap(),
+            Bound::Excluded(&end) => end.try_into().unwrap(),
+            // Same default as Godot.
+            Bound::Unbounded => i32::MAX.into(),
+        };
+
+        self.as_inner().slice(begin.try_into().unwrap(), end)
+    }
+
+    /// Parses a path such as `"../Player/Sprite:modulate:r"`, validating its syntax.
+    ///
+    /// Unlike the `From<&str>` conversion, which accepts any string, this returns an error if a node name is empty
+    /// (e.g. `"Player//Sprite"`), a subname is empty (e.g. `"Sprite:"`), or a node name contains characters that
+    /// Godot does not allow in node names. The special names `.` and `..` as well as the `%` prefix for
+    /// [scene-unique nodes](https://docs.godotengine.org/en/stable/tutorials/scripting/scene_unique_nodes.html) are accepted.
+    ///
+    /// An empty string results in an empty path.
+    pub fn parse(path: &str) -> Result<Self, NodePathError> {
+        if path.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let (names, subnames) = match path.split_once(':') {
+            Some((names, subnames)) => (names, Some(subnames)),
+            None => (path, None),
+        };
+        let relative_names = names.strip_prefix('/').unwrap_or(names);
+
+        // A path may consist only of subnames (":position"), or only of the root ("/").
+        if !relative_names.is_empty() {
+            for (index, name) in relative_names.split('/').enumerate() {
+                validate_name(index, name)?;
+            }
+        }
+
+        if let Some(subnames) = subnames {
+            for (index, subname) in subnames.split(':').enumerate() {
+                validate_subname(index, subname)?;
+            }
+        }
+
+        Ok(Self::from(path))
+    }
+
+    /// Creates a builder to assemble a path from individual names and subnames.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::builder()
+    ///     .absolute()
+    ///     .name("root")
+    ///     .name("Player")
+    ///     .subname("modulate")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(path, "/root/Player:modulate");
+    /// ```
+    pub fn builder() -> NodePathBuilder {
+        NodePathBuilder::default()
+    }
+
+    /// Returns `true` if the path starts at the scene tree root, i.e. begins with `/`.
+    ///
+    /// _Godot equivalent: `is_absolute`_
+    pub fn is_absolute(&self) -> bool {
+        self.as_inner().is_absolute()
+    }
+
+    /// Returns the node names, e.g. `["Player", "Sprite"]` for `"Player/Sprite:modulate"`.
+    ///
+    /// _Godot equivalent: `get_name` for each index up to `get_name_count`_
+    pub fn names(&self) -> Vec<StringName> {
+        let inner = self.as_inner();
+        (0..inner.get_name_count())
+            .map(|index| inner.get_name(index))
+            .collect()
+    }
+
+    /// Returns the subnames (property or resource path), e.g. `["modulate", "r"]` for `"Sprite:modulate:r"`.
+    ///
+    /// _Godot equivalent: `get_subname` for each index up to `get_subname_count`_
+    pub fn subnames(&self) -> Vec<StringName> {
+        let inner = self.as_inner();
+        (0..inner.get_subname_count())
+            .map(|index| inner.get_subname(index))
+            .collect()
+    }
+
+    /// Returns all node names joined by `/`, without a leading `/` for absolute paths.
+    ///
+    /// _Godot equivalent: `get_concatenated_names`_
+    pub fn concatenated_names(&self) -> StringName {
+        self.as_inner().get_concatenated_names()
+    }
+
+    /// Returns all subnames joined by `:`, e.g. `"modulate:r"` for `"Sprite:modulate:r"`.
+    ///
+    /// _Godot equivalent: `get_concatenated_subnames`_
+    pub fn concatenated_subnames(&self) -> StringName {
+        self.as_inner().get_concatenated_subnames()
+    }
+
+    /// Appends `other` to this path, similar to [`Path::join()`](std::path::Path::join).
+    ///
+    /// If `other` is absolute, it replaces this path. If `other` has no node names, its subnames are appended to
+    /// the subnames of this path: `"Sprite:modulate"` joined with `":r"` results in `"Sprite:modulate:r"`.
+    ///
+    /// Returns [`NodePathError::NameAfterSubname`] if this path has subnames and `other` has node names, since node names cannot
+    /// follow subnames.
+    pub fn join(&self, other: &NodePath) -> Result<NodePath, NodePathError> {
+        if other.is_absolute() {
+            return Ok(other.clone());
+        }
+
+        let own_subnames = self.subnames();
+        let other_names = other.names();
+        if !own_subnames.is_empty() && !other_names.is_empty() {
+            return Err(NodePathError::NameAfterSubname {
+                name: other_names[0].to_string(),
+            });
+        }
+
+        let builder = NodePathBuilder {
+            is_absolute: self.is_absolute(),
+            names: self
+                .names()
+                .iter()
+                .chain(other_names.iter())
+                .map(GString::from)
+                .collect(),
+            subnames: own_subnames
+                .iter()
+                .chain(other.subnames().iter())
+                .map(GString::from)
+                .collect(),
+        };
+
+        Ok(builder.build_unchecked())
+    }
+
     /// Returns a 32-bit integer hash value representing the string.
     pub fn hash(&self) -> u32 {
         self.as_inner()
@@ -33,6 +208,11 @@ impl NodePath {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Compares the string representation of this path with a Rust string.
+    fn eq_str(&self, other: &str) -> bool {
+        GString::from(self).eq_str(other)
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerNodePath {
         inner::InnerNodePath::from_outer(self)
@@ -85,6 +265,9 @@ impl_builtin_traits! {
     }
 }
 
+impl_str_eq!(NodePath);
+impl_string_serde!(NodePath);
+
 impl fmt::Display for NodePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let string = GString::from(self);
@@ -150,3 +333,147 @@ impl From<StringName> for NodePath {
         Self::from(GString::from(string_name))
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Builder and validation
+
+/// Assembles a [`NodePath`] from individual node names and subnames.
+///
+/// Created by [`NodePath::builder()`].
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodePathBuilder {
+    is_absolute: bool,
+    names: Vec<GString>,
+    subnames: Vec<GString>,
+}
+
+impl NodePathBuilder {
+    /// Makes the path start at the scene tree root (leading `/`).
+    pub fn absolute(mut self) -> Self {
+        self.is_absolute = true;
+        self
+    }
+
+    /// Appends a node name, such as `"Player"`, `".."` or `"%UniqueNode"`.
+    pub fn name(mut self, name: impl AsArg<GString>) -> Self {
+        self.names.push(name.into_arg());
+        self
+    }
+
+    /// Appends a subname, i.e. a property or resource path segment such as `"modulate"`.
+    pub fn subname(mut self, subname: impl AsArg<GString>) -> Self {
+        self.subnames.push(subname.into_arg());
+        self
+    }
+
+    /// Validates all names and subnames, and creates the path.
+    ///
+    /// Names are validated individually, so a name containing `/` is rejected rather than split.
+    pub fn build(self) -> Result<NodePath, NodePathError> {
+        for (index, name) in self.names.iter().enumerate() {
+            validate_name(index, &name.to_string())?;
+        }
+        for (index, subname) in self.subnames.iter().enumerate() {
+            validate_subname(index, &subname.to_string())?;
+        }
+
+        Ok(self.build_unchecked())
+    }
+
+    fn build_unchecked(self) -> NodePath {
+        let mut path = String::new();
+        if self.is_absolute {
+            path.push('/');
+        }
+
+        let names: Vec<String> = self.names.iter().map(String::from).collect();
+        path.push_str(&names.join("/"));
+
+        for subname in &self.subnames {
+            path.push(':');
+            path.push_str(&subname.to_string());
+        }
+
+        NodePath::from(path)
+    }
+}
+
+/// Error when parsing or building a [`NodePath`] with invalid syntax.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum NodePathError {
+    /// The node name at the given index is empty, e.g. in `"Player//Sprite"`.
+    EmptyName { index: usize },
+
+    /// The subname at the given index is empty, e.g. in `"Sprite:"`.
+    EmptySubname { index: usize },
+
+    /// The node name at the given index contains a character that is not allowed in node names.
+    InvalidCharacter {
+        index: usize,
+        name: String,
+        character: char,
+    },
+
+    /// A node name follows a subname when joining paths, e.g. `"Child"` joined to `"Sprite:modulate"`.
+    NameAfterSubname { name: String },
+}
+
+impl fmt::Display for NodePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName { index } => write!(f, "node name #{index} is empty"),
+            Self::EmptySubname { index } => write!(f, "subname #{index} is empty"),
+            Self::InvalidCharacter {
+                index,
+                name,
+                character,
+            } => write!(
+                f,
+                "node name #{index} \"{name}\" contains invalid character '{character}'"
+            ),
+            Self::NameAfterSubname { name } => {
+                write!(f, "node name \"{name}\" cannot follow subnames")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NodePathError {}
+
+/// Characters that Godot does not allow in node names (see `String::get_invalid_node_name_characters()`).
+const INVALID_NODE_NAME_CHARS: &[char] = &['.', ':', '@', '/', '"', '%'];
+
+fn validate_name(index: usize, name: &str) -> Result<(), NodePathError> {
+    if name.is_empty() {
+        return Err(NodePathError::EmptyName { index });
+    }
+
+    if name == "." || name == ".." {
+        return Ok(());
+    }
+
+    // Scene-unique nodes are referred to as `%Name`.
+    let plain_name = name.strip_prefix('%').unwrap_or(name);
+
+    match plain_name
+        .chars()
+        .find(|c| INVALID_NODE_NAME_CHARS.contains(c))
+    {
+        Some(character) => Err(NodePathError::InvalidCharacter {
+            index,
+            name: name.to_string(),
+            character,
+        }),
+        None if plain_name.is_empty() => Err(NodePathError::EmptyName { index }),
+        None => Ok(()),
+    }
+}
+
+fn validate_subname(index: usize, subname: &str) -> Result<(), NodePathError> {
+    if subname.is_empty() {
+        Err(NodePathError::EmptySubname { index })
+    } else {
+        Ok(())
+    }
+}
@@ -4,14 +4,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::fmt;
+use std::{fmt, sync};
 
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::inner;
-use crate::builtin::meta::impl_godot_as_self;
-use crate::builtin::{GString, NodePath};
+use crate::builtin::meta::{impl_godot_as_self, ToGodot};
+use crate::builtin::{GString, NodePath, PackedStringArray};
+
+use super::{found_to_option, AsArg};
 
 /// A string optimized for unique names.
 ///
@@ -65,21 +67,6 @@ impl StringName {
         result
     }
 
-    /// Returns the number of characters in the string.
-    ///
-    /// _Godot equivalent: `length`_
-    #[doc(alias = "length")]
-    pub fn len(&self) -> usize {
-        self.as_inner().length() as usize
-    }
-
-    /// Returns `true` if this is the empty string.
-    ///
-    /// _Godot equivalent: `is_empty`_
-    pub fn is_empty(&self) -> bool {
-        self.as_inner().is_empty()
-    }
-
     /// Returns a 32-bit integer hash value representing the string.
     pub fn hash(&self) -> u32 {
         self.as_inner()
@@ -102,6 +89,19 @@ impl StringName {
         inner::InnerStringName::from_outer(self)
     }
 
+    /// Returns the pointer to Godot's interned string data.
+    ///
+    /// Godot guarantees that equal strings share the same data, so this identifies the string content. It is null for empty names.
+    fn interned_ptr(&self) -> *const std::ffi::c_void {
+        // SAFETY: A StringName consists of a single pointer (`StringName::_data` in C++), which we read without dereferencing.
+        unsafe { *(self.sys() as *const *const std::ffi::c_void) }
+    }
+
+    /// Compares with a Rust string. Converting to `GString` typically just shares the existing buffer.
+    fn eq_str(&self, other: &str) -> bool {
+        GString::from(self).eq_str(other)
+    }
+
     /// Increment ref-count. This may leak memory if used wrongly.
     fn inc_ref(&self) {
         std::mem::forget(self.clone());
@@ -143,19 +143,50 @@ unsafe impl GodotFfi for StringName {
 }
 
 impl_godot_as_self!(StringName);
+impl_shared_string_api!(StringName);
 
 impl_builtin_traits! {
     for StringName {
         Default => string_name_construct_default;
         Clone => string_name_construct_copy;
         Drop => string_name_destroy;
-        Eq => string_name_operator_equal;
-        // currently broken: https://github.com/godotengine/godot/issues/76218
-        // Ord => string_name_operator_less;
+        // Eq and Ord are implemented manually below.
         Hash;
     }
 }
 
+impl PartialEq for StringName {
+    /// Compares the interned string data by pointer, without calling into Godot.
+    fn eq(&self, other: &Self) -> bool {
+        self.interned_ptr() == other.interned_ptr()
+    }
+}
+
+impl Eq for StringName {}
+
+impl Ord for StringName {
+    /// Orders lexicographically by content, like [`GString`].
+    ///
+    /// Godot's own `operator<` compares interned pointers, which is not a meaningful order (see
+    /// <https://github.com/godotengine/godot/issues/76218>).
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self == other {
+            return std::cmp::Ordering::Equal;
+        }
+
+        GString::from(self).cmp(&GString::from(other))
+    }
+}
+
+impl PartialOrd for StringName {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl_str_eq!(StringName);
+impl_string_serde!(StringName);
+
 impl fmt::Display for StringName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = GString::from(self);
@@ -245,3 +276,88 @@ impl From<NodePath> for StringName {
         Self::from(GString::from(path))
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Static string names
+
+/// All [`StaticStringName`] instances that have been initialized, so they can be released before the library is unloaded.
+static STATIC_STRING_NAMES: sync::Mutex<Vec<&'static StaticStringName>> =
+    sync::Mutex::new(Vec::new());
+
+/// A `StringName` for a string literal, created once per process on first access.
+///
+/// Use the [`sname!`](crate::builtin::sname) macro instead of this type.
+#[doc(hidden)]
+pub struct StaticStringName {
+    literal: &'static str,
+    cached: sync::RwLock<Option<StringName>>,
+}
+
+impl StaticStringName {
+    pub const fn new(literal: &'static str) -> Self {
+        Self {
+            literal,
+            cached: sync::RwLock::new(None),
+        }
+    }
+
+    /// Returns the cached `StringName`, creating it on first access.
+    ///
+    /// Cloning only increments the reference count; the string is not interned again.
+    pub fn get(&'static self) -> StringName {
+        if let Some(name) = self.cached.read().unwrap().as_ref() {
+            return name.clone();
+        }
+
+        let mut guard = self.cached.write().unwrap();
+        let name = guard.get_or_insert_with(|| {
+            STATIC_STRING_NAMES.lock().unwrap().push(self);
+            StringName::from(self.literal)
+        });
+
+        name.clone()
+    }
+}
+
+/// Releases all `StringName` instances created through [`sname!`](crate::builtin::sname).
+///
+/// Must be called before the library is unloaded, as destroying a `StringName` requires Godot. Literals accessed afterwards
+/// (e.g. after hot-reloading) are created anew.
+pub(crate) fn clear_static_string_names() {
+    // Take the list out first, so the mutex is not held while locking the individual caches (`get()` locks in opposite order).
+    let static_names = std::mem::take(&mut *STATIC_STRING_NAMES.lock().unwrap());
+
+    for static_name in static_names {
+        *static_name.cached.write().unwrap() = None;
+    }
+}
+
+/// Creates a [`StringName`] from a string literal, interning it only once per process.
+///
+/// Every call site has its own static cache. The first evaluation creates the `StringName`; subsequent ones return a cheap
+/// reference-counted copy, without crossing the FFI boundary to look up the string. This is useful for method, signal,
+/// property and action names that are used repeatedly, e.g. every frame.
+///
+/// The cached instances are released when the library is unloaded.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// fn make_callback(node: Gd<Node>) -> Option<Callable> {
+///     if node.has_method(sname!("on_hit")) {
+///         Some(Callable::from_object_method(node, sname!("on_hit")))
+///     } else {
+///         None
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! sname {
+    ($literal:literal) => {{
+        static STATIC_NAME: $crate::builtin::StaticStringName =
+            $crate::builtin::StaticStringName::new($literal);
+
+        STATIC_NAME.get()
+    }};
+}
@@ -195,6 +195,19 @@ impl Transform2D {
         self.a.is_finite() && self.b.is_finite() && self.origin.is_finite()
     }
 
+    /// Returns a copy of the transform rotated such that the rotated X-axis points towards the `target` position.
+    ///
+    /// Operations take place in global space. Scale and skew are not preserved.
+    ///
+    /// _Godot equivalent: `Transform2D.looking_at()`_
+    #[must_use]
+    pub fn looking_at(self, target: Vector2) -> Self {
+        let target_position = self.affine_inverse() * target;
+        let angle = (target_position * self.scale()).angle();
+
+        Self::from_angle_origin(self.rotation() + angle, self.origin)
+    }
+
     /// Returns the transform with the basis orthogonal (90 degrees), and
     /// normalized axis vectors (scale of 1 or -1).
     ///
@@ -281,12 +294,14 @@ impl Transform2D {
         self.to_basis() * v
     }
 
-    /// Returns a vector transformed (multiplied) by the inverse basis matrix.
+    /// Returns a vector transformed (multiplied) by the inverse basis matrix,
+    /// under the assumption that the basis is orthonormal (rotation/reflection only; scale and skew are not handled).
     /// This method does not account for translation (the origin vector).
     ///
     /// _Godot equivalent: `Transform2D.basis_xform_inv()`_
     pub fn basis_xform_inv(&self, v: Vector2) -> Vector2 {
-        self.basis().inverse() * v
+        // Translated from Godot: multiplies with the transposed basis.
+        Vector2::new(self.a.dot(v), self.b.dot(v))
     }
 }
 
@@ -447,12 +462,6 @@ impl Basis2D {
         self.glam(|mat| mat.determinant())
     }
 
-    /// Returns the inverse of the matrix.
-    #[must_use]
-    pub fn inverse(self) -> Self {
-        self.glam(|mat| mat.inverse())
-    }
-
     /// Returns the orthonormalized version of the basis.
     #[must_use]
     pub(crate) fn orthonormalized(self) -> Self {
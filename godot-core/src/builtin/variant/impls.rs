@@ -37,7 +37,7 @@ macro_rules! impl_ffi_variant {
             fn ffi_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
                 // Type check -- at the moment, a strict match is required.
                 if variant.get_type() != Self::variant_type() {
-                    return Err(VariantConversionError::BadType);
+                    return Err(VariantConversionError::bad_type::<Self>(variant));
                 }
 
                 // For 4.0:
@@ -151,7 +151,7 @@ impl GodotFfiVariant for () {
             return Ok(());
         }
 
-        Err(VariantConversionError::BadValue)
+        Err(VariantConversionError::bad_value::<Self>(variant))
     }
 }
 
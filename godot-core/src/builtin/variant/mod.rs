@@ -11,8 +11,11 @@ use sys::types::OpaqueVariant;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 mod impls;
+#[cfg(feature = "serde")]
+mod serialize;
 mod variant_traits;
 
+pub use crate::gen::central::VariantDispatch;
 pub use impls::*;
 pub use sys::{VariantOperator, VariantType};
 pub use variant_traits::*;
@@ -92,6 +95,27 @@ impl Variant {
         }
     }
 
+    /// Converts this variant into a [`VariantDispatch`], an enum with one owned Rust value per variant type.
+    ///
+    /// Useful to handle variants of unknown type with a single `match`. The result can be turned back into a `Variant` with
+    /// `.into()`. Unlike [`get_type()`][Self::get_type], a null object is dispatched as `VariantDispatch::Object(None)`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::builtin::VariantDispatch;
+    /// fn describe(variant: &Variant) -> String {
+    ///     match variant.dispatch() {
+    ///         VariantDispatch::Nil => "nothing".to_string(),
+    ///         VariantDispatch::Int(i) => format!("integer {i}"),
+    ///         VariantDispatch::String(s) => format!("string \"{s}\""),
+    ///         other => format!("something else: {other:?}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn dispatch(&self) -> VariantDispatch {
+        VariantDispatch::from_variant(self)
+    }
+
     /// ⚠️ Calls the specified `method` with the given `args`.
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc).
@@ -182,6 +206,265 @@ impl Variant {
         unsafe { interface_fn!(variant_booleanize)(self.var_sys()) != 0 }
     }
 
+    /// Returns the name of the type held by this variant, e.g. `"Vector2"` or `"Dictionary"`.
+    ///
+    /// Objects are reported as `"Object"`, regardless of their class.
+    pub fn get_type_name(&self) -> GString {
+        Self::type_name(self.get_type())
+    }
+
+    /// Returns the name of a variant type, e.g. `"int"` for `VariantType::Int`.
+    pub fn type_name(variant_type: VariantType) -> GString {
+        unsafe {
+            sys::from_sys_init_or_init_default::<GString>(|string_ptr| {
+                interface_fn!(variant_get_type_name)(variant_type.sys(), string_ptr as _);
+            })
+        }
+    }
+
+    /// Constructs a variant of the given type from arguments, like `Vector2(1, 2)` or `Color("red")` in GDScript.
+    ///
+    /// Without arguments, the default value of the type is constructed. Returns an error if no constructor of `variant_type`
+    /// matches the arguments.
+    pub fn construct(
+        variant_type: VariantType,
+        args: &[Variant],
+    ) -> Result<Variant, VariantAccessError> {
+        let args_sys: Vec<_> = args.iter().map(|v| v.var_sys_const()).collect();
+        let mut error = sys::default_call_error();
+
+        let result = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                interface_fn!(variant_construct)(
+                    variant_type.sys(),
+                    variant_ptr,
+                    args_sys.as_ptr(),
+                    args_sys.len() as i32,
+                    ptr::addr_of_mut!(error),
+                )
+            })
+        };
+
+        if error.error == sys::GDEXTENSION_CALL_OK {
+            Ok(result)
+        } else {
+            Err(VariantAccessError::ConstructionFailed {
+                variant_type,
+                arg_types: args.iter().map(Variant::get_type).collect(),
+            })
+        }
+    }
+
+    /// Returns `true` if the variant has a method with the given name (built-in method, or method of an object).
+    pub fn has_method(&self, method: impl Into<StringName>) -> bool {
+        let method = method.into();
+        unsafe { interface_fn!(variant_has_method)(self.var_sys(), method.string_sys()) != 0 }
+    }
+
+    /// Returns `true` if the variant has the given key, e.g. for dictionaries or objects with that property.
+    pub fn has_key(&self, key: &Variant) -> Result<bool, VariantAccessError> {
+        let mut is_valid = false as u8;
+        let has_key = unsafe {
+            interface_fn!(variant_has_key)(
+                self.var_sys(),
+                key.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        self.check_valid(is_valid)?;
+        Ok(has_key != 0)
+    }
+
+    /// Returns the member with the given key, like `variant[key]` in GDScript.
+    ///
+    /// Works for dictionary keys, array indices, object properties and built-in members such as `x` of a `Vector2`.
+    pub fn get(&self, key: &Variant) -> Result<Variant, VariantAccessError> {
+        let mut is_valid = false as u8;
+        let result = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                interface_fn!(variant_get)(
+                    self.var_sys(),
+                    key.var_sys(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                )
+            })
+        };
+
+        self.check_valid(is_valid).map(|()| result)
+    }
+
+    /// Sets the member with the given key, like `variant[key] = value` in GDScript.
+    ///
+    /// Note that for value types such as `Vector2`, only this variant is modified.
+    pub fn set(&mut self, key: &Variant, value: &Variant) -> Result<(), VariantAccessError> {
+        let mut is_valid = false as u8;
+        unsafe {
+            interface_fn!(variant_set)(
+                self.var_sys(),
+                key.var_sys(),
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        self.check_valid(is_valid)
+    }
+
+    /// Returns a named member, like `variant.name` in GDScript.
+    ///
+    /// This is faster than [`get()`][Self::get] for properties and built-in members.
+    pub fn get_named(&self, name: impl Into<StringName>) -> Result<Variant, VariantAccessError> {
+        let name = name.into();
+        let mut is_valid = false as u8;
+        let result = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                interface_fn!(variant_get_named)(
+                    self.var_sys(),
+                    name.string_sys(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                )
+            })
+        };
+
+        self.check_valid(is_valid).map(|()| result)
+    }
+
+    /// Sets a named member, like `variant.name = value` in GDScript.
+    pub fn set_named(
+        &mut self,
+        name: impl Into<StringName>,
+        value: &Variant,
+    ) -> Result<(), VariantAccessError> {
+        let name = name.into();
+        let mut is_valid = false as u8;
+        unsafe {
+            interface_fn!(variant_set_named)(
+                self.var_sys(),
+                name.string_sys(),
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        self.check_valid(is_valid)
+    }
+
+    /// Returns the element at `index` of an indexable variant, such as arrays, packed arrays, strings or vectors.
+    pub fn get_indexed(&self, index: usize) -> Result<Variant, VariantAccessError> {
+        let mut is_valid = false as u8;
+        let mut is_out_of_bounds = false as u8;
+        let result = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                interface_fn!(variant_get_indexed)(
+                    self.var_sys(),
+                    index.try_into().unwrap(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                    ptr::addr_of_mut!(is_out_of_bounds),
+                )
+            })
+        };
+
+        self.check_index(index, is_valid, is_out_of_bounds)
+            .map(|()| result)
+    }
+
+    /// Sets the element at `index` of an indexable variant.
+    pub fn set_indexed(&mut self, index: usize, value: &Variant) -> Result<(), VariantAccessError> {
+        let mut is_valid = false as u8;
+        let mut is_out_of_bounds = false as u8;
+        unsafe {
+            interface_fn!(variant_set_indexed)(
+                self.var_sys(),
+                index.try_into().unwrap(),
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+                ptr::addr_of_mut!(is_out_of_bounds),
+            )
+        };
+
+        self.check_index(index, is_valid, is_out_of_bounds)
+    }
+
+    /// Returns an iterator over the variant, like `for element in variant` in GDScript.
+    ///
+    /// Iterable are arrays and packed arrays (elements), dictionaries (keys), strings (characters), integers and floats
+    /// (ranges `0..n`), as well as `Vector2i`/`Vector3i` (ranges with start, end and step) and objects implementing
+    /// the `_iter_*` protocol.
+    pub fn iter(&self) -> Result<VariantIter<'_>, VariantAccessError> {
+        let mut is_valid = false as u8;
+        let mut has_next = false;
+        let state = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                has_next = interface_fn!(variant_iter_init)(
+                    self.var_sys(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                ) != 0;
+            })
+        };
+
+        if is_valid == 0 {
+            return Err(VariantAccessError::NotIterable {
+                variant_type: self.get_type(),
+            });
+        }
+
+        Ok(VariantIter {
+            variant: self,
+            state,
+            has_next,
+        })
+    }
+
+    /// Returns a shallow copy of the variant. Nested arrays and dictionaries are shared with the original.
+    ///
+    /// For types with value semantics, this is equivalent to `clone()`.
+    pub fn duplicate_shallow(&self) -> Variant {
+        self.duplicate(false)
+    }
+
+    /// Returns a deep copy of the variant. Nested arrays and dictionaries are duplicated recursively.
+    ///
+    /// Objects are not duplicated, only the references to them.
+    pub fn duplicate_deep(&self) -> Variant {
+        self.duplicate(true)
+    }
+
+    fn duplicate(&self, deep: bool) -> Variant {
+        unsafe {
+            Variant::from_var_sys_init_default(|variant_ptr| {
+                interface_fn!(variant_duplicate)(self.var_sys(), variant_ptr, deep as u8);
+            })
+        }
+    }
+
+    fn check_valid(&self, is_valid: u8) -> Result<(), VariantAccessError> {
+        if is_valid != 0 {
+            Ok(())
+        } else {
+            Err(VariantAccessError::InvalidAccess {
+                variant_type: self.get_type(),
+            })
+        }
+    }
+
+    fn check_index(
+        &self,
+        index: usize,
+        is_valid: u8,
+        is_out_of_bounds: u8,
+    ) -> Result<(), VariantAccessError> {
+        if is_out_of_bounds != 0 {
+            Err(VariantAccessError::IndexOutOfBounds { index })
+        } else {
+            self.check_valid(is_valid)
+        }
+    }
+
     fn from_opaque(opaque: OpaqueVariant) -> Self {
         Self { opaque }
     }
@@ -257,6 +540,56 @@ impl Variant {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over a [`Variant`], created by [`Variant::iter()`].
+///
+/// Modifying the iterated value during iteration (e.g. through another reference to the same array) results in
+/// unspecified, but safe behavior.
+pub struct VariantIter<'a> {
+    variant: &'a Variant,
+    state: Variant,
+    has_next: bool,
+}
+
+impl<'a> Iterator for VariantIter<'a> {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.has_next {
+            return None;
+        }
+
+        let mut is_valid = false as u8;
+        let element = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                interface_fn!(variant_iter_get)(
+                    self.variant.var_sys(),
+                    self.state.var_sys(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                )
+            })
+        };
+
+        let mut is_next_valid = false as u8;
+        self.has_next = unsafe {
+            interface_fn!(variant_iter_next)(
+                self.variant.var_sys(),
+                self.state.var_sys(),
+                ptr::addr_of_mut!(is_next_valid),
+            ) != 0
+        };
+
+        // Iteration state became invalid, e.g. because the underlying container was modified.
+        if is_next_valid == 0 {
+            self.has_next = false;
+        }
+
+        (is_valid != 0).then_some(element)
+    }
+}
+
 // SAFETY:
 // `from_opaque` properly initializes a dereferenced pointer to an `OpaqueVariant`.
 // `std::mem::swap` is sufficient for returning a value.
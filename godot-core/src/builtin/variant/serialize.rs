@@ -0,0 +1,139 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Serde support for `Variant`.
+//!
+//! A variant is encoded as an externally tagged enum, named after its [`VariantType`], e.g. `{"Int": 5}` or `"Nil"` in JSON.
+//! Objects are only supported if they are resources saved to a path; they are encoded as that path and loaded again on
+//! deserialization. Other objects as well as `Callable` and `Signal`, which refer to objects, cannot be serialized.
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+use crate::engine::{try_load, Object, Resource};
+use crate::obj::Gd;
+
+/// Defines `VariantRepr`, the serde-facing mirror of `VariantDispatch`, for all types that are serialized by value.
+macro_rules! impl_variant_repr {
+    ($( $Name:ident($Ty:ty), )*) => {
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename = "Variant")]
+        enum VariantRepr {
+            Nil,
+            $( $Name($Ty), )*
+
+            /// Raw RID value; only meaningful within the same engine session.
+            Rid(u64),
+
+            /// Resource, represented by its path.
+            Object(GString),
+        }
+
+        impl VariantRepr {
+            fn from_variant(variant: &Variant) -> Result<Self, String> {
+                let repr = match variant.dispatch() {
+                    VariantDispatch::Nil => Self::Nil,
+                    $( VariantDispatch::$Name(value) => Self::$Name(value), )*
+                    VariantDispatch::Rid(rid) => Self::Rid(rid.to_u64()),
+                    VariantDispatch::Object(Some(object)) => Self::Object(resource_path(object)?),
+                    VariantDispatch::Object(None) => Self::Nil,
+                    VariantDispatch::Callable(_) | VariantDispatch::Signal(_) => {
+                        return Err(format!(
+                            "variant of type {:?} cannot be serialized",
+                            variant.get_type()
+                        ));
+                    }
+                };
+
+                Ok(repr)
+            }
+
+            fn into_variant(self) -> Result<Variant, String> {
+                let variant = match self {
+                    Self::Nil => Variant::nil(),
+                    $( Self::$Name(value) => value.to_variant(), )*
+                    Self::Rid(id) => Rid::new(id).to_variant(),
+                    Self::Object(path) => try_load::<Resource>(path.clone())
+                        .ok_or_else(|| format!("failed to load resource at path `{path}`"))?
+                        .to_variant(),
+                };
+
+                Ok(variant)
+            }
+        }
+    };
+}
+
+impl_variant_repr! {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(GString),
+    Vector2(Vector2),
+    Vector2i(Vector2i),
+    Rect2(Rect2),
+    Rect2i(Rect2i),
+    Vector3(Vector3),
+    Vector3i(Vector3i),
+    Transform2D(Transform2D),
+    Vector4(Vector4),
+    Vector4i(Vector4i),
+    Plane(Plane),
+    Quaternion(Quaternion),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform3D(Transform3D),
+    Projection(Projection),
+    Color(Color),
+    StringName(StringName),
+    NodePath(NodePath),
+    Dictionary(Dictionary),
+    Array(VariantArray),
+    PackedByteArray(PackedByteArray),
+    PackedInt32Array(PackedInt32Array),
+    PackedInt64Array(PackedInt64Array),
+    PackedFloat32Array(PackedFloat32Array),
+    PackedFloat64Array(PackedFloat64Array),
+    PackedStringArray(PackedStringArray),
+    PackedVector2Array(PackedVector2Array),
+    PackedVector3Array(PackedVector3Array),
+    PackedColorArray(PackedColorArray),
+}
+
+fn resource_path(object: Gd<Object>) -> Result<GString, String> {
+    let class = object.get_class();
+    let path = object
+        .try_cast::<Resource>()
+        .map(|resource| resource.get_path())
+        .unwrap_or_default();
+
+    if path.is_empty() {
+        Err(format!(
+            "object of class {class} cannot be serialized; only resources saved to a path are supported"
+        ))
+    } else {
+        Ok(path)
+    }
+}
+
+impl Serialize for Variant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VariantRepr::from_variant(self)
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VariantRepr::deserialize(deserializer)?
+            .into_variant()
+            .map_err(D::Error::custom)
+    }
+}
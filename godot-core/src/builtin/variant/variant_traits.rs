@@ -4,9 +4,161 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-#[derive(Eq, PartialEq, Debug)]
-//pub struct VariantConversionError;
-pub enum VariantConversionError {
+use std::fmt;
+
+use super::{Variant, VariantType};
+
+/// Error that occurs when a [`Variant`] cannot be converted to a Rust type.
+///
+/// Besides the [kind](ConversionErrorKind) of failure, the error records the expected Rust type, the actual variant type and
+/// a short textual preview of the offending value. When a nested value fails to convert -- for example a field of a
+/// `#[derive(FromGodot)]` struct, an array element or a dictionary value -- the error also carries the path to that value,
+/// such as `player.inventory[3].count`.
+///
+/// The error holds no Godot objects, so it can be sent to other threads and compared without calling into the engine.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VariantConversionError {
+    kind: ConversionErrorKind,
+    expected_type: String,
+    actual_type: VariantType,
+    value: String,
+    path: Vec<PathSegment>,
+}
+
+impl VariantConversionError {
+    /// Creates an error of the given kind, for a conversion of `variant` into `T`.
+    pub fn new<T: ?Sized>(kind: ConversionErrorKind, variant: &Variant) -> Self {
+        Self {
+            kind,
+            expected_type: short_type_name::<T>(),
+            actual_type: variant.get_type(),
+            value: value_preview(variant),
+            path: Vec::new(),
+        }
+    }
+
+    /// Creates an error for a variant whose type does not match `T`.
+    pub fn bad_type<T: ?Sized>(variant: &Variant) -> Self {
+        Self::new::<T>(ConversionErrorKind::BadType, variant)
+    }
+
+    /// Creates an error for a variant of matching type, whose value cannot be represented in `T`.
+    pub fn bad_value<T: ?Sized>(variant: &Variant) -> Self {
+        Self::new::<T>(ConversionErrorKind::BadValue, variant)
+    }
+
+    /// Creates an error for a variant that lacks a value (such as a dictionary key) required by `T`.
+    pub fn missing_value<T: ?Sized>(variant: &Variant) -> Self {
+        Self::new::<T>(ConversionErrorKind::MissingValue, variant)
+    }
+
+    /// What kind of conversion failure this is.
+    pub fn kind(&self) -> ConversionErrorKind {
+        self.kind
+    }
+
+    /// Name of the Rust type that the value at [`path()`][Self::path] was converted to.
+    pub fn expected_type(&self) -> &str {
+        &self.expected_type
+    }
+
+    /// Type of the variant that could not be converted.
+    pub fn actual_type(&self) -> VariantType {
+        self.actual_type
+    }
+
+    /// String representation of the variant that could not be converted.
+    ///
+    /// Long representations are truncated to their first 64 characters, followed by `...`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Path from the converted root value to the nested value that failed, e.g. `player.inventory[3].count`.
+    ///
+    /// Empty if the root value itself could not be converted.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(name) if path.is_empty() => path.push_str(name),
+                PathSegment::Field(name) => {
+                    path.push('.');
+                    path.push_str(name);
+                }
+                PathSegment::Index(index) => path.push_str(&format!("[{index}]")),
+                PathSegment::Key(key) => path.push_str(&format!("[{key}]")),
+            }
+        }
+        path
+    }
+
+    /// Records that this error occurred inside the field `name` of a struct or enum.
+    ///
+    /// Path segments are prepended, so call this while the error propagates outwards.
+    pub fn with_field(mut self, name: &str) -> Self {
+        self.path.insert(0, PathSegment::Field(name.to_string()));
+        self
+    }
+
+    /// Records that this error occurred inside the element at `index` of an array or tuple.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.path.insert(0, PathSegment::Index(index));
+        self
+    }
+
+    /// Records that this error occurred inside the entry for `key` of a dictionary.
+    pub fn with_key(mut self, key: &Variant) -> Self {
+        let key = match key.get_type() {
+            VariantType::String | VariantType::StringName => {
+                format!("{:?}", key.stringify().to_string())
+            }
+            _ => key.stringify().to_string(),
+        };
+
+        self.path.insert(0, PathSegment::Key(key));
+        self
+    }
+
+    /// Replaces the expected type, unless the error stems from a nested value.
+    ///
+    /// Used to report the user-facing type instead of its FFI representation (e.g. `i32` instead of `i64`).
+    pub(crate) fn with_expected_type<T: ?Sized>(mut self) -> Self {
+        if self.path.is_empty() {
+            self.expected_type = short_type_name::<T>();
+        }
+        self
+    }
+}
+
+impl fmt::Display for VariantConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            kind,
+            expected_type,
+            actual_type,
+            value,
+            ..
+        } = self;
+
+        write!(
+            f,
+            "{kind}: expected {expected_type}, found {actual_type:?} `{value}`"
+        )?;
+
+        if !self.path.is_empty() {
+            write!(f, " (at `{}`)", self.path())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for VariantConversionError {}
+
+/// Reason why a [`VariantConversionError`] occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ConversionErrorKind {
     /// Variant type does not match expected type
     BadType,
 
@@ -20,23 +172,110 @@ pub enum VariantConversionError {
     VariantIsNil,
 }
 
-impl std::fmt::Display for VariantConversionError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl fmt::Display for ConversionErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            VariantConversionError::BadType => {
+            ConversionErrorKind::BadType => {
                 f.write_str("Variant type does not match expected type")
             }
-            VariantConversionError::BadValue => {
+            ConversionErrorKind::BadValue => {
                 f.write_str("Variant value cannot be represented in target type")
             }
-            VariantConversionError::MissingValue => {
+            ConversionErrorKind::MissingValue => {
                 f.write_str("Variant value is missing a value for the target type")
             }
-            VariantConversionError::VariantIsNil => {
+            ConversionErrorKind::VariantIsNil => {
                 f.write_str("Variant value is null but expected to be non-null")
             }
         }
     }
 }
 
-impl std::error::Error for VariantConversionError {}
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum PathSegment {
+    Field(String),
+    Index(usize),
+    Key(String),
+}
+
+/// Stringifies `variant`, keeping only the start of long representations such as big arrays.
+fn value_preview(variant: &Variant) -> String {
+    const MAX_CHARS: usize = 64;
+
+    let mut value = variant.stringify().to_string();
+    if let Some((end, _)) = value.char_indices().nth(MAX_CHARS) {
+        value.truncate(end);
+        value.push_str("...");
+    }
+    value
+}
+
+/// Like [`std::any::type_name()`], but without module paths, e.g. `Array<GString>` instead of
+/// `godot_core::builtin::array::Array<godot_core::builtin::string::gstring::GString>`.
+fn short_type_name<T: ?Sized>() -> String {
+    let full_name = std::any::type_name::<T>();
+    let mut name = String::with_capacity(full_name.len());
+
+    // Start of the current path segment within `name`; truncated to when a `::` separator is encountered.
+    let mut segment_start = 0;
+    let mut chars = full_name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            name.truncate(segment_start);
+        } else {
+            name.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = name.len();
+            }
+        }
+    }
+
+    name
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error returned by reflective operations on a [`Variant`](super::Variant), such as member access or construction.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum VariantAccessError {
+    /// The member, key or index does not exist, or the operation is not supported by the variant's type.
+    InvalidAccess { variant_type: VariantType },
+
+    /// The index is out of bounds.
+    IndexOutOfBounds { index: usize },
+
+    /// The variant's type cannot be iterated.
+    NotIterable { variant_type: VariantType },
+
+    /// No constructor of the type accepts the given argument types.
+    ConstructionFailed {
+        variant_type: VariantType,
+        arg_types: Vec<VariantType>,
+    },
+}
+
+impl fmt::Display for VariantAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariantAccessError::InvalidAccess { variant_type } => {
+                write!(f, "invalid access on variant of type {variant_type:?}")
+            }
+            VariantAccessError::IndexOutOfBounds { index } => {
+                write!(f, "index {index} is out of bounds")
+            }
+            VariantAccessError::NotIterable { variant_type } => {
+                write!(f, "variant of type {variant_type:?} is not iterable")
+            }
+            VariantAccessError::ConstructionFailed {
+                variant_type,
+                arg_types,
+            } => write!(
+                f,
+                "no constructor of {variant_type:?} accepts arguments {arg_types:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VariantAccessError {}
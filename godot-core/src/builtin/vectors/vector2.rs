@@ -27,6 +27,7 @@ use std::fmt;
 /// See [`Vector2i`] for its integer counterpart.
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[repr(C)]
 pub struct Vector2 {
     /// The vector's X component.
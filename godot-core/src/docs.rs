@@ -0,0 +1,183 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Registration of Rust doc comments in Godot's built-in class reference.
+//!
+//! The proc-macros turn `///` comments into escaped XML fragments. Since a class is spread across `#[derive(GodotClass)]`
+//! and possibly multiple `#[godot_api]` blocks, the fragments are only assembled into one document per class here.
+
+#![cfg_attr(
+    not(all(feature = "register-docs", since_api = "4.3")),
+    allow(dead_code)
+)]
+
+use crate::builtin::meta::ClassName;
+use crate::registry::{ClassPlugin, PluginComponent};
+use std::collections::HashMap;
+
+/// Docs collected by `#[derive(GodotClass)]`.
+///
+/// All strings are already XML-escaped.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StructDocs {
+    pub brief: &'static str,
+    pub description: &'static str,
+    /// `<member>` elements for `#[var]` and `#[export]` fields.
+    pub members: &'static str,
+}
+
+/// Docs collected by `#[godot_api] impl MyClass`.
+///
+/// All strings are already XML-escaped.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InherentImplDocs {
+    /// `<method>` elements for `#[func]` functions.
+    pub methods: &'static str,
+    /// `<signal>` elements for `#[signal]` declarations.
+    pub signals: &'static str,
+    /// `<constant>` elements for `#[constant]` declarations.
+    pub constants: &'static str,
+}
+
+/// All docs that belong to one class.
+#[derive(Default)]
+struct ClassDocs {
+    base_class_name: Option<ClassName>,
+    struct_docs: Option<StructDocs>,
+    impl_docs: Vec<InherentImplDocs>,
+}
+
+/// Returns one class reference XML document for each class which has docs.
+///
+/// Empty unless the `register-docs` feature is enabled.
+pub fn gather_xml_docs() -> Vec<String> {
+    let mut map = HashMap::<ClassName, ClassDocs>::new();
+
+    crate::private::iterate_plugins(|plugin: &ClassPlugin| {
+        let class_docs = map.entry(plugin.class_name).or_default();
+
+        match &plugin.component {
+            PluginComponent::ClassDef {
+                base_class_name,
+                docs,
+                ..
+            } => {
+                class_docs.base_class_name = Some(*base_class_name);
+                class_docs.struct_docs = *docs;
+            }
+            PluginComponent::UserMethodBinds {
+                docs: Some(docs), ..
+            } => {
+                class_docs.impl_docs.push(*docs);
+            }
+            _ => {}
+        }
+    });
+
+    map.into_iter()
+        .filter_map(|(class_name, class_docs)| {
+            let struct_docs = class_docs.struct_docs?;
+            let base_class_name = class_docs.base_class_name?;
+
+            Some(make_class_xml(
+                &class_name.to_string(),
+                &base_class_name.to_string(),
+                &struct_docs,
+                &class_docs.impl_docs,
+            ))
+        })
+        .collect()
+}
+
+/// Assembles the class reference XML document for a single class.
+fn make_class_xml(
+    class_name: &str,
+    base_class_name: &str,
+    struct_docs: &StructDocs,
+    impl_docs: &[InherentImplDocs],
+) -> String {
+    let StructDocs {
+        brief,
+        description,
+        members,
+    } = struct_docs;
+
+    let methods: String = impl_docs.iter().map(|docs| docs.methods).collect();
+    let signals: String = impl_docs.iter().map(|docs| docs.signals).collect();
+    let constants: String = impl_docs.iter().map(|docs| docs.constants).collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" ?>
+<class name="{class_name}" inherits="{base_class_name}" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../class.xsd">
+<brief_description>{brief}</brief_description>
+<description>{description}</description>
+<methods>{methods}</methods>
+<members>{members}</members>
+<signals>{signals}</signals>
+<constants>{constants}</constants>
+</class>"#
+    )
+}
+
+/// Loads the docs of all classes into the editor's help.
+///
+/// Must be called after all classes have been registered.
+#[cfg(all(feature = "register-docs", since_api = "4.3"))]
+pub(crate) fn register() {
+    use godot_ffi as sys;
+
+    for xml in gather_xml_docs() {
+        let xml = std::ffi::CString::new(xml).expect("class docs must not contain NUL bytes");
+
+        // SAFETY: the string is null-terminated and outlives the call; Godot copies the data.
+        unsafe {
+            sys::interface_fn!(editor_help_load_xml_from_utf8_chars)(xml.as_ptr());
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_xml_for_sample_class() {
+        let struct_docs = StructDocs {
+            brief: "A player.",
+            description: "A player.\n\nUses &lt;WASD&gt;.",
+            members: r#"<member name="hp" type="int">Hit points.</member>"#,
+        };
+        let impl_docs = [
+            InherentImplDocs {
+                methods: r#"<method name="jump"><description>Jumps.</description></method>"#,
+                signals: "",
+                constants: r#"<constant name="MAX_HP" value="100"></constant>"#,
+            },
+            InherentImplDocs {
+                methods: r#"<method name="run"><description></description></method>"#,
+                signals: r#"<signal name="died"><description>On death.</description></signal>"#,
+                constants: "",
+            },
+        ];
+
+        let xml = make_class_xml("Player", "CharacterBody2D", &struct_docs, &impl_docs);
+        let expected = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<class name="Player" inherits="CharacterBody2D" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../class.xsd">
+<brief_description>A player.</brief_description>
+<description>A player.
+
+Uses &lt;WASD&gt;.</description>
+<methods><method name="jump"><description>Jumps.</description></method><method name="run"><description></description></method></methods>
+<members><member name="hp" type="int">Hit points.</member></members>
+<signals><signal name="died"><description>On death.</description></signal></signals>
+<constants><constant name="MAX_HP" value="100"></constant></constants>
+</class>"#;
+
+        assert_eq!(xml, expected);
+    }
+}
@@ -61,6 +61,11 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
 
     // Swallow panics. TODO consider crashing if gdext init fails.
     let _ = crate::private::handle_panic(ctx, || {
+        // Levels are initialized in ascending order, so the minimum level comes first.
+        if level == E::min_level() {
+            gdext_on_init(level);
+        }
+
         gdext_on_level_init(level);
         E::on_level_init(level);
     });
@@ -77,9 +82,21 @@ unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
     let _ = crate::private::handle_panic(ctx, || {
         E::on_level_deinit(level);
         gdext_on_level_deinit(level);
+
+        // Levels are deinitialized in descending order, so the minimum level comes last.
+        if level == E::min_level() {
+            gdext_on_deinit();
+        }
     });
 }
 
+/// Tasks needed to be done by gdext internally upon loading the first initialization level, before any classes are registered.
+#[allow(unused_variables)] // level only used for some API versions
+fn gdext_on_init(level: InitLevel) {
+    #[cfg(before_api = "4.2")]
+    crate::builtin::callable_fallback::register_class(level);
+}
+
 /// Tasks needed to be done by gdext internally upon loading an initialization level. Called before user code.
 fn gdext_on_level_init(level: InitLevel) {
     // SAFETY: we are in the main thread, during initialization, no other logic is happening.
@@ -100,6 +117,12 @@ fn gdext_on_level_init(level: InitLevel) {
             }
         }
         crate::auto_register_classes(level);
+
+        // All classes are registered by now, as the editor level comes last.
+        #[cfg(all(feature = "register-docs", since_api = "4.3"))]
+        if level == InitLevel::Editor {
+            crate::docs::register();
+        }
     }
 }
 
@@ -108,6 +131,11 @@ fn gdext_on_level_deinit(level: InitLevel) {
     crate::unregister_classes(level);
 }
 
+/// Tasks needed to be done by gdext internally after the last level has been unloaded, while Godot is still available.
+fn gdext_on_deinit() {
+    crate::builtin::clear_static_string_names();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Defines the entry point for a GDExtension Rust library.
@@ -4,6 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod docs;
 mod registry;
 mod storage;
 
@@ -52,11 +53,14 @@ pub mod private {
 
     use std::sync::{Arc, Mutex};
 
+    pub use crate::docs::{gather_xml_docs, InherentImplDocs, StructDocs};
     pub use crate::gen::classes::class_macros;
     pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginComponent};
     pub use crate::storage::as_storage;
     pub use godot_ffi::out;
 
+    use crate::builtin::meta::{FromGodot, ToGodot};
+    use crate::builtin::{Dictionary, VariantArray, VariantConversionError};
     use crate::{log, sys};
 
     sys::plugin_registry!(pub __GODOT_PLUGIN_REGISTRY: ClassPlugin);
@@ -74,12 +78,47 @@ pub mod private {
             return false;
         }
 
+        // SAFETY: only invoked after global library initialization.
+        let global_config = unsafe { sys::config() };
+
+        global_config.tool_only_in_editor && is_editor()
+    }
+
+    /// Whether the library runs inside the Godot editor. The result is cached after the first call.
+    pub fn is_editor() -> bool {
         // SAFETY: only invoked after global library initialization.
         let global_config = unsafe { sys::config() };
         let is_editor = || crate::engine::Engine::singleton().is_editor_hint();
 
-        global_config.tool_only_in_editor //.
-            && *global_config.is_editor.get_or_init(is_editor)
+        *global_config.is_editor.get_or_init(is_editor)
+    }
+
+    /// Converts the entry `field` of `dict`; used by `#[derive(FromGodot)]`. Errors record `field` in their path.
+    pub fn from_dict_field<T: FromGodot>(
+        dict: &Dictionary,
+        field: &str,
+    ) -> Result<T, VariantConversionError> {
+        match dict.get(field) {
+            Some(value) => value.try_to::<T>().map_err(|err| err.with_field(field)),
+            None => Err(
+                VariantConversionError::missing_value::<T>(&dict.to_variant()).with_field(field),
+            ),
+        }
+    }
+
+    /// Converts the element at `index` of `array`; used by `#[derive(FromGodot)]`. Errors record `index` in their path.
+    pub fn from_array_element<T: FromGodot>(
+        array: &VariantArray,
+        index: usize,
+    ) -> Result<T, VariantConversionError> {
+        if index < array.len() {
+            array
+                .get(index)
+                .try_to::<T>()
+                .map_err(|err| err.with_index(index))
+        } else {
+            Err(VariantConversionError::missing_value::<T>(&array.to_variant()).with_index(index))
+        }
     }
 
     pub fn print_panic(err: Box<dyn std::any::Any + Send>) {
@@ -560,7 +560,7 @@ impl<T: GodotClass> GodotFfiVariant for RawGd<T> {
 
         raw.with_inc_refcount()
             .owned_cast()
-            .map_err(|_| VariantConversionError::BadType)
+            .map_err(|_| VariantConversionError::bad_type::<Self>(variant))
     }
 }
 
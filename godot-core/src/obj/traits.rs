@@ -136,6 +136,48 @@ impl<T: GodotClass> Inherits<T> for T {}
 /// Those are the only objects you can export to the editor.
 pub trait ExportableObject: GodotClass {}
 
+/// Queries whether code runs inside the Godot editor. Auto-implemented for all user-defined classes.
+///
+/// This is mostly useful for `#[class(tool)]` classes, whose lifecycle callbacks are also invoked in the editor. It allows
+/// to skip gameplay logic there:
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=Node)]
+/// struct Spinner {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Spinner {
+///     fn process(&mut self, _delta: f64) {
+///         if self.is_in_editor() {
+///             return; // only spin in-game
+///         }
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// See also `#[godot_api(editor)]` on individual virtual methods, to run them exclusively in the editor.
+pub trait EditorAware: GodotClass {
+    /// Whether the class is annotated with `#[class(tool)]`.
+    const IS_TOOL: bool;
+
+    /// Whether the library currently runs inside the Godot editor.
+    ///
+    /// Equivalent to `Engine::singleton().is_editor_hint()`, but cached after the first call.
+    fn is_in_editor(&self) -> bool {
+        crate::private::is_editor()
+    }
+
+    /// Whether the library currently runs as a game (exported or launched from the editor), i.e. not inside the editor.
+    fn is_in_game(&self) -> bool {
+        !self.is_in_editor()
+    }
+}
+
 /// Auto-implemented for all engine-provided classes.
 pub trait EngineClass: GodotClass {
     fn as_object_ptr(&self) -> sys::GDExtensionObjectPtr;
@@ -4,7 +4,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::GString;
+use std::marker::PhantomData;
+
+use crate::builtin::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::builtin::{GString, VariantType};
 use crate::engine::global::PropertyHint;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -30,6 +33,15 @@ pub trait Export: Property {
     fn default_export_info() -> PropertyHintInfo;
 }
 
+/// Trait implemented for array types whose elements can be exported with their own property hint.
+///
+/// This is used by `#[export(..., each)]`, which applies a hint such as `range` or `enum` to every element
+/// instead of the array itself.
+pub trait ExportElements: Export {
+    /// The variant type of a single element.
+    fn element_variant_type() -> VariantType;
+}
+
 /// Trait for types that can be represented as a type string for use with
 /// [`PropertyHint::PROPERTY_HINT_TYPE_STRING`].
 pub trait TypeStringHint {
@@ -83,6 +95,183 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Flag sets
+
+/// Enum whose variants are bit flags, declared with `#[godot(flags)]`.
+///
+/// Implemented by `#[derive(Property)]`. Arbitrary combinations of the flags are held by [`FlagSet`].
+pub trait FlagEnum {
+    /// The integer value of this variant. It may combine several bits, e.g. for `AB = A | B`.
+    fn bits(&self) -> i64;
+}
+
+/// Set of bit flags from the enum `E`, which can hold any bitwise OR of its variants.
+///
+/// A `#[godot(flags)]` enum itself can only represent the combinations declared as variants. Use `FlagSet<E>` for
+/// `#[var]`/`#[export]` fields and conversions when other combinations must survive a round-trip through Godot, such as
+/// the flags ticked in the editor. Bits that don't belong to any variant are kept as well.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::bind::property::FlagSet;
+///
+/// #[repr(u32)]
+/// #[derive(Property, Export)]
+/// #[godot(flags)]
+/// enum Element {
+///     Fire = 1,
+///     Water = 2,
+///     Earth = 4,
+/// }
+///
+/// let mut elements = FlagSet::from(Element::Fire) | Element::Earth;
+/// assert_eq!(elements.bits(), 5);
+///
+/// elements.remove(Element::Fire);
+/// assert!(elements.contains(Element::Earth));
+/// ```
+pub struct FlagSet<E> {
+    bits: i64,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E> FlagSet<E> {
+    /// The set without any flags.
+    pub const fn empty() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Creates a set from its integer value, as stored in Godot.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self {
+            bits,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The integer value of this set, as stored in Godot.
+    pub const fn bits(&self) -> i64 {
+        self.bits
+    }
+
+    /// Returns `true` if no bit is set.
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+impl<E: FlagEnum> FlagSet<E> {
+    /// Returns `true` if all bits of `flag` are set.
+    pub fn contains(&self, flag: E) -> bool {
+        let flag = flag.bits();
+        self.bits & flag == flag
+    }
+
+    /// Sets all bits of `flag`.
+    pub fn insert(&mut self, flag: E) {
+        self.bits |= flag.bits();
+    }
+
+    /// Clears all bits of `flag`.
+    pub fn remove(&mut self, flag: E) {
+        self.bits &= !flag.bits();
+    }
+}
+
+impl<E: FlagEnum> From<E> for FlagSet<E> {
+    fn from(flag: E) -> Self {
+        Self::from_bits(flag.bits())
+    }
+}
+
+impl<E: FlagEnum> std::ops::BitOr<E> for FlagSet<E> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: E) -> Self {
+        self.insert(rhs);
+        self
+    }
+}
+
+impl<E> std::ops::BitOr for FlagSet<E> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits | rhs.bits)
+    }
+}
+
+// Manual impls, since derives would require the same traits of `E`.
+impl<E> Clone for FlagSet<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for FlagSet<E> {}
+
+impl<E> Default for FlagSet<E> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<E> PartialEq for FlagSet<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<E> Eq for FlagSet<E> {}
+
+impl<E> std::hash::Hash for FlagSet<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+
+impl<E> std::fmt::Debug for FlagSet<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FlagSet({:#b})", self.bits)
+    }
+}
+
+impl<E> GodotConvert for FlagSet<E> {
+    type Via = i64;
+}
+
+impl<E> ToGodot for FlagSet<E> {
+    fn to_godot(&self) -> Self::Via {
+        self.bits
+    }
+}
+
+impl<E> FromGodot for FlagSet<E> {
+    fn try_from_godot(via: Self::Via) -> Option<Self> {
+        Some(Self::from_bits(via))
+    }
+}
+
+impl<E> Property for FlagSet<E> {
+    type Intermediate = i64;
+
+    fn get_property(&self) -> i64 {
+        self.bits
+    }
+
+    fn set_property(&mut self, value: i64) {
+        self.bits = value;
+    }
+}
+
+/// Exported with the `PROPERTY_HINT_FLAGS` of `E`, so the editor shows one checkbox per variant.
+impl<E: Export> Export for FlagSet<E> {
+    fn default_export_info() -> PropertyHintInfo {
+        E::default_export_info()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Export machinery
 
@@ -127,8 +316,9 @@ pub trait Cannot_export_without_godot_api_impl {
 pub mod export_info_functions {
     use crate::builtin::GString;
     use crate::engine::global::PropertyHint;
+    use crate::obj::EngineEnum;
 
-    use super::PropertyHintInfo;
+    use super::{ExportElements, PropertyHintInfo};
 
     /// Turn a list of variables into a comma separated string containing only the identifiers corresponding
     /// to a true boolean variable.
@@ -255,6 +445,24 @@ pub mod export_info_functions {
         }
     }
 
+    /// Applies `element_info` to each element of the array type `A`, instead of the array itself.
+    ///
+    /// Uses Godot's `"type/hint:hint_string"` syntax for typed array element hints.
+    pub fn export_each<A: ExportElements>(element_info: PropertyHintInfo) -> PropertyHintInfo {
+        let PropertyHintInfo { hint, hint_string } = element_info;
+        let hint_string = format!(
+            "{}/{}:{}",
+            A::element_variant_type() as i32,
+            hint.ord(),
+            hint_string
+        );
+
+        PropertyHintInfo {
+            hint: PropertyHint::PROPERTY_HINT_TYPE_STRING,
+            hint_string: hint_string.into(),
+        }
+    }
+
     pub fn export_file<S: AsRef<str>>(filter: S) -> PropertyHintInfo {
         export_file_inner(false, filter)
     }
@@ -437,6 +645,30 @@ mod export_impls {
     impl_property_by_clone!(u16 => Int; int);
     impl_property_by_clone!(u8 => Int; int);
 
+    macro_rules! impl_export_elements {
+        ($( $PackedArray:ty => $variant_type:ident, )*) => {
+            $(
+                impl ExportElements for $PackedArray {
+                    fn element_variant_type() -> VariantType {
+                        VariantType::$variant_type
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_export_elements!(
+        PackedByteArray => Int,
+        PackedInt32Array => Int,
+        PackedInt64Array => Int,
+        PackedFloat32Array => Float,
+        PackedFloat64Array => Float,
+        PackedStringArray => String,
+        PackedVector2Array => Vector2,
+        PackedVector3Array => Vector3,
+        PackedColorArray => Color,
+    );
+
     // Callables are useless when exported to the editor, so we only need to make them available as
     // properties.
     impl_property_by_clone!(Callable => Callable, no_export);
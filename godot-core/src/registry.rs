@@ -17,6 +17,7 @@ use sys::interface_fn;
 
 use crate::builtin::meta::ClassName;
 use crate::builtin::StringName;
+use crate::docs::{InherentImplDocs, StructDocs};
 use crate::out;
 use std::any::Any;
 use std::collections::HashMap;
@@ -82,6 +83,9 @@ pub enum PluginComponent {
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
+
+        /// Doc comments of the struct and its properties; `None` unless the `register-docs` feature is enabled.
+        docs: Option<StructDocs>,
     },
 
     /// Collected from `#[godot_api] impl MyClass`
@@ -90,6 +94,9 @@ pub enum PluginComponent {
         ///
         /// Always present since that's the entire point of this `impl` block.
         generated_register_fn: ErasedRegisterFn,
+
+        /// Doc comments of the functions, signals and constants; `None` unless the `register-docs` feature is enabled.
+        docs: Option<InherentImplDocs>,
     },
 
     /// Collected from `#[godot_api] impl GodotExt for MyClass`
@@ -270,6 +277,36 @@ pub fn auto_register_classes(init_level: InitLevel) {
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
+/// Registers a class that gdext uses internally, with FFI callbacks and methods provided directly instead of through `GodotClass`.
+///
+/// The class is unregistered together with the user classes of `init_level`.
+#[cfg(before_api = "4.2")] // only used for the Callable fallback at the moment
+pub(crate) fn register_internal_class(
+    class_name: ClassName,
+    parent_class_name: ClassName,
+    godot_params: sys::GDExtensionClassCreationInfo,
+    register_fn: fn(&mut dyn Any),
+    init_level: InitLevel,
+) {
+    out!("Register internal class: {class_name} at level `{init_level:?}`");
+
+    get_loaded_classes_with_mutex()
+        .get_or_insert_with(HashMap::default)
+        .entry(init_level)
+        .or_default()
+        .push(class_name);
+
+    register_class_raw(ClassRegistrationInfo {
+        class_name,
+        parent_class_name: Some(parent_class_name),
+        generated_register_fn: None,
+        user_register_fn: Some(ErasedRegisterFn { raw: register_fn }),
+        godot_params,
+        init_level,
+        is_editor_plugin: false,
+    });
+}
+
 pub fn unregister_classes(init_level: InitLevel) {
     let mut loaded_classes_guard = get_loaded_classes_with_mutex();
     let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
@@ -305,6 +342,7 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             generated_create_fn,
             generated_recreate_fn,
             free_fn,
+            docs: _,
         } => {
             c.parent_class_name = Some(base_class_name);
 
@@ -339,6 +377,7 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
 
         PluginComponent::UserMethodBinds {
             generated_register_fn,
+            docs: _,
         } => {
             c.generated_register_fn = Some(generated_register_fn);
         }
@@ -671,7 +710,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
 }
 
 #[cfg(before_api = "4.2")]
-fn default_creation_info() -> sys::GDExtensionClassCreationInfo {
+pub(crate) fn default_creation_info() -> sys::GDExtensionClassCreationInfo {
     sys::GDExtensionClassCreationInfo {
         is_abstract: false as u8,
         is_virtual: false as u8,
@@ -694,7 +733,7 @@ fn default_creation_info() -> sys::GDExtensionClassCreationInfo {
 }
 
 #[cfg(since_api = "4.2")]
-fn default_creation_info() -> sys::GDExtensionClassCreationInfo2 {
+pub(crate) fn default_creation_info() -> sys::GDExtensionClassCreationInfo2 {
     sys::GDExtensionClassCreationInfo2 {
         is_abstract: false as u8,
         is_virtual: false as u8,
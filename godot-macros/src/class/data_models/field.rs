@@ -5,6 +5,7 @@
  */
 
 use crate::class::{FieldExport, FieldVar};
+use crate::docs;
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -13,6 +14,9 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+
+    /// Doc comments on the field, registered as the property's description.
+    pub docs: Option<String>,
 }
 
 impl Field {
@@ -23,6 +27,7 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            docs: docs::extract_docs(&field.attributes),
         }
     }
 }
@@ -9,7 +9,7 @@ use quote::quote;
 use std::collections::HashSet;
 
 use crate::class::FieldHint;
-use crate::util::{KvParser, ListParser};
+use crate::util::{bail, KvParser, ListParser};
 use crate::ParseResult;
 
 /// Store info from `#[export]` attribute.
@@ -116,6 +116,13 @@ pub enum FieldExport {
     /// ### Property Hints
     /// - `PROPERTY_HINT_COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript Annotations
+    /// - any of the above, on a typed array (e.g. `@export_range(0, 1) var a: Array[float]`)
+    ///
+    /// ### Property Hints
+    /// - `PROPERTY_HINT_TYPE_STRING`, with the element's hint encoded as `"type/hint:hint_string"`
+    Each { element: Box<FieldExport> },
 }
 
 impl FieldExport {
@@ -129,7 +136,27 @@ impl FieldExport {
     /// - `@export_{flags/enum}("elem1", "elem2:key2", ...)`
     ///   becomes
     ///   `#[export(flags/enum = (elem1, elem2 = key2, ...))]`
+    ///
+    /// Adding `each` applies the hint to every element of an array, rather than the array itself.
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let Some(each) = parser.handle_alone_ident("each")? else {
+            return Self::new_hint_from_kv(parser);
+        };
+
+        let element = Self::new_hint_from_kv(parser)?;
+        if matches!(element, FieldExport::Default) {
+            return bail!(
+                each,
+                "`each` requires an element hint, e.g. `#[export(range = (0.0, 1.0), each)]`"
+            );
+        }
+
+        Ok(Self::Each {
+            element: Box::new(element),
+        })
+    }
+
+    fn new_hint_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         if let Some(list_parser) = parser.handle_list("range")? {
             return Self::new_range_list(list_parser);
         }
@@ -353,7 +380,8 @@ macro_rules! quote_export_func {
 }
 
 impl FieldExport {
-    pub fn to_field_hint(&self) -> FieldHint {
+    /// Returns the hint for a field of type `field_type`.
+    pub fn to_field_hint(&self, field_type: &venial::TyExpr) -> FieldHint {
         match self {
             FieldExport::Default => FieldHint::Inferred,
 
@@ -448,6 +476,18 @@ impl FieldExport {
                 export_placeholder(#placeholder)
             },
             FieldExport::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            FieldExport::Each { element } => {
+                let FieldHint::HintFromExportFunction(element_info) =
+                    element.to_field_hint(field_type)
+                else {
+                    unreachable!("`each` without element hint is rejected during parsing");
+                };
+
+                FieldHint::HintFromExportFunction(quote! {
+                    ::godot::bind::property::export_info_functions::export_each::<#field_type>(#element_info)
+                })
+            }
         }
     }
 }
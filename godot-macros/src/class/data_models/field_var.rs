@@ -10,7 +10,7 @@ use quote::{format_ident, quote};
 use crate::class::{
     make_existence_check, make_method_registration, Field, FieldHint, FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -30,7 +30,8 @@ impl FieldVar {
     /// - `set = expr`
     /// - `hint = ident`
     /// - `hint_string = expr`
-    /// - `usage_flags =
+    /// - `usage_flags = [ident, ...]`
+    /// - `no_editor`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let mut getter = GetterSetter::parse(parser, "get")?;
         let mut setter = GetterSetter::parse(parser, "set")?;
@@ -50,7 +51,7 @@ impl FieldVar {
             FieldHint::Inferred
         };
 
-        let usage_flags = if let Some(mut parser) = parser.handle_array("usage_flags")? {
+        let custom_flags = if let Some(mut parser) = parser.handle_array("usage_flags")? {
             let mut flags = Vec::new();
 
             while let Some(flag) = parser.next_ident()? {
@@ -59,9 +60,23 @@ impl FieldVar {
 
             parser.finish()?;
 
-            UsageFlags::Custom(flags)
+            Some(flags)
         } else {
-            UsageFlags::Inferred
+            None
+        };
+
+        let no_editor = parser.handle_alone_ident("no_editor")?;
+
+        let usage_flags = match (custom_flags, no_editor) {
+            (Some(_), Some(no_editor)) => {
+                return bail!(
+                    no_editor,
+                    "`no_editor` cannot be combined with `usage_flags`"
+                );
+            }
+            (Some(flags), None) => UsageFlags::Custom(flags),
+            (None, Some(_)) => UsageFlags::Storage,
+            (None, None) => UsageFlags::Inferred,
         };
 
         Ok(FieldVar {
@@ -230,11 +245,55 @@ pub enum UsageFlags {
     /// The usage flags should be inferred based on context, such that they include export.
     InferredExport,
 
+    /// Stored in scenes and resources, but not shown in the editor.
+    ///
+    /// Created by `#[export(storage)]` and `#[var(no_editor)]`.
+    Storage,
+
+    /// Shown in the editor and stored, but cannot be edited in the inspector.
+    ///
+    /// Created by `#[export(read_only)]`.
+    ReadOnly,
+
+    /// Shown in the editor, but not stored in scenes and resources.
+    ///
+    /// Created by `#[export(editor_only)]`.
+    EditorOnly,
+
     /// Use a custom set of usage flags provided by the user.
     Custom(Vec<Ident>),
 }
 
 impl UsageFlags {
+    /// Parse the usage presets of an `#[export]` attribute, i.e. `storage`, `read_only` or `editor_only`.
+    ///
+    /// Returns the key that was used (for error reporting), together with the corresponding usage flags.
+    pub(crate) fn parse_export_preset(parser: &mut KvParser) -> ParseResult<Option<(Ident, Self)>> {
+        let presets = [
+            ("storage", Self::Storage),
+            ("read_only", Self::ReadOnly),
+            ("editor_only", Self::EditorOnly),
+        ];
+
+        let mut found: Option<(Ident, Self)> = None;
+        for (key, usage_flags) in presets {
+            let Some(key) = parser.handle_alone_ident(key)? else {
+                continue;
+            };
+
+            if let Some((prev_key, _)) = &found {
+                return bail!(
+                    key,
+                    "`{key}` cannot be combined with `{prev_key}`; at most one usage preset is allowed"
+                );
+            }
+
+            found = Some((key, usage_flags));
+        }
+
+        Ok(found)
+    }
+
     pub fn is_inferred(&self) -> bool {
         matches!(self, Self::Inferred)
     }
@@ -80,7 +80,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         } = var;
 
         if let Some(export) = export {
-            hint = export.to_field_hint();
+            hint = export.to_field_hint(field_type);
 
             if usage_flags.is_inferred() {
                 usage_flags = UsageFlags::InferredExport;
@@ -94,6 +94,16 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             UsageFlags::InferredExport => {
                 quote! { ::godot::engine::global::PropertyUsageFlags::PROPERTY_USAGE_DEFAULT }
             }
+            UsageFlags::Storage => {
+                quote! { ::godot::engine::global::PropertyUsageFlags::PROPERTY_USAGE_STORAGE }
+            }
+            UsageFlags::ReadOnly => quote! {
+                ::godot::engine::global::PropertyUsageFlags::PROPERTY_USAGE_DEFAULT
+                    | ::godot::engine::global::PropertyUsageFlags::PROPERTY_USAGE_READ_ONLY
+            },
+            UsageFlags::EditorOnly => {
+                quote! { ::godot::engine::global::PropertyUsageFlags::PROPERTY_USAGE_EDITOR }
+            }
             UsageFlags::Custom(flags) => quote! {
                 #(
                     ::godot::engine::global::PropertyUsageFlags::#flags
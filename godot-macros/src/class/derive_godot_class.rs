@@ -8,9 +8,9 @@ use proc_macro2::{Ident, Punct, TokenStream};
 use quote::{format_ident, quote};
 use venial::{Declaration, NamedField, Struct, StructFields};
 
-use crate::class::{make_property_impl, Field, FieldExport, FieldVar, Fields};
+use crate::class::{make_property_impl, Field, FieldExport, FieldVar, Fields, UsageFlags};
 use crate::util::{bail, ident, KvParser};
-use crate::{util, ParseResult};
+use crate::{docs, util, ParseResult};
 
 pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let class = decl
@@ -35,6 +35,7 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
 
     let prv = quote! { ::godot::private };
     let godot_exports_impl = make_property_impl(class_name, &fields);
+    let docs = docs::make_struct_docs(&class.attributes, &fields.all_fields);
 
     let editor_plugin = if struct_cfg.is_editor_plugin {
         quote! {
@@ -90,6 +91,7 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
                 generated_create_fn: #create_fn,
                 generated_recreate_fn: #recreate_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                docs: #docs,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -194,7 +196,9 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
         }
 
         // #[export]
+        let mut export_preset = None;
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            export_preset = UsageFlags::parse_export_preset(&mut parser)?;
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
             parser.finish()?;
@@ -207,6 +211,8 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
             parser.finish()?;
         }
 
+        apply_export_preset(&mut field, export_preset)?;
+
         // Exported or Rust-only fields
         if is_base {
             base_field = Some(field);
@@ -221,6 +227,51 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
     })
 }
 
+/// Combines the usage preset of `#[export(storage | read_only | editor_only)]` with the usage flags of `#[var]`.
+///
+/// Presets cannot be mixed with explicit `#[var(usage_flags = ...)]` or `#[var(no_editor)]`, since they would silently override each other.
+fn apply_export_preset(
+    field: &mut Field,
+    export_preset: Option<(Ident, UsageFlags)>,
+) -> ParseResult<()> {
+    let Some((preset_key, usage_flags)) = export_preset else {
+        if field.export.is_some() {
+            if let Some(FieldVar {
+                usage_flags: UsageFlags::Storage,
+                ..
+            }) = field.var
+            {
+                return bail!(
+                    &field.name,
+                    "#[var(no_editor)] contradicts #[export]; use #[export(storage)] instead"
+                );
+            }
+        }
+
+        return Ok(());
+    };
+
+    match field.var.as_mut() {
+        None => {
+            field.var = Some(FieldVar {
+                usage_flags,
+                ..Default::default()
+            });
+        }
+        Some(var) if var.usage_flags.is_inferred() => {
+            var.usage_flags = usage_flags;
+        }
+        Some(_) => {
+            return bail!(
+                preset_key,
+                "#[export({preset_key})] cannot be combined with `usage_flags` or `no_editor` in #[var]"
+            );
+        }
+    }
+
+    Ok(())
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // General helpers
 
@@ -270,5 +321,9 @@ fn make_config_impl(class_name: &Ident, is_tool: bool) -> TokenStream {
                 }
             }
         }
+
+        impl ::godot::obj::EditorAware for #class_name {
+            const IS_TOOL: bool = #is_tool;
+        }
     }
 }
@@ -13,8 +13,8 @@ use venial::{
 };
 
 use crate::class::{make_method_registration, make_virtual_method_callback, FuncDefinition};
-use crate::util;
 use crate::util::{bail, KvParser};
+use crate::{docs, util};
 
 pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error> {
     let decl = match input_decl {
@@ -131,11 +131,18 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
 
     let prv = quote! { ::godot::private };
 
+    let consts = process_godot_constants(&mut decl)?;
+
+    let signal_docs: Vec<_> = signals
+        .iter()
+        .map(|signal| (&signal.signature, signal.external_attributes.as_slice()))
+        .collect();
+    let docs = docs::make_inherent_impl_docs(&funcs, &signal_docs, &consts);
+
     let methods_registration = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def));
 
-    let consts = process_godot_constants(&mut decl)?;
     let mut integer_constant_cfg_attrs = Vec::new();
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
@@ -230,6 +237,7 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
                 generated_register_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_binds::<#class_name>,
                 },
+                docs: #docs,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -422,6 +430,25 @@ where
     Ok(found)
 }
 
+/// Removes a `#[godot_api(editor)]` attribute from a virtual method, returning whether it was present.
+fn extract_editor_only(method: &mut Function) -> Result<bool, Error> {
+    let Some(mut parser) = KvParser::parse(&method.attributes, "godot_api")? else {
+        return Ok(false);
+    };
+
+    if !parser.handle_alone("editor")? {
+        return bail!(parser.span(), "expected `#[godot_api(editor)]`");
+    }
+    parser.finish()?;
+
+    method.attributes.retain(|attr| {
+        attr.get_single_path_segment()
+            .map_or(true, |name| name != "godot_api")
+    });
+
+    Ok(true)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Expects either Some(quote! { () => A, () => B, ... }) or None as the 'tokens' parameter.
@@ -449,7 +476,7 @@ fn convert_to_match_expression_or_none(tokens: Option<TokenStream>) -> TokenStre
 }
 
 /// Codegen for `#[godot_api] impl GodotExt for MyType`
-fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
+fn transform_trait_impl(mut original_impl: Impl) -> Result<TokenStream, Error> {
     let (class_name, trait_name) = util::validate_trait_impl_virtual(&original_impl, "godot_api")?;
     let class_name_obj = util::class_name_obj(&class_name);
 
@@ -467,15 +494,29 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let mut virtual_methods = vec![];
     let mut virtual_method_cfg_attrs = vec![];
     let mut virtual_method_names = vec![];
+    let mut virtual_method_editor_only = vec![];
+    let mut has_editor_only = false;
 
     let prv = quote! { ::godot::private };
 
-    for item in original_impl.body_items.iter() {
-        let method = if let ImplMember::Method(f) = item {
-            f
-        } else {
-            continue;
-        };
+    // #[godot_api(editor)] is consumed here, the trait impl itself must not see it.
+    let mut editor_only_flags = vec![];
+    for item in original_impl.body_items.iter_mut() {
+        if let ImplMember::Method(method) = item {
+            editor_only_flags.push(extract_editor_only(method)?);
+        }
+    }
+
+    let methods = original_impl
+        .body_items
+        .iter()
+        .filter_map(|item| match item {
+            ImplMember::Method(method) => Some(method),
+            _ => None,
+        });
+
+    for (method, is_editor_only) in methods.zip(editor_only_flags) {
+        has_editor_only |= is_editor_only;
 
         // Transport #[cfg] attributes to the virtual method's FFI glue, to ensure it won't be
         // registered in Godot if conditionally removed from compilation.
@@ -483,6 +524,18 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
             .into_iter()
             .collect::<Vec<_>>();
         let method_name = method.name.to_string();
+        if is_editor_only
+            && matches!(
+                method_name.as_str(),
+                "register_class" | "init" | "to_string"
+            )
+        {
+            return bail!(
+                &method.name,
+                "#[godot_api(editor)] is only supported on lifecycle methods and on_notification"
+            );
+        }
+
         match method_name.as_str() {
             "register_class" => {
                 // Implements the trait once for each implementation of this method, forwarding the cfg attrs of each
@@ -566,6 +619,16 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
             }
 
             "on_notification" => {
+                let editor_guard = if is_editor_only {
+                    quote! {
+                        if !::godot::private::is_editor() {
+                            return;
+                        }
+                    }
+                } else {
+                    TokenStream::new()
+                };
+
                 on_notification_impl = quote! {
                     #on_notification_impl
 
@@ -575,6 +638,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                             if ::godot::private::is_class_inactive(Self::__config().is_tool) {
                                 return;
                             }
+                            #editor_guard
 
                             <Self as #trait_name>::on_notification(self, what.into())
                         }
@@ -610,6 +674,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                 // each distinct method.
                 virtual_method_cfg_attrs.push(cfg_attrs);
                 virtual_method_names.push(virtual_method_name);
+                virtual_method_editor_only.push(is_editor_only);
                 virtual_methods.push(method);
             }
         }
@@ -617,9 +682,33 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
 
     let virtual_method_callbacks: Vec<TokenStream> = virtual_methods
         .iter()
-        .map(|method| make_virtual_method_callback(&class_name, method))
+        .zip(virtual_method_editor_only)
+        .map(|(method, is_editor_only)| {
+            let callback = make_virtual_method_callback(&class_name, method);
+
+            // Editor-only methods are simply not registered at runtime; Godot then falls back to the base implementation.
+            if is_editor_only {
+                quote! {
+                    if ::godot::private::is_editor() { #callback } else { None }
+                }
+            } else {
+                callback
+            }
+        })
         .collect();
 
+    // Running only in the editor makes no sense if callbacks are never invoked there.
+    let editor_only_check = if has_editor_only {
+        quote! {
+            const _: () = assert!(
+                <#class_name as ::godot::obj::EditorAware>::IS_TOOL,
+                "#[godot_api(editor)] requires the class to be annotated with #[class(tool)]"
+            );
+        }
+    } else {
+        TokenStream::new()
+    };
+
     // Use 'match' as a way to only emit 'Some(...)' if the given cfg attrs allow.
     // This permits users to conditionally remove virtual method impls from compilation while also removing their FFI
     // glue which would otherwise make them visible to Godot even if not really implemented.
@@ -637,6 +726,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
         #to_string_impl
         #on_notification_impl
         #register_class_impl
+        #editor_only_check
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
 
@@ -6,8 +6,9 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use venial::{Declaration, StructFields};
+use venial::Declaration;
 
+use crate::derive::{parse_enum_variants, EnumRepr};
 use crate::util::{bail, decl_get_info, DeclInfo};
 use crate::ParseResult;
 
@@ -25,41 +26,43 @@ pub fn derive_export(decl: Declaration) -> ParseResult<TokenStream2> {
         _ => unreachable!(),
     };
 
-    let hint_string = if enum_.variants.is_empty() {
-        return bail!(
-            enum_.name,
-            "In order to derive Export, enums must have at least one variant"
-        );
-    } else {
-        let mut hint_string_segments = Vec::new();
-        for (enum_v, _) in enum_.variants.inner.iter() {
-            let v_name = enum_v.name.clone();
-            let v_disc = if let Some(c) = enum_v.value.clone() {
-                c.value
+    let repr = EnumRepr::parse_or_int(&enum_.attributes)?;
+    let variants = parse_enum_variants(&enum_, &repr, "Export")?;
+
+    let (hint, hint_string) = match repr {
+        // String properties with an enum hint store the variant name itself.
+        EnumRepr::GString => {
+            let names = variants
+                .iter()
+                .map(|v| v.name.to_string())
+                .collect::<Vec<_>>();
+            let hint_string = names.join(",");
+
+            (
+                quote! { PROPERTY_HINT_ENUM },
+                quote! { godot::prelude::GString::from(#hint_string) },
+            )
+        }
+        EnumRepr::Int | EnumRepr::Flags => {
+            let hint = if matches!(repr, EnumRepr::Flags) {
+                quote! { PROPERTY_HINT_FLAGS }
             } else {
-                return bail!(
-                    v_name,
-                    "Property can only be derived on enums with explicit discriminants in all their variants"
-                );
+                quote! { PROPERTY_HINT_ENUM }
             };
-            let v_disc_trimmed = v_disc
-                .to_string()
-                .trim_matches(['(', ')'].as_slice())
-                .to_string();
 
-            hint_string_segments.push(format!("{v_name}:{v_disc_trimmed}"));
+            let segments = variants.iter().map(|v| {
+                let v_name = &v.name;
+                let v_name_str = v_name.to_string();
+                quote! { format!("{}:{}", #v_name_str, #name::#v_name as i64) }
+            });
 
-            match &enum_v.contents {
-                StructFields::Unit => {}
-                _ => {
-                    return bail!(
-                        v_name,
-                        "Property can only be derived on enums with only unit variants for now"
-                    )
-                }
-            };
+            (
+                hint,
+                quote! {
+                    godot::prelude::GString::from([#(#segments),*].join(","))
+                },
+            )
         }
-        hint_string_segments.join(",")
     };
 
     let out = quote! {
@@ -67,8 +70,8 @@ pub fn derive_export(decl: Declaration) -> ParseResult<TokenStream2> {
         impl godot::bind::property::Export for #name {
             fn default_export_info() -> godot::bind::property::PropertyHintInfo {
                 godot::bind::property::PropertyHintInfo {
-                    hint: godot::engine::global::PropertyHint::PROPERTY_HINT_ENUM,
-                    hint_string: godot::prelude::GString::from(#hint_string),
+                    hint: godot::engine::global::PropertyHint::#hint,
+                    hint_string: #hint_string,
                 }
             }
         }
@@ -8,6 +8,7 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, NamedStructFields, StructFields, TupleField, TupleStructFields};
 
+use crate::derive::{parse_enum_variants, EnumRepr};
 use crate::util::{decl_get_info, has_attr, DeclInfo};
 use crate::ParseResult;
 
@@ -16,6 +17,10 @@ fn has_attr_skip(attributes: &[venial::Attribute]) -> bool {
 }
 
 pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
+    if let Some((enum_, repr)) = EnumRepr::parse_decl(&decl)? {
+        return make_enum_repr(enum_, &repr);
+    }
+
     let DeclInfo {
         where_,
         generic_params,
@@ -24,8 +29,12 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
     } = decl_get_info(&decl);
 
     let mut body = quote! {
-        let root = variant.try_to::<::godot::builtin::Dictionary>().ok()?;
-        let root = root.get(#name_string)?;
+        let root = variant
+            .try_to::<::godot::builtin::Dictionary>()
+            .map_err(|_| ::godot::builtin::VariantConversionError::bad_type::<Self>(variant))?;
+        let root = root
+            .get(#name_string)
+            .ok_or_else(|| ::godot::builtin::VariantConversionError::missing_value::<Self>(variant))?;
     };
 
     match decl {
@@ -52,7 +61,7 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
                         _ if has_attr_skip(&enum_v.attributes) => {
                             quote! {
                                 if root == Variant::nil() {
-                                    return Some(Self::default());
+                                    return Ok(Self::default());
                                 }
                             }
                         }
@@ -60,7 +69,7 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
                             quote! {
                                 let child = root.try_to::<String>();
                                 if child == Ok(String::from(#variant_name_string)) {
-                                    return Some(Self::#variant_name);
+                                    return Ok(Self::#variant_name);
                                 }
                             }
                         }
@@ -92,7 +101,7 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
                 body = quote! {
                     #body
                     #matches
-                    None
+                    Err(::godot::builtin::VariantConversionError::bad_value::<Self>(variant))
                 };
             }
         }
@@ -107,7 +116,49 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
             fn try_from_godot(
                 variant: ::godot::builtin::Variant
             ) -> Option<Self> {
-                let variant = &variant;
+                Self::try_from_variant(&variant).ok()
+            }
+
+            fn try_from_variant(
+                variant: &::godot::builtin::Variant
+            ) -> Result<Self, ::godot::builtin::VariantConversionError> {
+                #body
+            }
+        }
+    })
+}
+
+/// Conversion for enums annotated with `#[godot(via = GString)]` or `#[godot(flags)]`.
+fn make_enum_repr(enum_: &venial::Enum, repr: &EnumRepr) -> ParseResult<TokenStream> {
+    let name = &enum_.name;
+    let via = repr.via_type();
+    let variants = parse_enum_variants(enum_, repr, "FromGodot")?;
+    let v_names = variants.iter().map(|v| &v.name).collect::<Vec<_>>();
+
+    let body = match repr {
+        EnumRepr::GString => {
+            let v_name_strs = v_names.iter().map(|v| v.to_string());
+            quote! {
+                match via.to_string().as_str() {
+                    #( #v_name_strs => Some(Self::#v_names), )*
+                    _ => None,
+                }
+            }
+        }
+        // Combined flag values only convert if a variant with that exact discriminant exists.
+        EnumRepr::Int | EnumRepr::Flags => quote! {
+            #(
+                if via == Self::#v_names as #via {
+                    return Some(Self::#v_names);
+                }
+            )*
+            None
+        },
+    };
+
+    Ok(quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: #via) -> Option<Self> {
                 #body
             }
         }
@@ -122,26 +173,29 @@ fn make_named_struct(
     let fields = fields.fields.iter().map(|(field, _)| {
         let ident = &field.name;
         let string_ident = &field.name.to_string();
+        let field_type = &field.ty;
 
         if has_attr_skip(&field.attributes) {
             (quote! {}, quote! { #ident: #name::default().#ident })
         } else {
             (
                 quote! {
-                    let #ident = root.get(#string_ident)?;
+                    let #ident = ::godot::private::from_dict_field::<#field_type>(&root, #string_ident)?;
                 },
-                quote! { #ident: #ident.try_to().ok()? },
+                quote! { #ident },
             )
         }
     });
     let (set_idents, set_self): (Vec<_>, Vec<_>) = fields.unzip();
     *body = quote! {
         #body
-        let root = root.try_to::<::godot::builtin::Dictionary>().ok()?;
+        let root = root
+            .try_to::<::godot::builtin::Dictionary>()
+            .map_err(|_| ::godot::builtin::VariantConversionError::bad_type::<Self>(&root))?;
         #(
             #set_idents
         )*
-        Some(Self { #(#set_self,)* })
+        Ok(Self { #(#set_self,)* })
     }
 }
 
@@ -150,6 +204,8 @@ fn make_tuple_struct(
     body: &mut TokenStream,
     name: &impl ToTokens,
 ) {
+    // Skipped fields are not stored, so array indices only count the remaining ones.
+    let mut index = 0usize;
     let ident_and_set = fields.fields.iter().enumerate().map(|(k, (f, _))| {
         let ident = format_ident!("__{}", k);
         let field_type = f.ty.to_token_stream();
@@ -160,22 +216,24 @@ fn make_tuple_struct(
                     let #ident = <#name as Default>::default().#ident;
                 }
             } else {
-                quote! {
-                    let #ident = root.pop_front()?
-                                     .try_to::<#field_type>()
-                                     .ok()?;
-                }
+                let set = quote! {
+                    let #ident = ::godot::private::from_array_element::<#field_type>(&root, #index)?;
+                };
+                index += 1;
+                set
             },
         )
     });
     let (idents, ident_set): (Vec<_>, Vec<_>) = ident_and_set.unzip();
     *body = quote! {
         #body
-        let mut root = root.try_to::<::godot::builtin::VariantArray>().ok()?;
+        let root = root
+            .try_to::<::godot::builtin::VariantArray>()
+            .map_err(|_| ::godot::builtin::VariantConversionError::bad_type::<Self>(&root))?;
         #(
             #ident_set
         )*
-        Some(Self(
+        Ok(Self(
             #(#idents,)*
         ))
     };
@@ -183,12 +241,12 @@ fn make_tuple_struct(
 
 fn make_new_type_struct(body: &mut TokenStream, fields: venial::TupleStructFields) {
     *body = if has_attr_skip(&fields.fields.first().unwrap().0.attributes) {
-        quote! { Some(Self::default()) }
+        quote! { Ok(Self::default()) }
     } else {
         quote! {
             #body
-            let root = root.try_to().ok()?;
-            Some(Self(root))
+            let root = root.try_to()?;
+            Ok(Self(root))
         }
     }
 }
@@ -196,7 +254,7 @@ fn make_new_type_struct(body: &mut TokenStream, fields: venial::TupleStructField
 fn make_unit_struct(body: &mut TokenStream) {
     *body = quote! {
         #body
-        return Some(Self);
+        Ok(Self)
     }
 }
 
@@ -209,7 +267,10 @@ fn make_enum_new_type(
     quote! {
         if let Ok(child) = root.try_to::<::godot::builtin::Dictionary>() {
             if let Some(variant) = child.get(#variant_name_string) {
-                return Some(Self::#variant_name(variant.try_to::<#field_type>().ok()?));
+                return variant
+                    .try_to::<#field_type>()
+                    .map(Self::#variant_name)
+                    .map_err(|err| err.with_field(#variant_name_string));
             }
         }
     }
@@ -225,7 +286,7 @@ fn make_enum_new_type_skipped(
         if let Ok(child) = root.try_to::<::godot::builtin::Dictionary>() {
             if let Some(v) = child.get(#variant_name_string) {
                 if v.is_nil() {
-                    return Some(Self::#variant_name(
+                    return Ok(Self::#variant_name(
                         <#field_type as Default>::default(),
                     ));
                 }
@@ -239,6 +300,8 @@ fn make_enum_tuple(
     variant_name: &impl ToTokens,
     variant_name_string: &impl ToTokens,
 ) -> TokenStream {
+    // Skipped fields are not stored, so array indices only count the remaining ones.
+    let mut index = 0usize;
     let fields = fields.fields.iter().enumerate().map(|(k, (field, _))| {
         let ident = format_ident!("__{k}");
         let field_type = &field.ty;
@@ -247,11 +310,12 @@ fn make_enum_tuple(
                 let #ident = <#field_type as Default>::default();
             }
         } else {
-            quote! {
-                let #ident = variant.pop_front()?
-                                    .try_to::<#field_type>()
-                                    .ok()?;
-            }
+            let set = quote! {
+                let #ident = ::godot::private::from_array_element::<#field_type>(&variant, #index)
+                    .map_err(|err| err.with_field(#variant_name_string))?;
+            };
+            index += 1;
+            set
         };
         (ident.to_token_stream(), set_ident)
     });
@@ -261,9 +325,14 @@ fn make_enum_tuple(
         let child = root.try_to::<::godot::builtin::Dictionary>();
         if let Ok(child) = child {
             if let Some(variant) = child.get(#variant_name_string) {
-                let mut variant = variant.try_to::<::godot::builtin::VariantArray>().ok()?;
+                let variant = variant
+                    .try_to::<::godot::builtin::VariantArray>()
+                    .map_err(|_| {
+                        ::godot::builtin::VariantConversionError::bad_type::<Self>(&variant)
+                            .with_field(#variant_name_string)
+                    })?;
                 #(#set_idents)*
-                return Some(Self::#variant_name(#(#idents ,)*));
+                return Ok(Self::#variant_name(#(#idents ,)*));
             }
         }
     }
@@ -283,9 +352,8 @@ fn make_enum_named(
             }
         } else {
             quote! {
-                let #field_name = variant.get(#field_name_string)?
-                    .try_to::<#field_type>()
-                    .ok()?;
+                let #field_name = ::godot::private::from_dict_field::<#field_type>(&variant, #field_name_string)
+                    .map_err(|err| err.with_field(#variant_name_string))?;
             }
         };
         (field_name.to_token_stream(), set_field)
@@ -295,11 +363,16 @@ fn make_enum_named(
     quote! {
         if let Ok(root) = root.try_to::<::godot::builtin::Dictionary>() {
             if let Some(variant) = root.get(#variant_name_string) {
-                let variant = variant.try_to::<::godot::builtin::Dictionary>().ok()?;
+                let variant = variant
+                    .try_to::<::godot::builtin::Dictionary>()
+                    .map_err(|_| {
+                        ::godot::builtin::VariantConversionError::bad_type::<Self>(&variant)
+                            .with_field(#variant_name_string)
+                    })?;
                 #(
                     #set_fields
                 )*
-                return Some(Self::#variant_name {
+                return Ok(Self::#variant_name {
                     #( #fields, )*
                 });
             }
@@ -8,6 +8,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use venial::Declaration;
 
+use crate::derive::EnumRepr;
 use crate::util::{decl_get_info, DeclInfo};
 use crate::ParseResult;
 
@@ -19,11 +20,16 @@ pub fn derive_godot_convert(decl: Declaration) -> ParseResult<TokenStream> {
         ..
     } = decl_get_info(&decl);
 
+    let via = match EnumRepr::parse_decl(&decl)? {
+        Some((_, repr)) => repr.via_type(),
+        None => quote! { ::godot::builtin::Variant },
+    };
+
     let gen = generic_params.as_ref().map(|x| x.as_inline_args());
 
     Ok(quote! {
         impl #generic_params ::godot::builtin::meta::GodotConvert for #name #gen #where_ {
-            type Via = ::godot::builtin::Variant;
+            type Via = #via;
         }
     })
 }
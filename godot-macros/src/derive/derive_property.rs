@@ -5,10 +5,11 @@
  */
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens};
-use venial::{Declaration, StructFields};
+use quote::quote;
+use venial::Declaration;
 
-use crate::util::{bail, decl_get_info, ident, DeclInfo};
+use crate::derive::{parse_enum_variants, parse_repr_type, EnumRepr};
+use crate::util::{bail, decl_get_info, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_property(decl: Declaration) -> ParseResult<TokenStream2> {
@@ -16,10 +17,6 @@ pub fn derive_property(decl: Declaration) -> ParseResult<TokenStream2> {
         name, name_string, ..
     } = decl_get_info(&decl);
 
-    let body_get;
-    let body_set;
-    let intermediate;
-
     let enum_ = match decl {
         Declaration::Enum(e) => e,
         Declaration::Struct(s) => {
@@ -31,76 +28,101 @@ pub fn derive_property(decl: Declaration) -> ParseResult<TokenStream2> {
         _ => unreachable!(),
     };
 
-    if enum_.variants.is_empty() {
-        return bail!(
-            enum_.name,
-            "In order to derive Property, enums must have at least one variant"
-        );
-    } else {
-        let mut matches_get = quote! {};
-        let mut matches_set = quote! {};
-        intermediate = if let Some(attr) = enum_
-            .attributes
-            .iter()
-            .find(|attr| attr.get_single_path_segment() == Some(&ident("repr")))
-        {
-            attr.value.to_token_stream()
-        } else {
-            return bail!(
-                name,
-                "Property can only be derived on enums with an explicit `#[repr(i*/u*)]` type"
-            );
-        };
-
-        for (enum_v, _) in enum_.variants.inner.iter() {
-            let v_name = enum_v.name.clone();
-            let v_disc = if let Some(c) = enum_v.value.clone() {
-                c.value
-            } else {
-                return bail!(
-                    v_name,
-                    "Property can only be derived on enums with explicit discriminants in all their variants"
-                );
+    let repr = EnumRepr::parse_or_int(&enum_.attributes)?;
+    let variants = parse_enum_variants(&enum_, &repr, "Property")?;
+    let v_names = variants.iter().map(|v| &v.name).collect::<Vec<_>>();
+
+    let intermediate;
+    let body_get;
+    let body_set;
+    let mut flag_enum_impl = TokenStream2::new();
+
+    match repr {
+        EnumRepr::Int => {
+            intermediate = parse_repr_type(&enum_, "Property")?;
+
+            let v_discs = variants.iter().map(|v| &v.discriminant);
+            body_get = quote! {
+                match &self {
+                    #( Self::#v_names => #v_discs, )*
+                }
+            };
+
+            let v_discs = variants.iter().map(|v| &v.discriminant);
+            body_set = quote! {
+                *self = match value {
+                    #( #v_discs => Self::#v_names, )*
+                    _ => {
+                        ::godot::log::godot_error!(
+                            "Invalid value {} for enum {}; keeping previous value",
+                            value,
+                            #name_string,
+                        );
+                        return;
+                    }
+                }
             };
+        }
+        EnumRepr::Flags => {
+            intermediate = parse_repr_type(&enum_, "Property")?;
 
-            let match_content_get;
-            let match_content_set;
-            match &enum_v.contents {
-                StructFields::Unit => {
-                    match_content_get = quote! {
-                        Self::#v_name => #v_disc,
-                    };
-                    match_content_set = quote! {
-                        #v_disc => Self::#v_name,
-                    };
+            // Discriminants of flags are often expressions like `1 << 3`, which cannot be used as patterns.
+            body_get = quote! {
+                match &self {
+                    #( Self::#v_names => Self::#v_names as #intermediate, )*
                 }
-                _ => {
-                    return bail!(
-                        v_name,
-                        "Property can only be derived on enums with only unit variants for now"
-                    )
+            };
+
+            // The editor combines the ticked flags with bitwise OR. Such a combination is accepted if a variant declares it (e.g.
+            // `None = 0` or `AB = A | B`); other combinations cannot be represented by the enum and keep the previous value.
+            // Fields of type `FlagSet<Self>` hold any combination.
+            body_set = quote! {
+                #(
+                    if value == Self::#v_names as #intermediate {
+                        *self = Self::#v_names;
+                        return;
+                    }
+                )*
+                ::godot::log::godot_error!(
+                    "Flag combination {} has no matching variant in enum {}; keeping previous value",
+                    value,
+                    #name_string,
+                );
+            };
+
+            flag_enum_impl = quote! {
+                impl godot::bind::property::FlagEnum for #name {
+                    fn bits(&self) -> i64 {
+                        match self {
+                            #( Self::#v_names => Self::#v_names as i64, )*
+                        }
+                    }
                 }
             };
-            matches_get = quote! {
-                #matches_get
-                #match_content_get
+        }
+        EnumRepr::GString => {
+            intermediate = quote! { ::godot::builtin::GString };
+
+            let v_name_strs = v_names.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            body_get = quote! {
+                match &self {
+                    #( Self::#v_names => ::godot::builtin::GString::from(#v_name_strs), )*
+                }
             };
-            matches_set = quote! {
-                #matches_set
-                #match_content_set
+            body_set = quote! {
+                *self = match value.to_string().as_str() {
+                    #( #v_name_strs => Self::#v_names, )*
+                    other => {
+                        ::godot::log::godot_error!(
+                            "Invalid value \"{}\" for enum {}; keeping previous value",
+                            other,
+                            #name_string,
+                        );
+                        return;
+                    }
+                }
             };
         }
-        body_get = quote! {
-            match &self {
-                #matches_get
-            }
-        };
-        body_set = quote! {
-            *self = match value {
-                #matches_set
-                _ => panic!("Incorrect conversion from {} to {}", stringify!(#intermediate), #name_string),
-            }
-        };
     }
 
     let out = quote! {
@@ -116,6 +138,8 @@ pub fn derive_property(decl: Declaration) -> ParseResult<TokenStream2> {
                 #body_set
             }
         }
+
+        #flag_enum_impl
     };
     Ok(out)
 }
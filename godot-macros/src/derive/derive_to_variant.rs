@@ -8,10 +8,15 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, StructFields};
 
+use crate::derive::{parse_enum_variants, EnumRepr};
 use crate::util::{decl_get_info, has_attr, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_to_godot(decl: Declaration) -> ParseResult<TokenStream> {
+    if let Some((enum_, repr)) = EnumRepr::parse_decl(&decl)? {
+        return make_enum_repr(enum_, &repr);
+    }
+
     let mut body = quote! {
         let mut root = ::godot::builtin::Dictionary::new();
     };
@@ -100,6 +105,35 @@ pub fn derive_to_godot(decl: Declaration) -> ParseResult<TokenStream> {
     })
 }
 
+/// Conversion for enums annotated with `#[godot(via = GString)]` or `#[godot(flags)]`.
+fn make_enum_repr(enum_: &venial::Enum, repr: &EnumRepr) -> ParseResult<TokenStream> {
+    let name = &enum_.name;
+    let via = repr.via_type();
+    let variants = parse_enum_variants(enum_, repr, "ToGodot")?;
+    let v_names = variants.iter().map(|v| &v.name);
+
+    let arms = match repr {
+        EnumRepr::GString => {
+            let v_name_strs = variants.iter().map(|v| v.name.to_string());
+            quote! { #( Self::#v_names => ::godot::builtin::GString::from(#v_name_strs), )* }
+        }
+        EnumRepr::Int | EnumRepr::Flags => {
+            let v_names2 = variants.iter().map(|v| &v.name);
+            quote! { #( Self::#v_names => Self::#v_names2 as #via, )* }
+        }
+    };
+
+    Ok(quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> #via {
+                match self {
+                    #arms
+                }
+            }
+        }
+    })
+}
+
 fn make_named_enum_field(named: &venial::NamedStructFields) -> TokenStream {
     let fields = named.fields.iter().map(|(field, _)| {
         let field_name = field.name.to_token_stream();
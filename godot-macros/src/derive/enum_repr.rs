@@ -0,0 +1,163 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parsing of the `#[godot(...)]` attribute, which selects how an enum is represented in Godot.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use venial::{Attribute, Declaration, Enum, StructFields};
+
+use crate::util::{bail, ident, KvParser};
+use crate::ParseResult;
+
+/// How a fieldless enum is represented in Godot.
+pub enum EnumRepr {
+    /// Variants are stored as their integer discriminant, typed according to `#[repr]`.
+    ///
+    /// This is the default, if no `#[godot]` attribute is present.
+    Int,
+
+    /// Variants are stored as their name, in a `GString`.
+    ///
+    /// Specified by `#[godot(via = GString)]`.
+    GString,
+
+    /// Variants are bit flags, stored as their integer discriminant, typed according to `#[repr]`.
+    ///
+    /// Discriminants may combine other flags, e.g. `AB = 0b11`.
+    /// Specified by `#[godot(flags)]`.
+    Flags,
+}
+
+impl EnumRepr {
+    /// Parse the `#[godot(...)]` attribute. Returns `None` if the attribute is absent.
+    pub fn parse(attributes: &[Attribute]) -> ParseResult<Option<Self>> {
+        let Some(mut parser) = KvParser::parse(attributes, "godot")? else {
+            return Ok(None);
+        };
+
+        let via = parser.handle_ident("via")?;
+        let flags = parser.handle_alone_ident("flags")?;
+
+        let repr = match (via, flags) {
+            (Some(via), Some(_)) => {
+                return bail!(via, "`via` and `flags` cannot be combined");
+            }
+            (Some(via), None) if via == "GString" => Self::GString,
+            (Some(via), None) => {
+                return bail!(
+                    via,
+                    "unsupported `via` type; only `via = GString` is allowed"
+                );
+            }
+            (None, Some(_)) => Self::Flags,
+            (None, None) => {
+                return bail!(
+                    parser.span(),
+                    "expected `#[godot(via = GString)]` or `#[godot(flags)]`"
+                );
+            }
+        };
+
+        parser.finish()?;
+        Ok(Some(repr))
+    }
+
+    /// Parse the `#[godot(...)]` attribute of a declaration passed to a conversion derive.
+    ///
+    /// Returns the enum and its representation if the attribute is present, or `None` to use the default conversion.
+    pub fn parse_decl(decl: &Declaration) -> ParseResult<Option<(&Enum, Self)>> {
+        match decl {
+            Declaration::Enum(enum_) => {
+                Ok(Self::parse(&enum_.attributes)?.map(|repr| (enum_, repr)))
+            }
+            Declaration::Struct(struct_) => match Self::parse(&struct_.attributes)? {
+                Some(_) => bail!(&struct_.name, "#[godot(...)] is only supported on enums"),
+                None => Ok(None),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// The `GodotConvert::Via` type used for this representation.
+    pub fn via_type(&self) -> TokenStream {
+        match self {
+            Self::Int | Self::Flags => quote! { i64 },
+            Self::GString => quote! { ::godot::builtin::GString },
+        }
+    }
+
+    /// Like [`Self::parse`], but falls back to [`EnumRepr::Int`] if the attribute is absent.
+    pub fn parse_or_int(attributes: &[Attribute]) -> ParseResult<Self> {
+        Ok(Self::parse(attributes)?.unwrap_or(Self::Int))
+    }
+
+    /// Whether this representation needs an explicit integer discriminant for each variant.
+    pub fn needs_discriminants(&self) -> bool {
+        !matches!(self, Self::GString)
+    }
+}
+
+/// A unit variant of an enum, along with its discriminant (if any).
+pub struct EnumVariant {
+    pub name: Ident,
+    pub discriminant: Option<TokenStream>,
+}
+
+/// Collects all variants of an enum, validating that they are compatible with `repr`.
+///
+/// `derive_name` is used in error messages.
+pub fn parse_enum_variants(
+    enum_: &Enum,
+    repr: &EnumRepr,
+    derive_name: &str,
+) -> ParseResult<Vec<EnumVariant>> {
+    if enum_.variants.is_empty() {
+        return bail!(
+            &enum_.name,
+            "In order to derive {derive_name}, enums must have at least one variant"
+        );
+    }
+
+    let mut variants = Vec::new();
+    for (enum_v, _) in enum_.variants.inner.iter() {
+        let name = enum_v.name.clone();
+
+        if !matches!(enum_v.contents, StructFields::Unit) {
+            return bail!(
+                name,
+                "{derive_name} can only be derived on enums with only unit variants for now"
+            );
+        }
+
+        let discriminant = enum_v.value.as_ref().map(|c| c.value.to_token_stream());
+        if discriminant.is_none() && repr.needs_discriminants() {
+            return bail!(
+                name,
+                "{derive_name} can only be derived on enums with explicit discriminants in all their variants"
+            );
+        }
+
+        variants.push(EnumVariant { name, discriminant });
+    }
+
+    Ok(variants)
+}
+
+/// Returns the type inside `#[repr(...)]`, which is required for integer-based representations.
+pub fn parse_repr_type(enum_: &Enum, derive_name: &str) -> ParseResult<TokenStream> {
+    match enum_
+        .attributes
+        .iter()
+        .find(|attr| attr.get_single_path_segment() == Some(&ident("repr")))
+    {
+        Some(attr) => Ok(attr.value.to_token_stream()),
+        None => bail!(
+            &enum_.name,
+            "{derive_name} can only be derived on enums with an explicit `#[repr(i*/u*)]` type"
+        ),
+    }
+}
@@ -0,0 +1,444 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Collects `///` doc comments and turns them into fragments of Godot's class reference XML.
+//!
+//! The fragments are assembled into a complete `<class>` document by `godot-core` during registration, since the parts
+//! of a class are spread across `#[derive(GodotClass)]` and `#[godot_api]` invocations.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use venial::{Attribute, AttributeValue, Constant, FnParam, Function, TyExpr};
+
+use crate::class::{Field, FuncDefinition};
+
+/// Generates `Option<StructDocs>` for the `ClassDef` plugin, or `None` if docs are not registered.
+pub fn make_struct_docs(attributes: &[Attribute], fields: &[Field]) -> TokenStream {
+    if !cfg!(feature = "register-docs") {
+        return quote! { None };
+    }
+
+    let description = extract_docs(attributes).unwrap_or_default();
+    let brief = xml_escape(&brief_description(&description));
+    let description = xml_escape(&description);
+
+    let members: String = fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .map(|field| make_member_xml(&field.name.to_string(), &field.ty, &field.docs))
+        .collect();
+
+    quote! {
+        Some(::godot::private::StructDocs {
+            brief: #brief,
+            description: #description,
+            members: #members,
+        })
+    }
+}
+
+/// Generates `Option<InherentImplDocs>` for the `UserMethodBinds` plugin, or `None` if docs are not registered.
+pub fn make_inherent_impl_docs(
+    funcs: &[FuncDefinition],
+    signals: &[(&Function, &[Attribute])],
+    constants: &[Constant],
+) -> TokenStream {
+    if !cfg!(feature = "register-docs") {
+        return quote! { None };
+    }
+
+    let methods: String = funcs.iter().map(make_method_xml).collect();
+
+    let signals: String = signals
+        .iter()
+        .map(|(signature, attributes)| make_signal_xml(signature, attributes))
+        .collect();
+
+    let constants: String = constants.iter().map(make_constant_xml).collect();
+
+    quote! {
+        Some(::godot::private::InherentImplDocs {
+            methods: #methods,
+            signals: #signals,
+            constants: #constants,
+        })
+    }
+}
+
+/// Concatenates all `#[doc = "..."]` attributes (which `///` comments desugar to), or returns `None` if there are none.
+pub fn extract_docs(attributes: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attributes
+        .iter()
+        .filter(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "doc")
+        })
+        .filter_map(|attr| match &attr.value {
+            AttributeValue::Equals(_, tokens) => match tokens.as_slice() {
+                [literal] => unescape_str_literal(&literal.to_string()),
+                _ => None,
+            },
+            _ => None, // e.g. #[doc(hidden)]
+        })
+        .flat_map(|doc| {
+            doc.lines()
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let docs = lines.join("\n").trim().to_string();
+    (!docs.is_empty()).then_some(docs)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// XML fragments
+
+fn make_member_xml(name: &str, ty: &TyExpr, docs: &Option<String>) -> String {
+    format!(
+        r#"<member name="{name}" type="{ty}">{docs}</member>"#,
+        name = xml_escape(name),
+        ty = xml_escape(&godot_type_name(ty)),
+        docs = xml_escape(docs.as_deref().unwrap_or_default()),
+    )
+}
+
+fn make_method_xml(func_def: &FuncDefinition) -> String {
+    let signature = &func_def.func;
+    let name = func_def
+        .rename
+        .clone()
+        .unwrap_or_else(|| signature.name.to_string());
+
+    let has_receiver = func_def.has_gd_self
+        || signature
+            .params
+            .inner
+            .iter()
+            .any(|(param, _)| matches!(param, FnParam::Receiver(_)));
+    let qualifiers = if has_receiver {
+        ""
+    } else {
+        r#" qualifiers="static""#
+    };
+
+    let ret = match &signature.return_ty {
+        Some(ty) => godot_type_name(ty),
+        None => "void".to_string(),
+    };
+
+    let docs = extract_docs(&func_def.external_attributes).unwrap_or_default();
+
+    format!(
+        r#"<method name="{name}"{qualifiers}><return type="{ret}" />{params}<description>{docs}</description></method>"#,
+        name = xml_escape(&name),
+        ret = xml_escape(&ret),
+        params = make_params_xml(signature),
+        docs = xml_escape(&docs),
+    )
+}
+
+fn make_signal_xml(signature: &Function, attributes: &[Attribute]) -> String {
+    let docs = extract_docs(attributes).unwrap_or_default();
+
+    format!(
+        r#"<signal name="{name}">{params}<description>{docs}</description></signal>"#,
+        name = xml_escape(&signature.name.to_string()),
+        params = make_params_xml(signature),
+        docs = xml_escape(&docs),
+    )
+}
+
+fn make_constant_xml(constant: &Constant) -> String {
+    let value = constant
+        .initializer
+        .as_ref()
+        .map(tokens_to_string)
+        .unwrap_or_default();
+    let docs = extract_docs(&constant.attributes).unwrap_or_default();
+
+    format!(
+        r#"<constant name="{name}" value="{value}">{docs}</constant>"#,
+        name = xml_escape(&constant.name.to_string()),
+        value = xml_escape(&value),
+        docs = xml_escape(&docs),
+    )
+}
+
+fn make_params_xml(signature: &Function) -> String {
+    signature
+        .params
+        .inner
+        .iter()
+        .filter_map(|(param, _)| match param {
+            FnParam::Typed(param) => Some(param),
+            FnParam::Receiver(_) => None,
+        })
+        .enumerate()
+        .map(|(index, param)| {
+            format!(
+                r#"<param index="{index}" name="{name}" type="{ty}" />"#,
+                name = xml_escape(&param.name.to_string()),
+                ty = xml_escape(&godot_type_name(&param.ty)),
+            )
+        })
+        .collect()
+}
+
+/// Name of a Rust type as it appears in Godot's class reference, e.g. `int` for `i64` or `Node[]` for `Array<Gd<Node>>`.
+///
+/// Works on the syntax only, so type aliases and user-defined types keep their Rust name (without path).
+fn godot_type_name(ty: &TyExpr) -> String {
+    godot_type_name_of_str(&tokens_to_string(ty))
+}
+
+fn godot_type_name_of_str(ty: &str) -> String {
+    let ty = ty.trim_start_matches('&').trim_start_matches("mut ");
+
+    let (path, generic_arg) = match ty.find('<') {
+        Some(open) if ty.ends_with('>') => (&ty[..open], Some(&ty[open + 1..ty.len() - 1])),
+        _ => (ty, None),
+    };
+    let name = path.rsplit("::").next().unwrap_or(path);
+
+    let godot_name = match (name, generic_arg) {
+        ("i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize", _) => {
+            "int"
+        }
+        ("f32" | "f64" | "real", _) => "float",
+        ("GString" | "String" | "str", _) => "String",
+        ("()", _) => "void",
+        ("VariantArray" | "Array", None) => "Array",
+        ("Array", Some(element)) => {
+            let element = godot_type_name_of_str(element);
+            return if element == "Variant" {
+                "Array".to_string()
+            } else {
+                format!("{element}[]")
+            };
+        }
+        ("TypedDictionary", _) => "Dictionary",
+        // Objects are referred to by their class name; nullability is not expressed in the class reference.
+        ("Gd" | "Option", Some(inner)) => return godot_type_name_of_str(inner),
+        (other, _) => other,
+    };
+
+    godot_name.to_string()
+}
+
+/// First paragraph of the docs, shown in class lists and tooltips.
+fn brief_description(description: &str) -> String {
+    description
+        .split("\n\n")
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Prints tokens like `Option < Gd < Node > >` in the compact form `Option<Gd<Node>>`.
+fn tokens_to_string(tokens: &impl ToTokens) -> String {
+    let spaced = tokens.to_token_stream().to_string();
+    let chars: Vec<char> = spaced.chars().collect();
+    let is_word = |c: Option<&char>| c.map_or(false, |c| c.is_alphanumeric() || *c == '_');
+
+    let mut result = String::with_capacity(spaced.len());
+    for (i, c) in chars.iter().enumerate() {
+        // Keep spaces only where they separate two words, e.g. `dyn Trait` or `mut x`.
+        if *c == ' ' && !(is_word(result.chars().last().as_ref()) && is_word(chars.get(i + 1))) {
+            continue;
+        }
+        result.push(*c);
+    }
+    result
+}
+
+/// Resolves escape sequences in a string literal as printed by `proc_macro2`, e.g. `"a \"b\"\n"` or `r#"raw"#`.
+fn unescape_str_literal(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let content = &raw[hashes..raw.len() - hashes];
+        return content
+            .strip_prefix('"')
+            .and_then(|c| c.strip_suffix('"'))
+            .map(str::to_string);
+    }
+
+    let content = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            '0' => result.push('\0'),
+            '\\' => result.push('\\'),
+            '\'' => result.push('\''),
+            '"' => result.push('"'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                result.push(u8::from_str_radix(&hex, 16).ok()? as char);
+            }
+            'u' => {
+                let hex: String = chars
+                    .by_ref()
+                    .skip(1) // {
+                    .take_while(|&c| c != '}')
+                    .collect();
+                result.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            '\n' => {
+                // Line continuation: skip leading whitespace of the next line.
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            }
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_struct(tokens: TokenStream) -> venial::Struct {
+        venial::parse_declaration(tokens)
+            .unwrap()
+            .as_struct()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn docs_from_attributes() {
+        let class = parse_struct(quote! {
+            /// Player character.
+            ///
+            /// Moves with "WASD" & <arrow keys>.
+            #[doc(hidden)]
+            struct Player;
+        });
+
+        let docs = extract_docs(&class.attributes).unwrap();
+        assert_eq!(
+            docs,
+            "Player character.\n\nMoves with \"WASD\" & <arrow keys>."
+        );
+        assert_eq!(brief_description(&docs), "Player character.");
+        assert_eq!(
+            xml_escape(&docs),
+            "Player character.\n\nMoves with &quot;WASD&quot; &amp; &lt;arrow keys&gt;."
+        );
+    }
+
+    #[test]
+    fn xml_for_sample_class() {
+        let class = parse_struct(quote! {
+            struct Player {
+                /// Hit points.
+                #[var]
+                hp: Option<Gd<Node>>,
+            }
+        });
+        let (field, _) = match &class.fields {
+            venial::StructFields::Named(fields) => fields.fields.inner[0].clone(),
+            _ => unreachable!(),
+        };
+        let field = Field::new(&field);
+        assert_eq!(
+            make_member_xml(&field.name.to_string(), &field.ty, &field.docs),
+            r#"<member name="hp" type="Node">Hit points.</member>"#
+        );
+
+        let func = venial::parse_declaration(quote! {
+            /// Deals `amount` damage.
+            fn damage(&mut self, amount: i64, source: Gd<Node>) -> bool {}
+        })
+        .unwrap();
+        let func = func.as_function().unwrap();
+        let func_def = FuncDefinition {
+            func: func.clone(),
+            external_attributes: func.attributes.clone(),
+            rename: Some("take_damage".to_string()),
+            has_gd_self: false,
+        };
+        assert_eq!(
+            make_method_xml(&func_def),
+            concat!(
+                r#"<method name="take_damage"><return type="bool" />"#,
+                r#"<param index="0" name="amount" type="int" />"#,
+                r#"<param index="1" name="source" type="Node" />"#,
+                r#"<description>Deals `amount` damage.</description></method>"#,
+            )
+        );
+    }
+
+    #[test]
+    fn godot_type_names() {
+        let cases = [
+            ("i32", "int"),
+            ("u8", "int"),
+            ("real", "float"),
+            ("f64", "float"),
+            ("bool", "bool"),
+            ("GString", "String"),
+            ("godot::builtin::GString", "String"),
+            ("StringName", "StringName"),
+            ("Vector2", "Vector2"),
+            ("Gd<Node3D>", "Node3D"),
+            ("Option<Gd<Node>>", "Node"),
+            ("VariantArray", "Array"),
+            ("Array<Variant>", "Array"),
+            ("Array<i64>", "int[]"),
+            ("Array<Gd<Texture2D>>", "Texture2D[]"),
+            ("TypedDictionary<GString, i64>", "Dictionary"),
+            ("PackedVector2Array", "PackedVector2Array"),
+            ("MyEnum", "MyEnum"),
+        ];
+
+        for (rust, godot) in cases {
+            assert_eq!(godot_type_name_of_str(rust), godot, "type {rust}");
+        }
+    }
+
+    #[test]
+    fn unescape_literals() {
+        assert_eq!(
+            unescape_str_literal(r#"" a \"b\"\n\u{e9}\x41""#).unwrap(),
+            " a \"b\"\néA"
+        );
+        assert_eq!(unescape_str_literal(r##"r#"raw \n"#"##).unwrap(), r"raw \n");
+    }
+}
@@ -7,6 +7,7 @@
 mod bench;
 mod class;
 mod derive;
+mod docs;
 mod gdextension;
 mod itest;
 mod util;
@@ -279,6 +280,26 @@ use crate::util::ident;
 /// impl MyStruct {}
 /// ```
 ///
+/// Arrays (`Array<T>` and the `Packed*Array` types) can apply a hint to each of their elements instead, by adding `each`.
+/// This corresponds to annotating a typed array in GDScript, e.g. `@export_range(0.0, 1.0) var weights: Array[float]`.
+/// Arrays of resources such as `Array<Gd<MyResource>>` restrict their elements to that resource type automatically.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     #[export(range = (0.0, 1.0), each)]
+///     weights: Array<f64>,
+///
+///     #[export(enum = (Small, Medium, Large), each)]
+///     sizes: PackedInt32Array,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {}
+/// ```
+///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the
 /// `hint`, `hint_string`, and `usage_flags` keys in the attribute:
 ///
@@ -302,6 +323,34 @@ use crate::util::ident;
 /// impl MyStruct {}
 /// ```
 ///
+/// For the most common combinations of usage flags, `#[export]` and `#[var]` accept presets instead:
+///
+/// - `#[export(storage)]` saves the property in scenes and resources, but hides it in the inspector.
+/// - `#[export(read_only)]` shows the property in the inspector, but does not allow editing it there.
+/// - `#[export(editor_only)]` shows the property in the inspector, but does not save it.
+/// - `#[var(no_editor)]` explicitly requests the default `#[var]` behavior: stored, but not shown in the editor.
+///
+/// Presets can be combined with the other `#[export]` keys, but not with each other or with `usage_flags`.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     #[export(storage)]
+///     saved_but_hidden: i64,
+///
+///     #[export(range = (0.0, 1.0), read_only)]
+///     progress: f64,
+///
+///     #[export(editor_only)]
+///     preview_color: Color,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {}
+/// ```
+///
 ///
 /// # Signals
 ///
@@ -319,6 +368,31 @@ use crate::util::ident;
 ///
 /// This is very similar to [GDScript's `@tool` feature](https://docs.godotengine.org/en/stable/tutorials/plugins/running_code_in_the_editor.html).
 ///
+/// Inside callbacks, `self.is_in_editor()` and `self.is_in_game()` from the
+/// [`EditorAware`](../obj/trait.EditorAware.html) trait tell whether code currently runs in the editor, e.g. to skip gameplay logic.
+/// Individual virtual methods of a tool class can also be restricted to the editor, by annotating them with `#[godot_api(editor)]`.
+/// They are then not invoked in-game at all:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=Node2D)]
+/// struct Gizmo {
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Gizmo {
+///     #[godot_api(editor)]
+///     fn process(&mut self, _delta: f64) {
+///         // Only runs in the editor.
+///     }
+/// }
+/// ```
+///
+/// Using `#[godot_api(editor)]` in a class without `#[class(tool)]` is a compile error.
+///
 /// # Editor Plugins
 ///
 /// If you annotate a class with `#[class(editor_plugin)]`, it will be turned into an editor plugin. The
@@ -333,6 +407,12 @@ use crate::util::ident;
 /// This should usually be combined with `#[class(tool)]` so that the code you write will actually run in the
 /// editor.
 ///
+/// # Documentation
+///
+/// With the `register-docs` Cargo feature, `///` doc comments on the struct, its `#[var]`/`#[export]` fields as well as on `#[func]`,
+/// `#[signal]` and `#[constant]` items in `#[godot_api]` blocks are shown in the editor's built-in help (requires Godot 4.3+).
+/// The first paragraph of the struct's docs becomes the brief description.
+///
 /// # Class Renaming
 ///
 /// You may want to have structs with the same name. With Rust, this is allowed using `mod`. However in GDScript,
@@ -442,7 +522,12 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
 }
 
-#[proc_macro_derive(GodotConvert)]
+/// Derive macro for [GodotConvert](../builtin/meta/trait.GodotConvert.html) on structs or enums.
+///
+/// By default, the type is passed to Godot as a [`Variant`](../builtin/struct.Variant.html). For fieldless enums, the
+/// `#[godot(via = GString)]` and `#[godot(flags)]` attributes select a more natural representation; see [`Property`] for details.
+/// The `ToGodot` and `FromGodot` derives honor the same attribute, so all three should be derived together.
+#[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
@@ -475,7 +560,7 @@ pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// You can use the `#[skip]` attribute to ignore a field from being converted to `ToGodot`.
-#[proc_macro_derive(ToGodot, attributes(variant))]
+#[proc_macro_derive(ToGodot, attributes(variant, godot))]
 pub fn derive_to_godot(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_to_godot)
 }
@@ -509,7 +594,7 @@ pub fn derive_to_godot(input: TokenStream) -> TokenStream {
 ///
 /// You can use the skip attribute to ignore a field from the provided variant and use `Default::default()`
 /// to get it instead.
-#[proc_macro_derive(FromGodot, attributes(variant))]
+#[proc_macro_derive(FromGodot, attributes(variant, godot))]
 pub fn derive_from_godot(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_from_godot)
 }
@@ -552,7 +637,43 @@ pub fn derive_from_godot(input: TokenStream) -> TokenStream {
 /// assert_eq!(class.foo, TestEnum::A);
 /// # }
 /// ```
-#[proc_macro_derive(Property)]
+///
+/// # Representation
+///
+/// The representation in Godot can be changed with a `#[godot(...)]` attribute on the enum:
+///
+/// - `#[godot(via = GString)]` stores the variant's name in a `String` property. Neither `#[repr]` nor explicit discriminants
+///   are required. Exporting such an enum produces `PROPERTY_HINT_ENUM` with the variant names.
+/// - `#[godot(flags)]` treats the discriminants as bit flags. Exporting such an enum produces `PROPERTY_HINT_FLAGS`, and variants
+///   may combine other flags (e.g. `AB = 0b11`). The enum itself only converts back from integer values matching a variant's
+///   discriminant. Since the editor combines ticked flags with bitwise OR, use
+///   [`FlagSet<Element>`](../bind/property/struct.FlagSet.html) as the field type to hold any combination; `FlagSet` is
+///   exported with the same hint and requires `Property` to be derived on the enum.
+///
+/// Setting a property to a value that matches no variant (an unknown name or flag combination) logs an error and keeps the
+/// previous value.
+///
+/// The `GodotConvert`, `ToGodot` and `FromGodot` derives honor the same attribute, converting via `GString` or `i64` respectively.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(Property, Export, GodotConvert, ToGodot, FromGodot)]
+/// #[godot(via = GString)]
+/// enum Weapon {
+///     Sword,
+///     Bow,
+/// }
+///
+/// #[repr(u32)]
+/// #[derive(Property, Export, GodotConvert, ToGodot, FromGodot)]
+/// #[godot(flags)]
+/// enum Element {
+///     Fire = 1,
+///     Water = 2,
+///     Steam = 3,
+/// }
+/// ```
+#[proc_macro_derive(Property, attributes(godot))]
 pub fn derive_property(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_property)
 }
@@ -560,7 +681,8 @@ pub fn derive_property(input: TokenStream) -> TokenStream {
 /// Derive macro for [Export](../bind/property/trait.Export.html) on enums.
 ///
 /// Currently has some tight requirements which are expected to be softened as implementation expands, see requirements for [Property].
-#[proc_macro_derive(Export)]
+/// The `#[godot(via = GString)]` and `#[godot(flags)]` representations are supported as well.
+#[proc_macro_derive(Export, attributes(godot))]
 pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
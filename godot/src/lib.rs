@@ -131,10 +131,16 @@
 //!
 //! * **`serde`**
 //!
-//!   Implement the [serde](https://docs.rs/serde) traits `Serialize` and `Deserialize` traits for certain built-in types.
+//!   Implement the [serde](https://docs.rs/serde) traits `Serialize` and `Deserialize` for built-in types, including `Variant`.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!   <br><br>
 //!
+//! * **`bytemuck`**
+//!
+//!   Implement the [bytemuck](https://docs.rs/bytemuck) traits `Pod` and `Zeroable` for vector and color types, and enable
+//!   casting helpers on `PackedByteArray`. This allows to move mesh or image data between Rust crates and Godot without
+//!   per-element conversions.<br><br>
+//!
 //! * **`experimental-threads`**
 //!
 //!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
@@ -153,6 +159,11 @@
 //!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
 //!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.
 //!
+//! * **`register-docs`**
+//!
+//!   Show the `///` doc comments of classes, properties, functions, signals and constants in the editor's built-in help.
+//!   Requires Godot 4.3 or later; with older versions, the docs are not loaded. Increases binary size, as the docs are embedded.
+//!
 //! # Public API
 //!
 //! Some symbols in the API are not intended for users, however Rust's visibility feature is not strong enough to express that in all cases
@@ -212,7 +223,7 @@ pub mod prelude {
     pub use super::builtin::math::FloatExt as _;
     pub use super::builtin::meta::{FromGodot, ToGodot};
     pub use super::builtin::*;
-    pub use super::builtin::{array, dict, varray}; // Re-export macros.
+    pub use super::builtin::{array, dict, sname, varray}; // Re-export macros.
     pub use super::engine::{
         load, try_load, utilities, AudioStreamPlayer, Camera2D, Camera3D, IAudioStreamPlayer,
         ICamera2D, ICamera3D, INode, INode2D, INode3D, IObject, IPackedScene, IRefCounted,
@@ -225,5 +236,6 @@ pub mod prelude {
 
     // Make trait methods available
     pub use super::engine::NodeExt as _;
+    pub use super::obj::EditorAware as _;
     pub use super::obj::EngineEnum as _;
 }
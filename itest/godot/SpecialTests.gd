@@ -47,3 +47,17 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Test that `Callable::call_deferred()` invokes the method once the frame ends, which cannot happen within a
+# standard integration test.
+func test_callable_call_deferred():
+	var root: Node = Engine.get_main_loop().root
+
+	var obj := CallableTestObj.new()
+	obj.foo_deferred(5)
+	assert_eq(obj.value, 0, "deferred call not yet executed")
+
+	# Deferred calls are flushed during the frame; the first signal may be emitted before that happens.
+	await root.get_tree().process_frame
+	await root.get_tree().process_frame
+
+	assert_eq(obj.value, 5, "deferred call executed")
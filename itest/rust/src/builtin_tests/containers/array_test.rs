@@ -288,6 +288,79 @@ fn array_sort() {
     assert_eq!(array, array![1, 2]);
 }
 
+#[itest]
+fn array_sort_unstable_by() {
+    let values = vec![5, -3, 8, 0, -7, 2, 2, -1];
+    let mut array = Array::from(values.as_slice());
+    array.sort_unstable_by(|a: &i64, b: &i64| b.cmp(a));
+
+    let mut expected = values;
+    expected.sort_by(|a, b| b.cmp(a));
+    assert_eq!(Vec::from(&array), expected);
+}
+
+#[itest]
+fn array_sort_by_key() {
+    let values: Vec<GString> = ["kiwi", "fig", "banana", "apple", "pear"]
+        .into_iter()
+        .map(GString::from)
+        .collect();
+    let mut array = Array::from(values.as_slice());
+    array.sort_by_key(|s: &GString| s.len());
+
+    // Stable: "kiwi" and "pear" keep their original relative order.
+    let mut expected = values;
+    expected.sort_by_key(|s| s.len());
+    assert_eq!(Vec::from(&array), expected);
+}
+
+#[itest]
+fn array_sort_gd_by_field() {
+    let scores = [30, 10, 20];
+    let mut array: Array<Gd<ArraySortItem>> = scores
+        .into_iter()
+        .map(|score| Gd::new(ArraySortItem { score }))
+        .collect();
+    let first = array.get(0);
+
+    array.sort_unstable_by(|a, b| a.bind().score.cmp(&b.bind().score));
+
+    let sorted: Vec<i64> = array.iter_shared().map(|item| item.bind().score).collect();
+    assert_eq!(sorted, vec![10, 20, 30]);
+
+    // Elements are moved, not copied.
+    assert_eq!(array.get(2), first);
+}
+
+#[itest]
+fn array_sort_custom() {
+    let mut array = array![1, 3, 2];
+    let object = Gd::new(ArrayTest);
+    array.sort_custom(Callable::from_object_method(object, "is_greater"));
+
+    assert_eq!(array, array![3, 2, 1]);
+}
+
+#[itest]
+fn array_binary_search_by() {
+    let values = vec![1, 3, 3, 7, 9];
+    let array = Array::from(values.as_slice());
+
+    for target in 0..11 {
+        let result = array.binary_search_by(|element: &i64| element.cmp(&target));
+        let expected = values.binary_search_by(|element| element.cmp(&target));
+
+        match expected {
+            // With duplicates, any matching index is valid.
+            Ok(_) => assert_eq!(array.get(result.unwrap()), target),
+            Err(index) => assert_eq!(result, Err(index)),
+        }
+    }
+
+    let empty = Array::<i64>::new();
+    assert_eq!(empty.binary_search_by(|element| element.cmp(&5)), Err(0));
+}
+
 #[itest]
 fn array_reverse() {
     let mut array = array![1, 2];
@@ -418,8 +491,11 @@ fn typed_array_try_from_untyped() {
     let node = Node::new_alloc();
     let array = VariantArray::from(&[node.clone().to_variant()]);
     assert_eq!(
-        array.to_variant().try_to::<Array<Option<Gd<Node>>>>(),
-        Err(VariantConversionError::BadType)
+        array
+            .to_variant()
+            .try_to::<Array<Option<Gd<Node>>>>()
+            .map_err(|err| err.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     node.free();
 }
@@ -429,8 +505,11 @@ fn untyped_array_try_from_typed() {
     let node = Node::new_alloc();
     let array = Array::<Option<Gd<Node>>>::from(&[Some(node.clone())]);
     assert_eq!(
-        array.to_variant().try_to::<VariantArray>(),
-        Err(VariantConversionError::BadType)
+        array
+            .to_variant()
+            .try_to::<VariantArray>()
+            .map_err(|err| err.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     node.free();
 }
@@ -445,4 +524,15 @@ impl ArrayTest {
     fn return_typed_array(&self, n: i64) -> Array<i64> {
         (1..(n + 1)).collect()
     }
+
+    #[func]
+    fn is_greater(&self, a: i64, b: i64) -> bool {
+        a > b
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ArraySortItem {
+    score: i64,
 }
@@ -7,16 +7,19 @@
 use godot::bind::{godot_api, GodotClass};
 use godot::builtin::inner::InnerCallable;
 use godot::builtin::meta::ToGodot;
-use godot::builtin::{varray, Callable, GString, StringName, Variant};
-use godot::engine::{Node2D, Object};
-use godot::obj::Gd;
+use godot::builtin::{varray, Callable, GString, StringName, Variant, Vector2};
+use godot::engine::{Node2D, Object, RefCounted};
+use godot::obj::{Base, Gd};
 
 use crate::framework::itest;
 
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
 struct CallableTestObj {
+    #[var]
     value: i32,
+    #[base]
+    base: Base<RefCounted>,
 }
 
 #[godot_api]
@@ -30,6 +33,12 @@ impl CallableTestObj {
     fn bar(&self, b: i32) -> GString {
         b.to_variant().stringify()
     }
+
+    /// Calls `foo(a)` through `Callable::call_deferred()`. Awaiting the result requires a frame, see `SpecialTests.gd`.
+    #[func]
+    fn foo_deferred(&self, a: i32) {
+        self.base.callable("foo").call_deferred(&[a.to_variant()]);
+    }
 }
 
 #[itest]
@@ -40,6 +49,7 @@ fn callable_validity() {
     assert!(obj.callable("foo").is_valid());
     assert!(!obj.callable("foo").is_null());
     assert!(!obj.callable("foo").is_custom());
+    assert!(obj.callable("foo").is_standard());
     assert!(obj.callable("foo").object().is_some());
 
     // non-null object, invalid method
@@ -52,6 +62,7 @@ fn callable_validity() {
     assert!(!Callable::invalid().is_valid());
     assert!(Callable::invalid().is_null());
     assert!(!Callable::invalid().is_custom());
+    assert!(Callable::invalid().is_standard());
     assert!(Callable::invalid().object().is_none());
 }
 
@@ -117,16 +128,63 @@ fn callable_call_engine() {
     assert_eq!(inner.get_object_id(), obj.instance_id().to_i64());
     assert_eq!(inner.get_method(), StringName::from("set_position"));
 
-    // TODO once varargs is available
-    // let pos = Vector2::new(5.0, 7.0);
-    // inner.call(&[pos.to_variant()]);
-    // assert_eq!(obj.position(), pos);
-    //
-    // inner.bindv(array);
+    let pos = Vector2::new(5.0, 7.0);
+    cb.call(&[pos.to_variant()]);
+    assert_eq!(obj.get_position(), pos);
 
     obj.free();
 }
 
+#[itest]
+fn callable_call_slice() {
+    let obj = Gd::<CallableTestObj>::new_default();
+
+    obj.callable("foo").call(&[12.to_variant()]);
+    assert_eq!(obj.bind().value, 12);
+
+    let result = obj.callable("bar").call(&[7.to_variant()]);
+    assert_eq!(result, "7".to_variant());
+
+    assert_eq!(Callable::invalid().call(&[]), Variant::nil());
+}
+
+#[itest]
+fn callable_bind() {
+    let obj = Gd::<CallableTestObj>::new_default();
+    let bound = obj.callable("bar").bind(&[10.to_variant()]);
+
+    assert!(bound.is_custom());
+    assert!(!bound.is_standard());
+    assert_eq!(bound.get_bound_arguments(), varray![10]);
+    assert_eq!(bound.call(&[]), "10".to_variant());
+
+    assert_eq!(obj.callable("bar").get_bound_arguments(), varray![]);
+}
+
+#[itest]
+fn callable_unbind() {
+    let obj = Gd::<CallableTestObj>::new_default();
+    let unbound = obj.callable("foo").unbind(2);
+
+    unbound.call(&[3.to_variant(), "ignored".to_variant(), Variant::nil()]);
+    assert_eq!(obj.bind().value, 3);
+}
+
+#[itest]
+#[cfg(since_api = "4.3")]
+fn callable_get_argument_count() {
+    let obj = Gd::<CallableTestObj>::new_default();
+
+    assert_eq!(obj.callable("foo").get_argument_count(), 1);
+    assert_eq!(obj.callable("foo").unbind(2).get_argument_count(), 3);
+    assert_eq!(
+        obj.callable("foo")
+            .bind(&[1.to_variant()])
+            .get_argument_count(),
+        0
+    );
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/410
 
 #[derive(GodotClass)]
@@ -142,12 +200,15 @@ impl CallableRefcountTest {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests and infrastructure for custom callables
 
-#[cfg(since_api = "4.2")]
 mod custom_callable {
     use super::*;
-    use godot::builtin::Dictionary;
+    use crate::framework::suppress_godot_print;
+    use godot::builtin::meta::VarcallSignatureTuple;
+    use godot::builtin::{CallError, VariantArray, VariantType};
+    use std::cell::Cell;
     use std::fmt;
     use std::hash::Hash;
+    use std::rc::Rc;
     use std::sync::{Arc, Mutex};
 
     #[itest]
@@ -157,6 +218,7 @@ mod custom_callable {
         assert!(callable.is_valid());
         assert!(!callable.is_null());
         assert!(callable.is_custom());
+        #[cfg(since_api = "4.2")] // bound to a helper object before
         assert!(callable.object().is_none());
 
         let sum1 = callable.callv(varray![1, 2, 4, 8]);
@@ -166,6 +228,34 @@ mod custom_callable {
         assert_eq!(sum2, 5.to_variant());
     }
 
+    #[itest]
+    fn callable_from_fn_lifetime() {
+        let state = Arc::new(Mutex::new(0));
+
+        let variant = {
+            let state = state.clone();
+            let callable = Callable::from_fn("increment", move |_args| {
+                *state.lock().unwrap() += 1;
+                Ok(Variant::nil())
+            });
+
+            callable.to_variant()
+        };
+
+        // Only the Variant refers to the function now.
+        assert_eq!(Arc::strong_count(&state), 2, "function kept alive");
+
+        variant.to::<Callable>().callv(varray![]);
+        assert_eq!(*state.lock().unwrap(), 1);
+
+        drop(variant);
+        assert_eq!(
+            Arc::strong_count(&state),
+            1,
+            "function dropped with callable"
+        );
+    }
+
     #[itest]
     fn callable_from_fn_eq() {
         let a = Callable::from_fn("sum", sum);
@@ -181,6 +271,149 @@ mod custom_callable {
         Ok(sum.to_variant())
     }
 
+    #[itest]
+    fn callable_from_local_fn() {
+        let node = Node2D::new_alloc();
+        let calls = Rc::new(Cell::new(0));
+
+        let callable = {
+            let mut node = node.clone();
+            let calls = calls.clone();
+
+            Callable::from_local_fn("move_node", move |args: &[&Variant]| {
+                node.set_position(args[0].to());
+                calls.set(calls.get() + 1);
+
+                Ok(Variant::nil())
+            })
+        };
+
+        assert!(callable.is_valid());
+        assert!(callable.is_custom());
+        assert_eq!(callable.to_string(), "move_node");
+
+        callable.callv(varray![Vector2::new(3.0, 4.0)]);
+        assert_eq!(node.get_position(), Vector2::new(3.0, 4.0));
+        assert_eq!(calls.get(), 1);
+
+        drop(callable);
+        assert_eq!(Rc::strong_count(&calls), 1, "closure dropped with callable");
+
+        node.free();
+    }
+
+    #[itest]
+    #[cfg(feature = "experimental-threads")]
+    fn callable_from_local_fn_other_thread() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+
+        let callable = Callable::from_local_fn("local", move |_args: &[&Variant]| {
+            counter.set(counter.get() + 1);
+            Ok(1.to_variant())
+        });
+
+        let mut result = Variant::nil();
+        suppress_godot_print(|| {
+            let sent = callable.clone();
+            result = std::thread::spawn(move || sent.callv(varray![]))
+                .join()
+                .unwrap();
+        });
+
+        assert_eq!(result, Variant::nil());
+        assert_eq!(calls.get(), 0, "function not invoked on other thread");
+
+        assert_eq!(callable.callv(varray![]), 1.to_variant());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[itest]
+    fn callable_from_typed_fn() {
+        let callable =
+            Callable::from_typed_fn("describe", |name: GString, count: i64| -> GString {
+                format!("{name} x{count}").into()
+            });
+
+        assert!(callable.is_valid());
+        assert!(callable.is_custom());
+        assert_eq!(callable.to_string(), "describe");
+
+        let result = callable.callv(varray!["apple", 3]);
+        assert_eq!(result, "apple x3".to_variant());
+
+        let nullary = Callable::from_typed_fn("answer", || 42);
+        assert_eq!(nullary.callv(varray![]), 42.to_variant());
+
+        let unit = Callable::from_typed_fn("ignore", |_node: Option<Gd<Object>>| {});
+        assert_eq!(unit.callv(varray![Variant::nil()]), Variant::nil());
+    }
+
+    #[itest]
+    fn callable_from_typed_fn_mismatch() {
+        let calls = Arc::new(Mutex::new(0));
+        let counter = calls.clone();
+
+        let callable = Callable::from_typed_fn("add", move |a: i32, b: i32| {
+            *counter.lock().unwrap() += 1;
+            a + b
+        });
+
+        assert_eq!(callable.callv(varray![1, 2]), 3.to_variant());
+
+        suppress_godot_print(|| {
+            assert_eq!(callable.callv(varray![1]), Variant::nil());
+            assert_eq!(callable.callv(varray![1, 2, 3]), Variant::nil());
+            assert_eq!(callable.callv(varray![1, "two"]), Variant::nil());
+        });
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            1,
+            "function not invoked on mismatch"
+        );
+    }
+
+    #[itest]
+    fn typed_fn_param_errors() {
+        let int = 7.to_variant();
+        let string = "seven".to_variant();
+
+        let params = <((), i32, GString)>::params_from_variants(&[&int, &string]);
+        assert_eq!(params, Ok((7, GString::from("seven"))));
+
+        let err = <((), i32, GString)>::params_from_variants(&[&int]).unwrap_err();
+        assert_eq!(
+            err,
+            CallError::TooFewArguments {
+                expected: 2,
+                actual: 1
+            }
+        );
+
+        let err = <((), i32)>::params_from_variants(&[&int, &string]).unwrap_err();
+        assert_eq!(
+            err,
+            CallError::TooManyArguments {
+                expected: 1,
+                actual: 2
+            }
+        );
+
+        let err = <((), i32, i32)>::params_from_variants(&[&int, &string]).unwrap_err();
+        let CallError::InvalidArgument {
+            index,
+            expected,
+            error,
+        } = err
+        else {
+            panic!("expected invalid argument, got {err:?}");
+        };
+        assert_eq!(index, 1);
+        assert_eq!(expected, VariantType::Int);
+        assert_eq!(error.actual_type(), VariantType::String);
+    }
+
     #[itest]
     fn callable_custom_invoke() {
         let my_rust_callable = Adder::new(0);
@@ -189,6 +422,7 @@ mod custom_callable {
         assert!(callable.is_valid());
         assert!(!callable.is_null());
         assert!(callable.is_custom());
+        #[cfg(since_api = "4.2")] // bound to a helper object before
         assert!(callable.object().is_none());
 
         let sum1 = callable.callv(varray![3, 9, 2, 1]);
@@ -198,16 +432,90 @@ mod custom_callable {
         assert_eq!(sum2, 19.to_variant());
     }
 
+    #[itest]
+    fn callable_custom_introspection() {
+        let adder = Callable::from_custom(Adder::new(0));
+        assert_eq!(
+            adder.get_bound_arguments(),
+            varray![],
+            "default: none bound"
+        );
+
+        let greeter = Callable::from_custom(Greeter {
+            greeting: GString::from("hello"),
+        });
+        assert_eq!(greeter.get_bound_arguments(), varray!["hello"]);
+        assert_eq!(
+            greeter.call(&["world".to_variant()]),
+            "hello world".to_variant()
+        );
+
+        #[cfg(since_api = "4.3")]
+        {
+            assert_eq!(adder.get_argument_count(), 0, "default: unknown");
+            assert_eq!(greeter.get_argument_count(), 1);
+
+            let typed = Callable::from_typed_fn("typed", |a: i32, b: i32| a * b);
+            assert_eq!(typed.get_argument_count(), 2);
+        }
+    }
+
+    #[itest]
+    fn callable_custom_bind() {
+        let callable = Callable::from_fn("sum", sum).bind(&[10.to_variant(), 20.to_variant()]);
+
+        assert_eq!(callable.get_bound_arguments(), varray![10, 20]);
+        assert_eq!(callable.call(&[1.to_variant()]), 31.to_variant());
+
+        let unbound = Callable::from_fn("sum", sum).unbind(1);
+        assert_eq!(
+            unbound.call(&[1.to_variant(), 2.to_variant()]),
+            1.to_variant()
+        );
+    }
+
     #[itest]
     fn callable_custom_to_string() {
         let my_rust_callable = Adder::new(-2);
         let callable = Callable::from_custom(my_rust_callable);
 
-        let variant = callable.to_variant();
-        assert_eq!(variant.stringify(), GString::from("Adder(sum=-2)"));
+        assert_eq!(callable.to_string(), "Adder(sum=-2)");
+
+        #[cfg(since_api = "4.2")] // Godot only knows the helper object before
+        assert_eq!(
+            callable.to_variant().stringify(),
+            GString::from("Adder(sum=-2)")
+        );
+    }
+
+    #[itest]
+    fn callable_custom_eq_hash_rust() {
+        // Unlike callable_custom_eq*(), only uses Rust-side operations, which behave the same on every API level.
+
+        let at = Tracker::new();
+        let bt = Tracker::new();
+
+        let a = Callable::from_custom(Adder::new_tracked(3, at.clone()));
+        let b = Callable::from_custom(Adder::new_tracked(3, bt.clone()));
+        let c = Callable::from_custom(Adder::new(4));
+
+        assert_eq!(a, a.clone());
+        assert_eq!(eq_count(&at), 0, "same instance, no custom eq");
+
+        assert_eq!(a, b, "equal via RustCallable's PartialEq impl");
+        assert_eq!(eq_count(&at), 1);
+        assert_eq!(eq_count(&bt), 1);
+
+        assert_ne!(a, c);
+        assert_ne!(a, Callable::from_fn("sum", sum), "different types");
+
+        assert_eq!(a.hash(), b.hash(), "hash via RustCallable's Hash impl");
+        assert_eq!(hash_count(&at), 1);
+        assert_eq!(hash_count(&bt), 1);
     }
 
     #[itest]
+    #[cfg(since_api = "4.2")] // Godot-side equality of the helper objects doesn't use RustCallable before
     fn callable_custom_eq() {
         // Godot only invokes custom equality function if the operands are not the same instance of the Callable.
 
@@ -244,7 +552,10 @@ mod custom_callable {
     }
 
     #[itest]
+    #[cfg(since_api = "4.2")]
     fn callable_custom_eq_hash() {
+        use godot::builtin::Dictionary;
+
         // Godot only invokes custom equality function if the operands are not the same instance of the Callable.
 
         let at = Tracker::new();
@@ -323,6 +634,35 @@ mod custom_callable {
         }
     }
 
+    /// Custom callable which declares a bound argument (the greeting) and its argument count.
+    #[derive(PartialEq, Hash)]
+    struct Greeter {
+        greeting: GString,
+    }
+
+    impl fmt::Display for Greeter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Greeter({})", self.greeting)
+        }
+    }
+
+    impl godot::builtin::RustCallable for Greeter {
+        fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+            let [name] = args else { return Err(()) };
+            let name = name.try_to::<GString>().map_err(|_| ())?;
+
+            Ok(format!("{} {name}", self.greeting).to_variant())
+        }
+
+        fn argument_count(&self) -> Option<usize> {
+            Some(1)
+        }
+
+        fn bound_arguments(&self) -> VariantArray {
+            varray![self.greeting.clone()]
+        }
+    }
+
     struct Tracker {
         eq_counter: usize,
         hash_counter: usize,
@@ -6,8 +6,11 @@
 
 use std::collections::{HashMap, HashSet};
 
+use godot::bind::property::{Export, TypeStringHint};
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{
+    dict, varray, ConversionErrorKind, Dictionary, GString, TypedDictionary, Variant,
+};
 use godot::sys::GdextBuild;
 
 use crate::framework::{expect_panic, itest};
@@ -614,3 +617,104 @@ fn dictionary_iter_erase() {
     ```
      */
 }
+
+#[itest]
+fn typed_dictionary_insert_get() {
+    let mut dictionary = TypedDictionary::<GString, i64>::new();
+    assert!(dictionary.is_empty());
+
+    assert_eq!(dictionary.insert("foo".into(), 1), None);
+    assert_eq!(dictionary.insert("bar".into(), 2), None);
+    assert_eq!(dictionary.insert("foo".into(), 3), Some(1));
+
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get(&"foo".into()), Some(3));
+    assert_eq!(dictionary.get(&"bar".into()), Some(2));
+    assert_eq!(dictionary.get(&"baz".into()), None);
+    assert!(dictionary.contains_key(&"bar".into()));
+
+    assert_eq!(dictionary.remove(&"bar".into()), Some(2));
+    assert_eq!(dictionary.remove(&"bar".into()), None);
+    assert_eq!(dictionary.len(), 1);
+}
+
+#[itest]
+fn typed_dictionary_iter() {
+    let dictionary: TypedDictionary<i64, GString> =
+        [(1, GString::from("one")), (2, GString::from("two"))]
+            .into_iter()
+            .collect();
+
+    let map: HashMap<i64, GString> = dictionary.iter_shared().collect();
+    let expected = HashMap::from([(1, GString::from("one")), (2, GString::from("two"))]);
+    assert_eq!(map, expected);
+
+    let keys: HashSet<i64> = dictionary.keys_shared().collect();
+    assert_eq!(keys, HashSet::from([1, 2]));
+}
+
+#[itest]
+fn typed_dictionary_from_untyped() {
+    let untyped = dict! { "a": 1, "b": 2 };
+    let typed = TypedDictionary::<GString, i64>::try_from_untyped(untyped.clone())
+        .expect("all entries have matching types");
+
+    // Refers to the same data.
+    let mut untyped = untyped;
+    untyped.set("c", 3);
+    assert_eq!(typed.get(&"c".into()), Some(3));
+    assert_eq!(typed.as_untyped(), &untyped);
+
+    let bad_value = dict! { "a": 1, "b": "two" };
+    let err = TypedDictionary::<GString, i64>::try_from_untyped(bad_value).unwrap_err();
+    assert_eq!(err.kind(), ConversionErrorKind::BadType);
+    assert_eq!(err.path(), r#"["b"]"#);
+
+    let bad_key = dict! { "a": 1, 2: 2 };
+    let err = TypedDictionary::<GString, i64>::try_from(bad_key).unwrap_err();
+    assert_eq!(err.kind(), ConversionErrorKind::BadType);
+    assert_eq!(err.path(), "[2]");
+}
+
+#[itest]
+fn typed_dictionary_variant_conversion() {
+    let typed: TypedDictionary<GString, bool> = [(GString::from("on"), true)].into_iter().collect();
+
+    let variant = typed.to_variant();
+    let back = TypedDictionary::<GString, bool>::try_from_variant(&variant);
+    assert_eq!(back, Ok(typed));
+
+    let mismatched = TypedDictionary::<GString, i64>::try_from_variant(&variant);
+    assert_eq!(
+        mismatched.map_err(|err| err.kind()),
+        Err(ConversionErrorKind::BadType)
+    );
+
+    let not_a_dictionary =
+        TypedDictionary::<GString, bool>::try_from_variant(&varray![1].to_variant());
+    assert!(not_a_dictionary.is_err());
+}
+
+#[itest]
+fn typed_dictionary_from_godot_panics() {
+    let bad_value = dict! { "a": 1, "b": "two" };
+    expect_panic("value of wrong type", || {
+        TypedDictionary::<GString, i64>::from_godot(bad_value);
+    });
+
+    let typed = TypedDictionary::<GString, i64>::from_godot(dict! { "a": 1 });
+    assert_eq!(typed.get(&"a".into()), Some(1));
+}
+
+#[itest]
+fn typed_dictionary_export_info() {
+    // Exported untyped: no dedicated hint for key/value types, which the editor could not interpret.
+    assert_eq!(
+        TypedDictionary::<GString, i64>::default_export_info(),
+        Dictionary::default_export_info()
+    );
+    assert_eq!(
+        TypedDictionary::<GString, i64>::type_string(),
+        Dictionary::type_string()
+    );
+}
@@ -5,7 +5,13 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{
+    array, real, varray, Array, Color, GString, PackedArray, PackedArrayElement, PackedByteArray,
+    PackedColorArray, PackedFloat32Array, PackedInt64Array, PackedStringArray, PackedVector3Array,
+    Variant, Vector3,
+};
+use godot::engine::file_access::CompressionMode;
 
 #[itest]
 fn packed_array_default() {
@@ -43,16 +49,112 @@ fn packed_array_to_vec() {
     assert_eq!(array.to_vec(), vec![1, 2]);
 }
 
-/*
-#[itest(skip)]
+#[itest]
+fn packed_array_from_vec() {
+    let array = PackedByteArray::from(vec![1, 2, 3]);
+    assert_eq!(array.to_vec(), vec![1, 2, 3]);
+
+    let strings = PackedStringArray::from(vec![GString::from("a"), GString::from("b")]);
+    assert_eq!(strings.get(1), GString::from("b"));
+
+    let back: Vec<GString> = strings.into();
+    assert_eq!(back, vec![GString::from("a"), GString::from("b")]);
+}
+
+#[itest]
+fn packed_array_extend_from_slice() {
+    let mut array = PackedByteArray::from(&[1, 2]);
+    array.extend_from_slice(&[3, 4, 5]);
+    array.extend_from_slice(&[]);
+    assert_eq!(array.to_vec(), vec![1, 2, 3, 4, 5]);
+
+    let mut strings = PackedStringArray::new();
+    strings.extend_from_slice(&[GString::from("x")]);
+    assert_eq!(strings.to_vec(), vec![GString::from("x")]);
+}
+
+#[itest]
+fn packed_array_components() {
+    let components: [[real; 3]; 2] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let mut array = PackedVector3Array::from_components(&components);
+    assert_eq!(
+        array.to_vec(),
+        vec![Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)]
+    );
+    assert_eq!(array.as_components(), &components);
+
+    array.as_mut_components()[1][2] = 7.0;
+    assert_eq!(array.get(1), Vector3::new(4.0, 5.0, 7.0));
+
+    let colors = PackedColorArray::from(&[[1.0, 0.5, 0.25, 1.0]][..]);
+    assert_eq!(colors.get(0), Color::from_rgba(1.0, 0.5, 0.25, 1.0));
+    assert!(PackedVector3Array::new().as_components().is_empty());
+}
+
+#[itest]
 fn packed_array_into_iterator() {
     let array = PackedByteArray::from(&[1, 2]);
+
+    let borrowed: Vec<u8> = (&array).into_iter().copied().collect();
+    assert_eq!(borrowed, vec![1, 2]);
+
     let mut iter = array.into_iter();
     assert_eq!(iter.next(), Some(1));
     assert_eq!(iter.next(), Some(2));
     assert_eq!(iter.next(), None);
 }
-*/
+
+fn sum_lengths<T: PackedArrayElement>(arrays: &[PackedArray<T>]) -> usize {
+    arrays.iter().map(PackedArray::len).sum()
+}
+
+fn reversed<T: PackedArrayElement>(array: &PackedArray<T>) -> PackedArray<T> {
+    let mut copy = array.clone();
+    copy.reverse();
+    copy
+}
+
+#[itest]
+fn packed_array_generic() {
+    let bytes = PackedByteArray::from(&[1, 2, 3]);
+    let vectors: PackedVector3Array = [Vector3::ONE, Vector3::UP].into_iter().collect();
+    let strings: PackedArray<GString> =
+        PackedArray::from(&[GString::from("a"), GString::from("b")]);
+
+    assert_eq!(sum_lengths(&[bytes.clone(), PackedByteArray::new()]), 3);
+    assert_eq!(sum_lengths(&[vectors.clone()]), 2);
+
+    assert_eq!(reversed(&bytes).to_vec(), vec![3, 2, 1]);
+    assert_eq!(reversed(&vectors).get(0), Vector3::UP);
+    assert_eq!(reversed(&strings).get(0), GString::from("b"));
+}
+
+#[itest]
+fn packed_array_typed_array_conversion() {
+    let array: Array<i64> = array![1, 2, 3];
+    let packed = PackedInt64Array::from(&array);
+    assert_eq!(packed.to_vec(), vec![1, 2, 3]);
+
+    let back: Array<i64> = Array::from(&packed);
+    assert_eq!(back, array);
+
+    let colors = PackedColorArray::from(&[Color::from_rgba(1.0, 0.0, 0.0, 1.0)]);
+    let color_array: Array<Color> = Array::from(&colors);
+    assert_eq!(color_array.get(0), Color::from_rgba(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(PackedColorArray::from(&color_array), colors);
+
+    let untyped = varray![1, 2];
+    assert_eq!(PackedFloat32Array::from(&untyped).to_vec(), vec![1.0, 2.0]);
+}
+
+#[itest]
+fn packed_array_extend_iter() {
+    let mut array = PackedStringArray::new();
+    array.extend(["x", "y"].into_iter().map(GString::from));
+    array.extend(std::iter::empty());
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.get(1), GString::from("y"));
+}
 
 #[itest]
 fn packed_array_eq() {
@@ -223,3 +325,158 @@ fn packed_array_reverse() {
     array.reverse();
     assert_eq!(array.to_vec(), vec![2, 1]);
 }
+
+#[itest]
+fn packed_byte_array_encode_decode() {
+    let mut array = PackedByteArray::new();
+    array.resize(16);
+
+    assert_eq!(array.encode_u16(0, 0xABCD), Ok(()));
+    assert_eq!(array.encode_s32(2, -5), Ok(()));
+    assert_eq!(array.encode_double(8, 1.5), Ok(()));
+    assert_eq!(array.encode_u64(9, 1), Err(()));
+
+    assert_eq!(array.as_slice()[..2], [0xCD, 0xAB]);
+    assert_eq!(array.decode_u16(0), Ok(0xABCD));
+    assert_eq!(array.decode_s32(2), Ok(-5));
+    assert_eq!(array.decode_double(8), Ok(1.5));
+    assert_eq!(array.decode_u8(16), Err(()));
+    assert_eq!(array.decode_float(usize::MAX), Err(()));
+
+    // Godot's own decoding must agree with the Rust side.
+    assert_eq!(array.as_inner().decode_s32(2), -5);
+}
+
+#[itest]
+fn packed_byte_array_encode_var() {
+    let value = Vector3::new(1.0, 2.0, 3.0).to_variant();
+
+    let mut array = PackedByteArray::new();
+    assert_eq!(array.encode_var(0, &value, false), Err(()));
+
+    array.resize(64);
+    let size = array.encode_var(4, &value, false).expect("encode_var");
+    assert!(array.has_encoded_var(4, false));
+    assert_eq!(array.decode_var(4, false), Ok(value));
+    assert_eq!(array.decode_var_size(4, false), Ok(size));
+
+    // Nil is a valid encoded value, although Godot also returns nil on failure.
+    let size = array.encode_var(0, &Variant::nil(), false).unwrap();
+    assert_eq!(array.decode_var(0, false), Ok(Variant::nil()));
+    assert_eq!(array.decode_var_size(0, false), Ok(size));
+
+    assert!(!PackedByteArray::from(&[0xFF; 8]).has_encoded_var(0, false));
+    assert_eq!(
+        PackedByteArray::from(&[0xFF; 8]).decode_var(0, false),
+        Err(())
+    );
+    assert_eq!(
+        PackedByteArray::from(&[0xFF; 8]).decode_var_size(0, false),
+        Err(())
+    );
+    assert_eq!(PackedByteArray::new().decode_var(0, false), Err(()));
+    assert_eq!(PackedByteArray::from(&[0; 3]).decode_var(0, false), Err(()));
+}
+
+#[itest]
+fn packed_byte_array_compress() {
+    let original = PackedByteArray::from(vec![7; 1000]);
+
+    for mode in [
+        CompressionMode::COMPRESSION_FASTLZ,
+        CompressionMode::COMPRESSION_DEFLATE,
+        CompressionMode::COMPRESSION_ZSTD,
+        CompressionMode::COMPRESSION_GZIP,
+    ] {
+        let compressed = original.compress(mode).expect("compress");
+        assert!(compressed.len() < original.len());
+
+        let decompressed = compressed.decompress(original.len(), mode);
+        assert_eq!(decompressed, Ok(original.clone()));
+    }
+
+    let compressed = original
+        .compress(CompressionMode::COMPRESSION_GZIP)
+        .unwrap();
+    let decompressed = compressed.decompress_dynamic(None, CompressionMode::COMPRESSION_GZIP);
+    assert_eq!(decompressed, Ok(original.clone()));
+
+    assert_eq!(
+        PackedByteArray::new().compress(CompressionMode::COMPRESSION_DEFLATE),
+        Ok(PackedByteArray::new())
+    );
+}
+
+#[itest]
+fn packed_byte_array_compress_empty() {
+    let empty = PackedByteArray::new();
+
+    for mode in [
+        CompressionMode::COMPRESSION_FASTLZ,
+        CompressionMode::COMPRESSION_DEFLATE,
+        CompressionMode::COMPRESSION_ZSTD,
+        CompressionMode::COMPRESSION_GZIP,
+    ] {
+        let compressed = empty.compress(mode).expect("compress");
+        assert_eq!(compressed.decompress(0, mode), Ok(empty.clone()));
+        assert_eq!(compressed.decompress(16, mode), Ok(empty.clone()));
+    }
+
+    let compressed = empty.compress(CompressionMode::COMPRESSION_GZIP).unwrap();
+    assert_eq!(
+        compressed.decompress_dynamic(None, CompressionMode::COMPRESSION_GZIP),
+        Ok(empty.clone())
+    );
+
+    // Zero output size yields empty data, even for non-empty input.
+    let compressed = PackedByteArray::from(vec![7; 100])
+        .compress(CompressionMode::COMPRESSION_DEFLATE)
+        .unwrap();
+    assert_eq!(
+        compressed.decompress(0, CompressionMode::COMPRESSION_DEFLATE),
+        Ok(empty.clone())
+    );
+    assert_eq!(
+        compressed.decompress_dynamic(Some(0), CompressionMode::COMPRESSION_DEFLATE),
+        Ok(empty)
+    );
+}
+
+#[itest]
+fn packed_byte_array_strings() {
+    let array = PackedByteArray::from("héllo".as_bytes());
+    assert_eq!(array.get_string_from_utf8(), GString::from("héllo"));
+    assert_eq!(
+        PackedByteArray::from(b"abc").get_string_from_ascii(),
+        GString::from("abc")
+    );
+    assert_eq!(
+        PackedByteArray::from(&[0x0B, 0x2E, 0xFF]).hex_encode(),
+        GString::from("0b2eff")
+    );
+}
+
+#[itest]
+fn packed_byte_array_cursor() {
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    let mut cursor = PackedByteArray::from(&[1, 2, 3]).into_cursor();
+    cursor.seek(SeekFrom::Start(1)).unwrap();
+    cursor.write_all(&[9, 8, 7]).unwrap();
+    assert_eq!(cursor.position(), 4);
+    assert_eq!(cursor.get_ref().to_vec(), vec![1, 9, 8, 7]);
+
+    cursor.seek(SeekFrom::End(-3)).unwrap();
+    let mut buf = [0; 8];
+    assert_eq!(cursor.read(&mut buf).unwrap(), 3);
+    assert_eq!(buf[..3], [9, 8, 7]);
+    assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+    assert_eq!(
+        cursor.seek(SeekFrom::Current(-10)).unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    cursor.set_position(6);
+    cursor.write_all(&[5]).unwrap();
+    assert_eq!(cursor.into_inner().to_vec(), vec![1, 9, 8, 7, 0, 0, 5]);
+}
@@ -10,10 +10,12 @@ use std::fmt::Display;
 use godot::builtin::meta::{FromGodot, ToGodot};
 use godot::builtin::{dict, varray, GString, NodePath, StringName, Variant, Vector2, Vector3};
 use godot::builtin::{
-    Basis, Dictionary, VariantArray, VariantConversionError, VariantOperator, VariantType,
+    Array, Basis, Callable, ConversionErrorKind, Dictionary, PackedByteArray, PackedStringArray,
+    Rid, VariantAccessError, VariantArray, VariantConversionError, VariantDispatch,
+    VariantOperator, VariantType,
 };
-use godot::engine::Node2D;
-use godot::obj::InstanceId;
+use godot::engine::{Node2D, Object};
+use godot::obj::{Gd, InstanceId};
 use godot::sys::GodotFfi;
 
 use crate::common::roundtrip;
@@ -158,6 +160,124 @@ fn variant_call() {
     node2d.free();
 }
 
+#[itest]
+fn variant_get_set_named() {
+    let mut variant = Vector2::new(1.0, 2.0).to_variant();
+
+    assert_eq!(variant.get_named("x"), Ok(1.0.to_variant()));
+    assert_eq!(variant.set_named("y", &5.0.to_variant()), Ok(()));
+    assert_eq!(variant.to::<Vector2>(), Vector2::new(1.0, 5.0));
+
+    assert_eq!(
+        variant.get_named("z"),
+        Err(VariantAccessError::InvalidAccess {
+            variant_type: VariantType::Vector2
+        })
+    );
+
+    // Object properties.
+    let node2d = Node2D::new_alloc();
+    let mut variant = node2d.to_variant();
+    variant
+        .set_named("rotation", &1.5.to_variant())
+        .expect("property exists");
+    assert_eq!(variant.get_named("rotation"), Ok(1.5.to_variant()));
+    assert!(variant.get_named("no_such_property").is_err());
+    node2d.free();
+}
+
+#[itest]
+fn variant_get_set_keyed_indexed() {
+    let mut variant = dict! { "hp": 10 }.to_variant();
+    assert_eq!(variant.get(&"hp".to_variant()), Ok(10.to_variant()));
+    assert_eq!(variant.has_key(&"hp".to_variant()), Ok(true));
+    assert_eq!(variant.has_key(&"mp".to_variant()), Ok(false));
+
+    variant.set(&"mp".to_variant(), &3.to_variant()).unwrap();
+    assert_eq!(variant.to::<Dictionary>(), dict! { "hp": 10, "mp": 3 });
+
+    let mut variant = varray![1, "two", 3.0].to_variant();
+    assert_eq!(variant.get_indexed(1), Ok("two".to_variant()));
+    assert_eq!(variant.set_indexed(2, &false.to_variant()), Ok(()));
+    assert_eq!(variant.to::<VariantArray>(), varray![1, "two", false]);
+    assert_eq!(
+        variant.get_indexed(3),
+        Err(VariantAccessError::IndexOutOfBounds { index: 3 })
+    );
+
+    assert!(Variant::nil().get_indexed(0).is_err());
+}
+
+#[itest]
+fn variant_iter() {
+    let array = varray![1, "two", 3.0].to_variant();
+    let elements: Vec<Variant> = array.iter().unwrap().collect();
+    assert_eq!(
+        elements,
+        vec![1.to_variant(), "two".to_variant(), 3.0.to_variant()]
+    );
+
+    let dict = dict! { "a": 1, "b": 2 }.to_variant();
+    let keys: Vec<Variant> = dict.iter().unwrap().collect();
+    assert_eq!(keys, vec!["a".to_variant(), "b".to_variant()]);
+
+    // Integers iterate as ranges.
+    let range: Vec<i64> = 4.to_variant().iter().unwrap().map(|v| v.to()).collect();
+    assert_eq!(range, vec![0, 1, 2, 3]);
+
+    let empty = varray![].to_variant();
+    assert_eq!(empty.iter().unwrap().count(), 0);
+
+    assert_eq!(
+        true.to_variant().iter().err(),
+        Some(VariantAccessError::NotIterable {
+            variant_type: VariantType::Bool
+        })
+    );
+}
+
+#[itest]
+fn variant_has_method_and_type_name() {
+    let variant = Vector2::new(3.0, 4.0).to_variant();
+
+    assert!(variant.has_method("length"));
+    assert!(!variant.has_method("no_such_method"));
+    assert_eq!(variant.get_type_name(), GString::from("Vector2"));
+    assert_eq!(Variant::type_name(VariantType::Int), GString::from("int"));
+    assert_eq!(Variant::nil().get_type_name(), GString::from("Nil"));
+}
+
+#[itest]
+fn variant_construct() {
+    let vector = Variant::construct(VariantType::Vector2, &[1.to_variant(), 2.to_variant()]);
+    assert_eq!(vector, Ok(Vector2::new(1.0, 2.0).to_variant()));
+
+    let default = Variant::construct(VariantType::Basis, &[]);
+    assert_eq!(default, Ok(Basis::IDENTITY.to_variant()));
+
+    let err = Variant::construct(VariantType::Vector2, &["x".to_variant()]);
+    assert_eq!(
+        err,
+        Err(VariantAccessError::ConstructionFailed {
+            variant_type: VariantType::Vector2,
+            arg_types: vec![VariantType::String],
+        })
+    );
+}
+
+#[itest]
+fn variant_duplicate() {
+    let inner = varray![1, 2];
+    let outer = varray![inner.clone()].to_variant();
+
+    let shallow = outer.duplicate_shallow();
+    let deep = outer.duplicate_deep();
+    inner.clone().push(3.to_variant());
+
+    assert_eq!(shallow, varray![varray![1, 2, 3]].to_variant());
+    assert_eq!(deep, varray![varray![1, 2]].to_variant());
+}
+
 #[rustfmt::skip]
 #[itest]
 fn variant_evaluate() {
@@ -298,33 +418,102 @@ fn variant_null_object_is_nil() {
 #[itest]
 fn variant_conversion_fails() {
     assert_eq!(
-        "hello".to_variant().try_to::<i64>(),
-        Err(VariantConversionError::BadType)
+        "hello".to_variant().try_to::<i64>().map_err(|e| e.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     assert_eq!(
-        28.to_variant().try_to::<f32>(),
-        Err(VariantConversionError::BadType)
+        28.to_variant().try_to::<f32>().map_err(|e| e.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     assert_eq!(
-        10.to_variant().try_to::<bool>(),
-        Err(VariantConversionError::BadType)
+        10.to_variant().try_to::<bool>().map_err(|e| e.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     assert_eq!(
-        false.to_variant().try_to::<String>(),
-        Err(VariantConversionError::BadType)
+        false.to_variant().try_to::<String>().map_err(|e| e.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     assert_eq!(
-        VariantArray::default().to_variant().try_to::<StringName>(),
-        Err(VariantConversionError::BadType)
+        VariantArray::default()
+            .to_variant()
+            .try_to::<StringName>()
+            .map_err(|e| e.kind()),
+        Err(ConversionErrorKind::BadType)
     );
     //assert_eq!(
     //    Dictionary::default().to_variant().try_to::<Array>(),
     //    Err(VariantConversionError)
     //);
     assert_eq!(
-        Variant::nil().to_variant().try_to::<Dictionary>(),
-        Err(VariantConversionError::BadType)
+        Variant::nil()
+            .to_variant()
+            .try_to::<Dictionary>()
+            .map_err(|e| e.kind()),
+        Err(ConversionErrorKind::BadType)
+    );
+}
+
+#[itest]
+fn variant_conversion_error_details() {
+    let err = "hello".to_variant().try_to::<i32>().unwrap_err();
+    assert_eq!(err.kind(), ConversionErrorKind::BadType);
+    assert_eq!(err.expected_type(), "i32");
+    assert_eq!(err.actual_type(), VariantType::String);
+    assert_eq!(err.value(), "hello");
+    assert_eq!(err.path(), "");
+    assert_eq!(
+        err.to_string(),
+        "Variant type does not match expected type: expected i32, found String `hello`"
     );
+
+    let err = 300.to_variant().try_to::<u8>().unwrap_err();
+    assert_eq!(err.kind(), ConversionErrorKind::BadValue);
+    assert_eq!(err.expected_type(), "u8");
+    assert_eq!(err.actual_type(), VariantType::Int);
+    assert_eq!(err.value(), "300");
+
+    let boxed: Box<dyn std::error::Error> = Box::new(err);
+    assert!(boxed.to_string().contains("expected u8"));
+}
+
+#[itest]
+fn variant_conversion_error_value_preview() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<VariantConversionError>();
+
+    let long = "a".repeat(100);
+    let err = long.to_variant().try_to::<i32>().unwrap_err();
+    assert_eq!(err.value(), format!("{}...", "a".repeat(64)));
+
+    // Equal errors compare equal without referring to the original variant.
+    let same = long.to_variant().try_to::<i32>().unwrap_err();
+    assert_eq!(err, same);
+}
+
+#[itest]
+fn variant_conversion_error_array_path() {
+    let array = varray![1, 2, "three", 4];
+
+    let err = array.to_variant().try_to::<Array<i64>>().unwrap_err();
+    assert_eq!(err.kind(), ConversionErrorKind::BadType);
+    assert_eq!(err.expected_type(), "i64");
+    assert_eq!(err.actual_type(), VariantType::String);
+    assert_eq!(err.value(), "three");
+    assert_eq!(err.path(), "[2]");
+    assert!(err.to_string().ends_with("(at `[2]`)"), "{err}");
+}
+
+#[itest]
+fn variant_conversion_error_path_combination() {
+    let err = 7.to_variant().try_to::<GString>().unwrap_err();
+    let err = err
+        .with_field("count")
+        .with_index(3)
+        .with_field("inventory")
+        .with_field("player");
+
+    assert_eq!(err.path(), "player.inventory[3].count");
+    assert_eq!(err.expected_type(), "GString");
 }
 
 #[itest]
@@ -408,6 +597,96 @@ fn variant_hash_correct() {
     assert_ne!(dict! { 0: dict!{ 0: 0 } }, dict! { 0: dict!{ 0: 1 } });
 }
 
+#[itest]
+fn variant_dispatch_roundtrip() {
+    let node = Node2D::new_alloc();
+
+    let values = [
+        Variant::nil(),
+        true.to_variant(),
+        (-42).to_variant(),
+        2.5.to_variant(),
+        gstr("hello").to_variant(),
+        Vector2::new(1.0, -2.0).to_variant(),
+        Vector3::new(1.0, 2.0, 3.0).to_variant(),
+        TEST_BASIS.to_variant(),
+        gname("name").to_variant(),
+        NodePath::from("A/B:c").to_variant(),
+        Rid::new(7).to_variant(),
+        Callable::invalid().to_variant(),
+        varray![1, "two", 3.0].to_variant(),
+        Array::<i64>::from(&[1, 2, 3]).to_variant(),
+        dict! { "key": 10 }.to_variant(),
+        PackedByteArray::from(&[1, 2, 3][..]).to_variant(),
+        PackedStringArray::from(&[gstr("a"), gstr("b")][..]).to_variant(),
+        node.clone().upcast::<Object>().to_variant(),
+        Option::<Gd<Object>>::None.to_variant(),
+    ];
+
+    for value in values {
+        let dispatch = value.dispatch();
+        let back: Variant = dispatch.clone().into();
+
+        assert_eq!(back.get_type(), value.get_type(), "{dispatch:?}");
+        assert_eq!(back, value, "{dispatch:?}");
+        assert_eq!(back.dispatch(), dispatch);
+    }
+
+    node.free();
+}
+
+#[itest]
+fn variant_dispatch_match() {
+    fn describe(variant: Variant) -> String {
+        match variant.dispatch() {
+            VariantDispatch::Nil => "nil".to_string(),
+            VariantDispatch::Int(i) => format!("int {i}"),
+            VariantDispatch::String(s) => format!("string {s}"),
+            VariantDispatch::Array(array) => format!("array of {}", array.len()),
+            VariantDispatch::Object(Some(object)) => format!("object {}", object.get_class()),
+            VariantDispatch::Object(None) => "null object".to_string(),
+            _ => "other".to_string(),
+        }
+    }
+
+    let node = Node2D::new_alloc();
+
+    assert_eq!(describe(Variant::nil()), "nil");
+    assert_eq!(describe(17.to_variant()), "int 17");
+    assert_eq!(describe(gstr("text").to_variant()), "string text");
+    assert_eq!(describe(varray![1, 2].to_variant()), "array of 2");
+    assert_eq!(describe(node.to_variant()), "object Node2D");
+    assert_eq!(
+        describe(Option::<Gd<Node2D>>::None.to_variant()),
+        "null object"
+    );
+    assert_eq!(describe(Vector2::ZERO.to_variant()), "other");
+
+    node.free();
+}
+
+#[itest]
+fn variant_dispatch_null_object() {
+    use godot::sys;
+
+    let mut node = Node2D::new_alloc();
+    let variant = node.call(
+        "get_node_or_null".into(),
+        &[NodePath::from("missing").to_variant()],
+    );
+
+    assert_eq!(variant.dispatch(), VariantDispatch::Object(None));
+
+    // Converting back keeps the raw type `OBJECT`.
+    let back = Variant::from(variant.dispatch());
+    let raw_type: sys::GDExtensionVariantType =
+        unsafe { sys::interface_fn!(variant_get_type)(back.var_sys()) };
+    assert_eq!(raw_type, sys::GDEXTENSION_VARIANT_TYPE_OBJECT);
+    assert_eq!(back, variant);
+
+    node.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -0,0 +1,201 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{itest, Differential, EdgeCases};
+
+use godot::builtin::inner::InnerAabb;
+use godot::builtin::{real, Aabb, Plane, RealConv, Vector3};
+
+// Not compared:
+// * `has_area()`: Godot's closest equivalent `has_surface()` also accepts boxes with only one non-zero extent.
+// * `intersects()`: includes borders, while Godot's `intersects()` corresponds to `intersects_exclude_borders()`.
+
+#[itest]
+fn aabb_differential() {
+    Differential::new("Aabb::abs")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| Aabb::new(gen.any(), gen.any()),
+            |b| b.abs(),
+            |b| InnerAabb::from_outer(b).abs(),
+        );
+
+    Differential::new("Aabb::center")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.center(),
+            |b| InnerAabb::from_outer(b).get_center(),
+        );
+
+    Differential::new("Aabb::volume")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.volume(),
+            |b| real::from_f64(InnerAabb::from_outer(b).get_volume()),
+        );
+
+    Differential::new("Aabb::has_volume")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| Aabb::new(gen.any(), gen.any()),
+            |b| b.has_volume(),
+            |b| InnerAabb::from_outer(b).has_volume(),
+        );
+
+    Differential::new("Aabb::has_point").check(
+        |gen| (gen.any::<Aabb>(), gen.any::<Vector3>()),
+        |(b, point)| b.has_point(*point),
+        |(b, point)| InnerAabb::from_outer(b).has_point(*point),
+    );
+
+    Differential::new("Aabb::encloses")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.any::<Aabb>()),
+            |(a, b)| a.encloses(*b),
+            |(a, b)| InnerAabb::from_outer(a).encloses(*b),
+        );
+
+    Differential::new("Aabb::intersects_exclude_borders")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.any::<Aabb>()),
+            |(a, b)| a.intersects_exclude_borders(b),
+            |(a, b)| InnerAabb::from_outer(a).intersects(*b),
+        );
+
+    Differential::new("Aabb::intersection")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.any::<Aabb>()),
+            |(a, b)| a.intersection(b).unwrap_or_default(),
+            |(a, b)| InnerAabb::from_outer(a).intersection(*b),
+        );
+
+    Differential::new("Aabb::merge")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.any::<Aabb>()),
+            |(a, b)| a.merge(b),
+            |(a, b)| InnerAabb::from_outer(a).merge(*b),
+        );
+
+    Differential::new("Aabb::expand")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.any::<Vector3>()),
+            |(b, to)| b.expand(*to),
+            |(b, to)| InnerAabb::from_outer(b).expand(*to),
+        );
+
+    Differential::new("Aabb::grow")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.real()),
+            |&(b, amount)| b.grow(amount),
+            |(b, amount)| InnerAabb::from_outer(b).grow(amount.as_f64()),
+        );
+
+    Differential::new("Aabb::longest_axis")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.longest_axis(),
+            |b| InnerAabb::from_outer(b).get_longest_axis(),
+        );
+
+    Differential::new("Aabb::longest_axis_index")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.longest_axis_index() as i64,
+            |b| InnerAabb::from_outer(b).get_longest_axis_index(),
+        );
+
+    Differential::new("Aabb::longest_axis_size")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.longest_axis_size(),
+            |b| real::from_f64(InnerAabb::from_outer(b).get_longest_axis_size()),
+        );
+
+    Differential::new("Aabb::shortest_axis")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.shortest_axis(),
+            |b| InnerAabb::from_outer(b).get_shortest_axis(),
+        );
+
+    Differential::new("Aabb::shortest_axis_index")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.shortest_axis_index() as i64,
+            |b| InnerAabb::from_outer(b).get_shortest_axis_index(),
+        );
+
+    Differential::new("Aabb::shortest_axis_size")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.shortest_axis_size(),
+            |b| real::from_f64(InnerAabb::from_outer(b).get_shortest_axis_size()),
+        );
+
+    Differential::new("Aabb::support")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Aabb>(), gen.any::<Vector3>()),
+            |(b, dir)| b.support(*dir),
+            |(b, dir)| InnerAabb::from_outer(b).get_support(*dir),
+        );
+
+    Differential::new("Aabb::intersects_plane").check(
+        |gen| (gen.any::<Aabb>(), gen.any::<Plane>()),
+        |(b, plane)| b.intersects_plane(plane),
+        |(b, plane)| InnerAabb::from_outer(b).intersects_plane(*plane),
+    );
+
+    // Ray and segment directions with zero components are handled differently (division by zero vs. explicit
+    // parallel check), so only regular inputs are compared.
+    Differential::new("Aabb::intersects_ray").check(
+        |gen| (gen.any::<Aabb>(), gen.any::<Vector3>(), gen.unit_vector3()),
+        |(b, from, dir)| b.intersects_ray(*from, *dir),
+        |(b, from, dir)| {
+            !InnerAabb::from_outer(b)
+                .intersects_ray(*from, *dir)
+                .is_nil()
+        },
+    );
+
+    Differential::new("Aabb::intersects_segment").check(
+        |gen| {
+            (
+                gen.any::<Aabb>(),
+                gen.any::<Vector3>(),
+                gen.any::<Vector3>(),
+            )
+        },
+        |(b, from, to)| b.intersects_segment(*from, *to),
+        |(b, from, to)| {
+            !InnerAabb::from_outer(b)
+                .intersects_segment(*from, *to)
+                .is_nil()
+        },
+    );
+
+    Differential::new("Aabb::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |b| b.is_finite(),
+            |b| InnerAabb::from_outer(b).is_finite(),
+        );
+}
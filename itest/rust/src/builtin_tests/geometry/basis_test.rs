@@ -9,7 +9,7 @@ use godot::builtin::math::assert_eq_approx;
 use godot::builtin::meta::ToGodot;
 use godot::builtin::{real, Basis, EulerOrder, RealConv, VariantOperator, Vector3};
 
-use crate::framework::itest;
+use crate::framework::{itest, suppress_godot_print, Differential, EdgeCases, TestRng};
 
 const TEST_BASIS: Basis = Basis::from_rows(
     Vector3::new(0.942155, -0.270682, 0.197677),
@@ -156,6 +156,209 @@ fn basis_equiv() {
     )
 }
 
+#[itest]
+fn basis_to_quat_sign_equiv() {
+    // Rotations close to 180 degrees, where the sign of the quaternion depends on the algorithm; plus a reflection.
+    let axis = Vector3::new(1.0, 2.0, -3.0).normalized();
+    let bases = [
+        Basis::from_axis_angle(axis, 3.0),
+        Basis::from_axis_angle(axis, -3.0),
+        Basis::from_axis_angle(Vector3::UP, 3.1),
+        Basis::from_axis_angle(axis, 2.5).scaled(Vector3::new(-1.0, 2.0, 1.0)),
+    ];
+
+    for basis in bases {
+        assert_eq_approx!(
+            basis.to_quat(),
+            InnerBasis::from_outer(&basis).get_rotation_quaternion(),
+            "basis={basis}"
+        );
+    }
+}
+
+#[itest]
+fn basis_looking_at_equiv() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let target = rng.vector3(-10.0, 10.0);
+        let up = rng.unit_vector3();
+        if target.is_zero_approx() || target.cross(up).is_zero_approx() {
+            continue;
+        }
+
+        #[cfg(before_api = "4.1")]
+        let (rust_res, godot_res) = (
+            Basis::new_looking_at(target, up),
+            InnerBasis::looking_at(target, up),
+        );
+        #[cfg(since_api = "4.1")]
+        let (rust_res, godot_res) = {
+            let use_model_front = rng.bool();
+            (
+                Basis::new_looking_at(target, up, use_model_front),
+                InnerBasis::looking_at(target, up, use_model_front),
+            )
+        };
+
+        assert_eq_approx!(rust_res, godot_res, "target={target} up={up}");
+    }
+}
+
+#[itest]
+fn basis_looking_at_invalid() {
+    let cases = [
+        (Vector3::ZERO, Vector3::UP),
+        (Vector3::FORWARD, Vector3::ZERO),
+        (Vector3::UP * 3.0, Vector3::UP),
+    ];
+
+    for (target, up) in cases {
+        // Godot prints an error and returns the identity basis, instead of panicking.
+        suppress_godot_print(|| {
+            #[cfg(before_api = "4.1")]
+            let (rust_res, godot_res) = (
+                Basis::new_looking_at(target, up),
+                InnerBasis::looking_at(target, up),
+            );
+            #[cfg(since_api = "4.1")]
+            let (rust_res, godot_res) = (
+                Basis::new_looking_at(target, up, false),
+                InnerBasis::looking_at(target, up, false),
+            );
+
+            assert_eq!(rust_res, Basis::IDENTITY, "target={target} up={up}");
+            assert_eq!(rust_res, godot_res, "target={target} up={up}");
+        });
+    }
+}
+
+#[itest]
+fn basis_differential() {
+    Differential::new("Basis::transposed")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Basis>(),
+            |b| b.transposed(),
+            |b| InnerBasis::from_outer(b).transposed(),
+        );
+
+    Differential::new("Basis::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Basis>(),
+            |b| b.is_finite(),
+            |b| InnerBasis::from_outer(b).is_finite(),
+        );
+
+    Differential::new("Basis::determinant").check(
+        |gen| gen.any::<Basis>(),
+        |b| b.determinant(),
+        |b| real::from_f64(InnerBasis::from_outer(b).determinant()),
+    );
+
+    Differential::new("Basis::inverse").check(
+        |gen| gen.scaled_rotation(),
+        |b| b.inverse(),
+        |b| InnerBasis::from_outer(b).inverse(),
+    );
+
+    Differential::new("Basis::orthonormalized").check(
+        |gen| gen.scaled_rotation(),
+        |b| b.orthonormalized(),
+        |b| InnerBasis::from_outer(b).orthonormalized(),
+    );
+
+    Differential::new("Basis::scale").check(
+        |gen| gen.scaled_rotation(),
+        |b| b.scale(),
+        |b| InnerBasis::from_outer(b).get_scale(),
+    );
+
+    Differential::new("Basis::to_quat").check(
+        |gen| gen.scaled_rotation(),
+        |b| b.to_quat(),
+        |b| InnerBasis::from_outer(b).get_rotation_quaternion(),
+    );
+
+    Differential::new("Basis::scaled")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Basis>(), gen.any::<Vector3>()),
+            |&(b, scale)| b.scaled(scale),
+            |(b, scale)| InnerBasis::from_outer(b).scaled(*scale),
+        );
+
+    Differential::new("Basis::rotated").check(
+        |gen| (gen.scaled_rotation(), gen.unit_vector3(), gen.real()),
+        |&(b, axis, angle)| b.rotated(axis, angle),
+        |(b, axis, angle)| InnerBasis::from_outer(b).rotated(*axis, angle.as_f64()),
+    );
+
+    Differential::new("Basis::slerp").check(
+        |gen| {
+            let from = Basis::from_quat(gen.rotation());
+            let to = Basis::from_quat(gen.rotation());
+            (from, to, gen.rng().real(0.0, 1.0))
+        },
+        |&(from, to, weight)| from.slerp(to, weight),
+        |(from, to, weight)| InnerBasis::from_outer(from).slerp(*to, weight.as_f64()),
+    );
+
+    Differential::new("Basis::tdotx")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Basis>(), gen.any::<Vector3>()),
+            |(b, v)| b.tdotx(*v),
+            |(b, v)| real::from_f64(InnerBasis::from_outer(b).tdotx(*v)),
+        );
+
+    Differential::new("Basis::tdoty")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Basis>(), gen.any::<Vector3>()),
+            |(b, v)| b.tdoty(*v),
+            |(b, v)| real::from_f64(InnerBasis::from_outer(b).tdoty(*v)),
+        );
+
+    Differential::new("Basis::tdotz")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Basis>(), gen.any::<Vector3>()),
+            |(b, v)| b.tdotz(*v),
+            |(b, v)| real::from_f64(InnerBasis::from_outer(b).tdotz(*v)),
+        );
+
+    for order in [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ] {
+        Differential::new(format!("Basis::to_euler({order:?})")).check(
+            |gen| Basis::from_quat(gen.rotation()),
+            |b| b.to_euler(order),
+            |b| InnerBasis::from_outer(b).get_euler(order as i64),
+        );
+
+        Differential::new(format!("Basis::from_euler({order:?})")).check(
+            |gen| gen.any::<Vector3>(),
+            |&angles| Basis::from_euler(order, angles),
+            |&angles| InnerBasis::from_euler(angles, order as i64),
+        );
+    }
+
+    Differential::new("Basis::from_scale")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Vector3>(),
+            |&scale| Basis::from_scale(scale),
+            |&scale| InnerBasis::from_scale(scale),
+        );
+}
+
 fn deg_to_rad(rotation: Vector3) -> Vector3 {
     Vector3::new(
         rotation.x.to_radians(),
@@ -0,0 +1,213 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{itest, Differential, Gen};
+
+use godot::builtin::math::geometry;
+use godot::builtin::real_consts::TAU;
+use godot::builtin::{real, PackedVector2Array, Vector2, Vector3};
+use godot::engine::{Geometry2D, Geometry3D};
+
+/// Simple (non-self-intersecting) polygon: points at increasing angles around the origin, with random radii.
+fn star_polygon(gen: &mut Gen) -> Vec<Vector2> {
+    let count = 3 + gen.rng().next_u64() as usize % 10;
+
+    let mut angles: Vec<real> = (0..count).map(|_| gen.rng().real(0.0, TAU)).collect();
+    angles.sort_by(|a, b| a.total_cmp(b));
+    angles.dedup_by(|a, b| (*a - *b).abs() < 0.01);
+
+    angles
+        .into_iter()
+        .map(|angle| Vector2::from_angle(angle) * gen.rng().real(10.0, 100.0))
+        .collect()
+}
+
+/// Triangle plus a point near it (barycentric weights slightly outside `0..=1`, so that both hits and misses occur).
+fn triangle_and_target(gen: &mut Gen) -> ([Vector3; 3], Vector3) {
+    let triangle = [gen.any(), gen.any(), gen.any()];
+    let u = gen.rng().real(-0.2, 1.2);
+    let v = gen.rng().real(-0.2, 1.2 - u);
+    let target = triangle[0] + (triangle[1] - triangle[0]) * u + (triangle[2] - triangle[0]) * v;
+
+    (triangle, target)
+}
+
+#[itest]
+fn geometry_segments_differential() {
+    Differential::new("closest_point_to_segment_2d").check(
+        |gen| {
+            (
+                gen.any::<Vector2>(),
+                gen.any::<Vector2>(),
+                gen.any::<Vector2>(),
+            )
+        },
+        |&(point, from, to)| geometry::closest_point_to_segment_2d(point, from, to),
+        |&(point, from, to)| Geometry2D::singleton().get_closest_point_to_segment(point, from, to),
+    );
+
+    Differential::new("closest_point_to_segment_3d").check(
+        |gen| {
+            (
+                gen.any::<Vector3>(),
+                gen.any::<Vector3>(),
+                gen.any::<Vector3>(),
+            )
+        },
+        |&(point, from, to)| geometry::closest_point_to_segment_3d(point, from, to),
+        |&(point, from, to)| Geometry3D::singleton().get_closest_point_to_segment(point, from, to),
+    );
+
+    Differential::new("closest_points_between_segments_2d").check(
+        |gen| [gen.any::<Vector2>(), gen.any(), gen.any(), gen.any()],
+        |&[from_a, to_a, from_b, to_b]| {
+            let (a, b) = geometry::closest_points_between_segments_2d(from_a, to_a, from_b, to_b);
+            vec![a, b]
+        },
+        |&[from_a, to_a, from_b, to_b]| {
+            Geometry2D::singleton()
+                .get_closest_points_between_segments(from_a, to_a, from_b, to_b)
+                .to_vec()
+        },
+    );
+
+    Differential::new("closest_points_between_segments_3d").check(
+        |gen| [gen.any::<Vector3>(), gen.any(), gen.any(), gen.any()],
+        |&[from_a, to_a, from_b, to_b]| {
+            let (a, b) = geometry::closest_points_between_segments_3d(from_a, to_a, from_b, to_b);
+            vec![a, b]
+        },
+        |&[from_a, to_a, from_b, to_b]| {
+            Geometry3D::singleton()
+                .get_closest_points_between_segments(from_a, to_a, from_b, to_b)
+                .to_vec()
+        },
+    );
+
+    // Random segments are almost never parallel, so test those separately.
+    Differential::new("closest_points_between_segments_3d (parallel)").check(
+        |gen| {
+            let from_a = gen.any::<Vector3>();
+            let dir = gen.any::<Vector3>();
+            let from_b = gen.any::<Vector3>();
+            let length_b = gen.rng().real(-2.0, 2.0);
+            [from_a, from_a + dir, from_b, from_b + dir * length_b]
+        },
+        |&[from_a, to_a, from_b, to_b]| {
+            let (a, b) = geometry::closest_points_between_segments_3d(from_a, to_a, from_b, to_b);
+            vec![a, b]
+        },
+        |&[from_a, to_a, from_b, to_b]| {
+            Geometry3D::singleton()
+                .get_closest_points_between_segments(from_a, to_a, from_b, to_b)
+                .to_vec()
+        },
+    );
+
+    Differential::new("segment_intersects_segment_2d").check(
+        |gen| [gen.any::<Vector2>(), gen.any(), gen.any(), gen.any()],
+        |&[from_a, to_a, from_b, to_b]| {
+            geometry::segment_intersects_segment_2d(from_a, to_a, from_b, to_b)
+        },
+        |&[from_a, to_a, from_b, to_b]| {
+            Geometry2D::singleton()
+                .segment_intersects_segment(from_a, to_a, from_b, to_b)
+                .try_to::<Vector2>()
+                .ok()
+        },
+    );
+}
+
+#[itest]
+fn geometry_triangles_differential() {
+    Differential::new("ray_intersects_triangle").check(
+        |gen| {
+            let (triangle, target) = triangle_and_target(gen);
+            let from = gen.any::<Vector3>();
+            (triangle, from, (target - from).normalized())
+        },
+        |&([a, b, c], from, dir)| geometry::ray_intersects_triangle(from, dir, a, b, c),
+        |&([a, b, c], from, dir)| {
+            Geometry3D::singleton()
+                .ray_intersects_triangle(from, dir, a, b, c)
+                .try_to::<Vector3>()
+                .ok()
+        },
+    );
+
+    Differential::new("segment_intersects_triangle").check(
+        |gen| {
+            let (triangle, target) = triangle_and_target(gen);
+            let from = gen.any::<Vector3>();
+            let to = from + (target - from) * gen.rng().real(0.5, 1.5);
+            (triangle, from, to)
+        },
+        |&([a, b, c], from, to)| geometry::segment_intersects_triangle(from, to, a, b, c),
+        |&([a, b, c], from, to)| {
+            Geometry3D::singleton()
+                .segment_intersects_triangle(from, to, a, b, c)
+                .try_to::<Vector3>()
+                .ok()
+        },
+    );
+}
+
+#[itest]
+fn geometry_polygons_differential() {
+    Differential::new("is_point_in_polygon").check(
+        |gen| (gen.rng().vector2(-100.0, 100.0), star_polygon(gen)),
+        |(point, polygon)| geometry::is_point_in_polygon(*point, polygon),
+        |(point, polygon)| {
+            Geometry2D::singleton()
+                .is_point_in_polygon(*point, PackedVector2Array::from(polygon.as_slice()))
+        },
+    );
+
+    Differential::new("is_polygon_clockwise").check(
+        |gen| {
+            let mut polygon = star_polygon(gen);
+            if gen.bool() {
+                polygon.reverse();
+            }
+            polygon
+        },
+        |polygon| geometry::is_polygon_clockwise(polygon),
+        |polygon| {
+            Geometry2D::singleton()
+                .is_polygon_clockwise(PackedVector2Array::from(polygon.as_slice()))
+        },
+    );
+
+    Differential::new("convex_hull").check(
+        |gen| {
+            let count = 3 + gen.rng().next_u64() as usize % 20;
+            (0..count)
+                .map(|_| gen.rng().vector2(-100.0, 100.0))
+                .collect::<Vec<_>>()
+        },
+        |points| geometry::convex_hull(points),
+        |points| {
+            Geometry2D::singleton()
+                .convex_hull(PackedVector2Array::from(points.as_slice()))
+                .to_vec()
+        },
+    );
+
+    // Godot signals failure with an empty array.
+    Differential::new("triangulate_polygon").check(
+        star_polygon,
+        |polygon| {
+            geometry::triangulate_polygon(polygon)
+                .map(|indices| indices.into_iter().map(|i| i as i32).collect::<Vec<_>>())
+        },
+        |polygon| {
+            let indices = Geometry2D::singleton()
+                .triangulate_polygon(PackedVector2Array::from(polygon.as_slice()))
+                .to_vec();
+            (!indices.is_empty()).then_some(indices)
+        },
+    );
+}
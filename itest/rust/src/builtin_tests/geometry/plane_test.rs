@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
+use crate::framework::{itest, Differential, EdgeCases};
 
 use godot::builtin::inner::InnerPlane;
 use godot::builtin::math::{assert_eq_approx, ApproxEq};
@@ -234,3 +234,115 @@ fn plane_intersect_3() {
         inner_a.intersect_3(b, c),
     );
 }
+
+#[itest]
+fn plane_differential() {
+    Differential::new("Plane::normalized")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| Plane {
+                normal: gen.any(),
+                d: gen.real(),
+            },
+            |p| p.normalized(),
+            |p| InnerPlane::from_outer(p).normalized(),
+        );
+
+    Differential::new("Plane::center")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Plane>(),
+            |p| p.center(),
+            |p| InnerPlane::from_outer(p).get_center(),
+        );
+
+    Differential::new("Plane::distance_to")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Plane>(), gen.any::<Vector3>()),
+            |(p, point)| p.distance_to(*point),
+            |(p, point)| real::from_f64(InnerPlane::from_outer(p).distance_to(*point)),
+        );
+
+    Differential::new("Plane::is_point_over")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Plane>(), gen.any::<Vector3>()),
+            |(p, point)| p.is_point_over(*point),
+            |(p, point)| InnerPlane::from_outer(p).is_point_over(*point),
+        );
+
+    Differential::new("Plane::contains_point").check(
+        |gen| {
+            let plane = gen.any::<Plane>();
+            let point = gen.any::<Vector3>();
+            // Move about half of the points close to the plane, so that both outcomes are covered.
+            let point = if gen.bool() {
+                plane.project(point) + plane.normal * gen.rng().real(-0.1, 0.1)
+            } else {
+                point
+            };
+            (plane, point, gen.rng().real(0.0, 0.1))
+        },
+        |&(p, point, tolerance)| p.contains_point(point, Some(tolerance)),
+        |(p, point, tolerance)| InnerPlane::from_outer(p).has_point(*point, tolerance.as_f64()),
+    );
+
+    Differential::new("Plane::project")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Plane>(), gen.any::<Vector3>()),
+            |(p, point)| p.project(*point),
+            |(p, point)| InnerPlane::from_outer(p).project(*point),
+        );
+
+    Differential::new("Plane::intersect_3").check(
+        |gen| (gen.any::<Plane>(), gen.any::<Plane>(), gen.any::<Plane>()),
+        |(a, b, c)| a.intersect_3(b, c),
+        |(a, b, c)| {
+            InnerPlane::from_outer(a)
+                .intersect_3(*b, *c)
+                .try_to::<Vector3>()
+                .ok()
+        },
+    );
+
+    Differential::new("Plane::intersect_ray").check(
+        |gen| (gen.any::<Plane>(), gen.any::<Vector3>(), gen.unit_vector3()),
+        |(p, from, dir)| p.intersect_ray(*from, *dir),
+        |(p, from, dir)| {
+            InnerPlane::from_outer(p)
+                .intersects_ray(*from, *dir)
+                .try_to::<Vector3>()
+                .ok()
+        },
+    );
+
+    Differential::new("Plane::intersect_segment").check(
+        |gen| {
+            (
+                gen.any::<Plane>(),
+                gen.any::<Vector3>(),
+                gen.any::<Vector3>(),
+            )
+        },
+        |(p, from, to)| p.intersect_segment(*from, *to),
+        |(p, from, to)| {
+            InnerPlane::from_outer(p)
+                .intersects_segment(*from, *to)
+                .try_to::<Vector3>()
+                .ok()
+        },
+    );
+
+    Differential::new("Plane::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| Plane {
+                normal: gen.any(),
+                d: gen.real(),
+            },
+            |p| p.is_finite(),
+            |p| InnerPlane::from_outer(p).is_finite(),
+        );
+}
@@ -6,11 +6,13 @@
 
 #![allow(clippy::type_complexity, clippy::excessive_precision)]
 
-use crate::framework::itest;
+use crate::framework::{itest, Differential, EdgeCases, TestRng};
 
 use godot::builtin::inner::InnerProjection;
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{real, Projection, RealConv, Vector2};
+use godot::builtin::{
+    real, Aabb, Projection, ProjectionEye, ProjectionPlane, RealConv, Rect2, Vector2,
+};
 
 #[itest]
 fn test_create_orthogonal() {
@@ -99,6 +101,46 @@ fn test_create_perspective() {
     }
 }
 
+#[itest]
+fn test_create_perspective_hmd() {
+    #[rustfmt::skip]
+    const TEST_DATA: [(real, real, real, real, bool, ProjectionEye, real, real); 4] = [
+        (90.0, 1.0,        1.0,  2.0,   false, ProjectionEye::Left,  0.065, 1.5),
+        (90.0, 1.0,        1.0,  2.0,   true,  ProjectionEye::Right, 0.065, 1.5),
+        (45.0, 16.0 / 9.0, 0.05, 100.0, false, ProjectionEye::Right, 0.07,  5.0),
+        (60.0, 9.0 / 16.0, 0.1,  50.0,  true,  ProjectionEye::Left,  0.06,  2.0),
+    ];
+
+    for (fov_y, aspect, near, far, flip_fov, eye, iod, convergence) in TEST_DATA {
+        let rust_proj = Projection::create_perspective_hmd(
+            fov_y,
+            aspect,
+            near,
+            far,
+            flip_fov,
+            eye,
+            iod,
+            convergence,
+        );
+        let godot_proj = InnerProjection::create_perspective_hmd(
+            fov_y.as_f64(),
+            aspect.as_f64(),
+            near.as_f64(),
+            far.as_f64(),
+            flip_fov,
+            eye as i64,
+            iod.as_f64(),
+            convergence.as_f64(),
+        );
+
+        assert_eq_approx!(
+            rust_proj,
+            godot_proj,
+            "perspective_hmd: fov_y={fov_y} aspect={aspect} near={near} far={far} flip_fov={flip_fov} eye={eye:?}"
+        );
+    }
+}
+
 #[itest]
 fn test_create_frustum() {
     const TEST_DATA: [[real; 6]; 3] = [
@@ -344,3 +386,273 @@ fn test_projection_combined() {
         }
     }
 }
+
+#[itest]
+fn test_create_light_atlas_rect() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let rect = Rect2::new(rng.vector2(-10.0, 10.0), rng.vector2(0.1, 10.0));
+        assert_eq_approx!(
+            Projection::create_light_atlas_rect(rect),
+            InnerProjection::create_light_atlas_rect(rect),
+            "light atlas rect: rect={rect:?}"
+        );
+    }
+}
+
+#[itest]
+fn test_fovy_of() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let fov_x = rng.real(1.0, 179.0);
+        let aspect = rng.real(0.25, 4.0);
+
+        assert_eq_approx!(
+            Projection::fovy_of(fov_x, aspect),
+            real::from_f64(InnerProjection::get_fovy(fov_x.as_f64(), aspect.as_f64())),
+            "fov_x={fov_x} aspect={aspect}"
+        );
+    }
+}
+
+#[itest]
+fn projection_differential() {
+    Differential::new("Projection::aspect")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.aspect(),
+            |p| real::from_f64(InnerProjection::from_outer(p).get_aspect()),
+        );
+
+    Differential::new("Projection::z_near")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.z_near(),
+            |p| real::from_f64(InnerProjection::from_outer(p).get_z_near()),
+        );
+
+    Differential::new("Projection::z_far")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.z_far(),
+            |p| real::from_f64(InnerProjection::from_outer(p).get_z_far()),
+        );
+
+    Differential::new("Projection::fov")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.fov(),
+            |p| real::from_f64(InnerProjection::from_outer(p).get_fov()),
+        );
+
+    Differential::new("Projection::lod_multiplier")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.lod_multiplier(),
+            |p| real::from_f64(InnerProjection::from_outer(p).get_lod_multiplier()),
+        );
+
+    // Godot converts the result to a C++ `int`, which is undefined for NaN and out-of-range values. Such inputs are skipped.
+    Differential::new("Projection::pixels_per_meter")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| {
+                gen.any_where(
+                    |gen| (gen.any::<Projection>(), gen.int().abs()),
+                    |&(p, width)| {
+                        let pixels =
+                            InnerProjection::from_outer(&p).get_pixels_per_meter(width.into());
+                        pixels != i64::from(i32::MIN)
+                    },
+                )
+            },
+            |&(p, width)| p.pixels_per_meter(width.into()),
+            |&(p, width)| InnerProjection::from_outer(&p).get_pixels_per_meter(width.into()),
+        );
+
+    Differential::new("Projection::projection_plane")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| {
+                const PLANES: [ProjectionPlane; 6] = [
+                    ProjectionPlane::Near,
+                    ProjectionPlane::Far,
+                    ProjectionPlane::Left,
+                    ProjectionPlane::Top,
+                    ProjectionPlane::Right,
+                    ProjectionPlane::Bottom,
+                ];
+                let plane = PLANES[gen.rng().next_u64() as usize % PLANES.len()];
+
+                (gen.any::<Projection>(), plane)
+            },
+            |&(p, plane)| p.projection_plane(plane),
+            |&(p, plane)| InnerProjection::from_outer(&p).get_projection_plane(plane as i64),
+        );
+
+    Differential::new("Projection::viewport_half_extents")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.viewport_half_extents(),
+            |p| InnerProjection::from_outer(p).get_viewport_half_extents(),
+        );
+
+    Differential::new("Projection::far_plane_half_extents")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Projection>(),
+            |p| p.far_plane_half_extents(),
+            |p| InnerProjection::from_outer(p).get_far_plane_half_extents(),
+        );
+
+    Differential::new("Projection::perspective_znear_adjusted")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| (gen.any::<Projection>(), gen.size()),
+            |&(p, new_znear)| p.perspective_znear_adjusted(new_znear),
+            |&(p, new_znear)| {
+                InnerProjection::from_outer(&p).perspective_znear_adjusted(new_znear.as_f64())
+            },
+        );
+
+    Differential::new("Projection::create_fit_aabb")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Aabb>(),
+            |&aabb| Projection::create_fit_aabb(aabb),
+            |&aabb| InnerProjection::create_fit_aabb(aabb),
+        );
+
+    Differential::new("Projection::determinant").check(
+        |gen| gen.any::<Projection>(),
+        |p| p.determinant(),
+        |p| real::from_f64(InnerProjection::from_outer(p).determinant()),
+    );
+
+    Differential::new("Projection::inverse").check(
+        |gen| gen.any::<Projection>(),
+        |p| p.inverse(),
+        |p| InnerProjection::from_outer(p).inverse(),
+    );
+
+    Differential::new("Projection::flipped_y").check(
+        |gen| gen.any::<Projection>(),
+        |p| p.flipped_y(),
+        |p| InnerProjection::from_outer(p).flipped_y(),
+    );
+
+    Differential::new("Projection::is_orthogonal").check(
+        |gen| gen.any::<Projection>(),
+        |p| p.is_orthogonal(),
+        |p| InnerProjection::from_outer(p).is_orthogonal(),
+    );
+
+    Differential::new("Projection::jitter_offset")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| (gen.any::<Projection>(), gen.any::<Vector2>()),
+            |(p, offset)| p.jitter_offset(*offset),
+            |(p, offset)| InnerProjection::from_outer(p).jitter_offseted(*offset),
+        );
+
+    Differential::new("Projection::create_depth_correction").check(
+        |gen| gen.bool(),
+        |&flip_y| Projection::create_depth_correction(flip_y),
+        |&flip_y| InnerProjection::create_depth_correction(flip_y),
+    );
+
+    Differential::new("Projection::create_for_hmd").check(
+        |gen| {
+            let rng = gen.rng();
+            let eye = if rng.bool() {
+                ProjectionEye::Left
+            } else {
+                ProjectionEye::Right
+            };
+            let intraocular_dist = rng.real(0.05, 0.08);
+            let display_width = intraocular_dist + rng.real(0.05, 0.1);
+            let near = rng.real(0.05, 1.0);
+
+            (
+                eye,
+                rng.real(0.5, 2.0),
+                intraocular_dist,
+                display_width,
+                rng.real(0.03, 0.1),
+                rng.real(1.0, 2.0),
+                near,
+                near + rng.real(1.0, 100.0),
+            )
+        },
+        |&(eye, aspect, iod, width, to_lens, oversample, near, far)| {
+            Projection::create_for_hmd(eye, aspect, iod, width, to_lens, oversample, near, far)
+        },
+        |&(eye, aspect, iod, width, to_lens, oversample, near, far)| {
+            InnerProjection::create_for_hmd(
+                eye as i64,
+                aspect.as_f64(),
+                iod.as_f64(),
+                width.as_f64(),
+                to_lens.as_f64(),
+                oversample.as_f64(),
+                near.as_f64(),
+                far.as_f64(),
+            )
+        },
+    );
+
+    Differential::new("Projection::create_perspective_hmd").check(
+        |gen| {
+            let rng = gen.rng();
+            let eye = if rng.bool() {
+                ProjectionEye::Left
+            } else {
+                ProjectionEye::Right
+            };
+            let near = rng.real(0.05, 1.0);
+
+            (
+                rng.real(30.0, 120.0),
+                rng.real(0.5, 2.0),
+                near,
+                near + rng.real(1.0, 100.0),
+                rng.bool(),
+                eye,
+                rng.real(0.05, 0.08),
+                rng.real(1.0, 10.0),
+            )
+        },
+        |&(fov_y, aspect, near, far, flip_fov, eye, iod, convergence)| {
+            Projection::create_perspective_hmd(
+                fov_y,
+                aspect,
+                near,
+                far,
+                flip_fov,
+                eye,
+                iod,
+                convergence,
+            )
+        },
+        |&(fov_y, aspect, near, far, flip_fov, eye, iod, convergence)| {
+            InnerProjection::create_perspective_hmd(
+                fov_y.as_f64(),
+                aspect.as_f64(),
+                near.as_f64(),
+                far.as_f64(),
+                flip_fov,
+                eye as i64,
+                iod.as_f64(),
+                convergence.as_f64(),
+            )
+        },
+    );
+}
@@ -4,8 +4,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
-use godot::builtin::Quaternion;
+use crate::framework::{itest, Differential, EdgeCases, Gen, TestRng};
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{real, Basis, EulerOrder, Quaternion, RealConv, Vector3, Vector4};
 
 #[itest]
 fn quaternion_default() {
@@ -27,4 +28,217 @@ fn quaternion_from_xyzw() {
     assert_eq!(quat.w, 0.8924);
 }
 
-// TODO more tests
+#[itest]
+fn quaternion_spherical_cubic_interpolate() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let from = rng.quaternion();
+        let to = rng.quaternion();
+        let pre = rng.quaternion();
+        let post = rng.quaternion();
+        let weight = rng.real(0.0, 1.0);
+
+        let rust_res = from.spherical_cubic_interpolate(to, pre, post, weight);
+        let godot_res = from
+            .as_inner()
+            .spherical_cubic_interpolate(to, pre, post, weight.as_f64());
+
+        assert_eq_approx!(
+            rust_res,
+            godot_res,
+            "from={from} to={to} pre={pre} post={post} weight={weight}"
+        );
+    }
+}
+
+#[itest]
+fn quaternion_spherical_cubic_interpolate_in_time() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let from = rng.quaternion();
+        let to = rng.quaternion();
+        let pre = rng.quaternion();
+        let post = rng.quaternion();
+        let weight = rng.real(0.0, 1.0);
+        let to_t = rng.real(0.5, 2.0);
+        let pre_t = rng.real(-2.0, -0.5);
+        let post_t = rng.real(2.5, 4.0);
+
+        let rust_res =
+            from.spherical_cubic_interpolate_in_time(to, pre, post, weight, to_t, pre_t, post_t);
+        let godot_res = from.as_inner().spherical_cubic_interpolate_in_time(
+            to,
+            pre,
+            post,
+            weight.as_f64(),
+            to_t.as_f64(),
+            pre_t.as_f64(),
+            post_t.as_f64(),
+        );
+
+        assert_eq_approx!(
+            rust_res,
+            godot_res,
+            "from={from} to={to} pre={pre} post={post} weight={weight} to_t={to_t} pre_t={pre_t} post_t={post_t}"
+        );
+    }
+}
+
+#[itest]
+fn quaternion_from_vectors() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let from = rng.unit_vector3();
+        let to = rng.unit_vector3();
+
+        let quat = Quaternion::from_vectors(from, to);
+
+        assert!(quat.is_normalized(), "from={from} to={to}");
+        assert_eq_approx!(Basis::from_quat(quat) * from, to, "from={from} to={to}");
+    }
+
+    // Opposite vectors fall back to a rotation around the Y axis.
+    let quat = Quaternion::from_vectors(Vector3::RIGHT, Vector3::LEFT);
+    assert_eq!(quat, Quaternion::new(0.0, 1.0, 0.0, 0.0));
+}
+
+#[itest]
+fn quaternion_differential() {
+    fn any_quaternion(gen: &mut Gen) -> Quaternion {
+        let v = gen.any::<Vector4>();
+        Quaternion::new(v.x, v.y, v.z, v.w)
+    }
+
+    Differential::new("Quaternion::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            any_quaternion,
+            |q| q.is_finite(),
+            |q| q.as_inner().is_finite(),
+        );
+
+    Differential::new("Quaternion::length")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            any_quaternion,
+            |q| q.length(),
+            |q| real::from_f64(q.as_inner().length()),
+        );
+
+    Differential::new("Quaternion::length_squared")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            any_quaternion,
+            |q| q.length_squared(),
+            |q| real::from_f64(q.as_inner().length_squared()),
+        );
+
+    Differential::new("Quaternion::normalized")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            any_quaternion,
+            |q| q.normalized(),
+            |q| q.as_inner().normalized(),
+        );
+
+    Differential::new("Quaternion::is_normalized").check(
+        |gen| {
+            if gen.bool() {
+                gen.rotation()
+            } else {
+                any_quaternion(gen)
+            }
+        },
+        |q| q.is_normalized(),
+        |q| q.as_inner().is_normalized(),
+    );
+
+    Differential::new("Quaternion::dot")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (any_quaternion(gen), any_quaternion(gen)),
+            |(a, b)| a.dot(*b),
+            |(a, b)| real::from_f64(a.as_inner().dot(*b)),
+        );
+
+    Differential::new("Quaternion::inverse").check(
+        |gen| gen.any::<Quaternion>(),
+        |q| q.inverse(),
+        |q| q.as_inner().inverse(),
+    );
+
+    Differential::new("Quaternion::log").check(
+        |gen| gen.any::<Quaternion>(),
+        |q| q.log(),
+        |q| q.as_inner().log(),
+    );
+
+    Differential::new("Quaternion::to_exp").check(
+        |gen| {
+            let v = gen.rng().vector3(-3.0, 3.0);
+            Quaternion::new(v.x, v.y, v.z, 0.0)
+        },
+        |q| q.to_exp(),
+        |q| q.as_inner().exp(),
+    );
+
+    Differential::new("Quaternion::get_axis").check(
+        |gen| gen.any::<Quaternion>(),
+        |q| q.get_axis(),
+        |q| q.as_inner().get_axis(),
+    );
+
+    Differential::new("Quaternion::get_angle").check(
+        |gen| gen.any::<Quaternion>(),
+        |q| q.get_angle(),
+        |q| real::from_f64(q.as_inner().get_angle()),
+    );
+
+    Differential::new("Quaternion::angle_to").check(
+        |gen| (gen.any::<Quaternion>(), gen.any::<Quaternion>()),
+        |(a, b)| a.angle_to(*b),
+        |(a, b)| real::from_f64(a.as_inner().angle_to(*b)),
+    );
+
+    Differential::new("Quaternion::slerp").check(
+        |gen| {
+            (
+                gen.any::<Quaternion>(),
+                gen.any::<Quaternion>(),
+                gen.rng().real(0.0, 1.0),
+            )
+        },
+        |&(a, b, weight)| a.slerp(b, weight),
+        |(a, b, weight)| a.as_inner().slerp(*b, weight.as_f64()),
+    );
+
+    Differential::new("Quaternion::slerpni").check(
+        |gen| {
+            (
+                gen.any::<Quaternion>(),
+                gen.any::<Quaternion>(),
+                gen.rng().real(0.0, 1.0),
+            )
+        },
+        |&(a, b, weight)| a.slerpni(b, weight),
+        |(a, b, weight)| a.as_inner().slerpni(*b, weight.as_f64()),
+    );
+
+    for order in [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ] {
+        Differential::new(format!("Quaternion::to_euler({order:?})")).check(
+            |gen| gen.any::<Quaternion>(),
+            |q| q.to_euler(order),
+            |q| q.as_inner().get_euler(order as i64),
+        );
+    }
+}
@@ -3,11 +3,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::framework::itest;
+use crate::framework::{itest, Differential, EdgeCases};
 
 use godot::builtin::inner::InnerRect2;
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{real, reals, RealConv, Rect2, RectSide, Vector2};
+use godot::builtin::{real, reals, RealConv, Rect2, RectSide, Vector2, Vector4};
 
 #[itest]
 fn rect2_inner_equivalence() {
@@ -94,3 +94,186 @@ fn rect2_inner_equivalence() {
         }
     }
 }
+
+#[itest]
+fn rect2_intersection_touching() {
+    let a = Rect2::new(Vector2::ZERO, Vector2::new(2.0, 2.0));
+    let right = Rect2::new(Vector2::new(2.0, 0.0), Vector2::new(2.0, 2.0));
+    let corner = Rect2::new(Vector2::new(2.0, 2.0), Vector2::new(1.0, 1.0));
+
+    // Like in Godot, rectangles that only share an edge or corner have no intersection.
+    for b in [right, corner] {
+        assert_eq!(a.intersection(b), None, "b={b}");
+        assert_eq!(InnerRect2::from_outer(&a).intersection(b), Rect2::default());
+    }
+
+    let overlapping = Rect2::new(Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0));
+    assert_eq!(
+        a.intersection(overlapping),
+        Some(Rect2::new(Vector2::new(1.0, 1.0), Vector2::new(1.0, 1.0)))
+    );
+}
+
+#[itest]
+fn rect2_rounding_like_godot() {
+    // 0.5 - 0.4 rounds to less than 0.1, but the point lies on the (exclusive) end edge.
+    let rect = Rect2::new(Vector2::new(0.4, 0.4), Vector2::new(0.1, 0.1));
+    let point = Vector2::new(0.5, 0.5);
+    assert!(!rect.has_point(point));
+    assert_eq!(
+        rect.has_point(point),
+        InnerRect2::from_outer(&rect).has_point(point)
+    );
+
+    // Margins are summed before they are added to the size.
+    let rect = Rect2::new(Vector2::ZERO, Vector2::ONE);
+    let margin = real::EPSILON * 0.5;
+    assert_eq!(
+        rect.grow_individual(margin, margin, margin, margin),
+        InnerRect2::from_outer(&rect).grow_individual(
+            margin.as_f64(),
+            margin.as_f64(),
+            margin.as_f64(),
+            margin.as_f64()
+        )
+    );
+}
+
+#[itest]
+fn rect2_differential() {
+    Differential::new("Rect2::abs")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| Rect2::new(gen.any(), gen.any()),
+            |r| r.abs(),
+            |r| InnerRect2::from_outer(r).abs(),
+        );
+
+    Differential::new("Rect2::area")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Rect2>(),
+            |r| r.area(),
+            |r| real::from_f64(InnerRect2::from_outer(r).get_area()),
+        );
+
+    Differential::new("Rect2::center")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Rect2>(),
+            |r| r.center(),
+            |r| InnerRect2::from_outer(r).get_center(),
+        );
+
+    Differential::new("Rect2::has_area")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Rect2>(),
+            |r| r.has_area(),
+            |r| InnerRect2::from_outer(r).has_area(),
+        );
+
+    Differential::new("Rect2::has_point")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Vector2>()),
+            |(r, point)| r.has_point(*point),
+            |(r, point)| InnerRect2::from_outer(r).has_point(*point),
+        );
+
+    Differential::new("Rect2::encloses")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Rect2>()),
+            |(a, b)| a.encloses(*b),
+            |(a, b)| InnerRect2::from_outer(a).encloses(*b),
+        );
+
+    Differential::new("Rect2::intersects")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Rect2>()),
+            |(a, b)| a.intersects(*b),
+            |(a, b)| InnerRect2::from_outer(a).intersects(*b, true),
+        );
+
+    Differential::new("Rect2::intersects_exclude_borders")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Rect2>()),
+            |(a, b)| a.intersects_exclude_borders(*b),
+            |(a, b)| InnerRect2::from_outer(a).intersects(*b, false),
+        );
+
+    Differential::new("Rect2::intersection")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Rect2>()),
+            |(a, b)| a.intersection(*b).unwrap_or_default(),
+            |(a, b)| InnerRect2::from_outer(a).intersection(*b),
+        );
+
+    Differential::new("Rect2::merge")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Rect2>()),
+            |(a, b)| a.merge(*b),
+            |(a, b)| InnerRect2::from_outer(a).merge(*b),
+        );
+
+    Differential::new("Rect2::expand")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Vector2>()),
+            |(r, to)| r.expand(*to),
+            |(r, to)| InnerRect2::from_outer(r).expand(*to),
+        );
+
+    Differential::new("Rect2::grow")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.real()),
+            |&(r, amount)| r.grow(amount),
+            |(r, amount)| InnerRect2::from_outer(r).grow(amount.as_f64()),
+        );
+
+    Differential::new("Rect2::grow_individual")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2>(), gen.any::<Vector4>()),
+            |&(r, m)| r.grow_individual(m.x, m.y, m.z, m.w),
+            |(r, m)| {
+                InnerRect2::from_outer(r).grow_individual(
+                    m.x.as_f64(),
+                    m.y.as_f64(),
+                    m.z.as_f64(),
+                    m.w.as_f64(),
+                )
+            },
+        );
+
+    Differential::new("Rect2::grow_side")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| {
+                const SIDES: [RectSide; 4] = [
+                    RectSide::Left,
+                    RectSide::Top,
+                    RectSide::Right,
+                    RectSide::Bottom,
+                ];
+                let side = SIDES[gen.rng().next_u64() as usize % SIDES.len()];
+                (gen.any::<Rect2>(), side, gen.real())
+            },
+            |&(r, side, amount)| r.grow_side(side, amount),
+            |(r, side, amount)| InnerRect2::from_outer(r).grow_side(*side as i64, amount.as_f64()),
+        );
+
+    Differential::new("Rect2::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Rect2>(),
+            |r| r.is_finite(),
+            |r| InnerRect2::from_outer(r).is_finite(),
+        );
+}
@@ -5,7 +5,7 @@
  */
 use std::fmt::Debug;
 
-use crate::framework::itest;
+use crate::framework::{itest, Differential, EdgeCases};
 use godot::prelude::{inner::InnerRect2i, *};
 
 #[itest]
@@ -90,3 +90,131 @@ fn rect2i_equiv_unary() {
         }
     }
 }
+
+#[itest]
+fn rect2i_differential() {
+    Differential::new("Rect2i::abs")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| Rect2i::new(gen.any(), gen.any()),
+            |r| r.abs(),
+            |r| InnerRect2i::from_outer(r).abs(),
+        );
+
+    Differential::new("Rect2i::area")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Rect2i>(),
+            |r| r.area() as i64,
+            |r| InnerRect2i::from_outer(r).get_area(),
+        );
+
+    Differential::new("Rect2i::center")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| gen.any::<Rect2i>(),
+            |r| r.center(),
+            |r| InnerRect2i::from_outer(r).get_center(),
+        );
+
+    Differential::new("Rect2i::has_area")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| Rect2i::new(gen.any(), gen.any()),
+            |r| r.has_area(),
+            |r| InnerRect2i::from_outer(r).has_area(),
+        );
+
+    Differential::new("Rect2i::contains_point")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.any::<Vector2i>()),
+            |(r, point)| r.contains_point(*point),
+            |(r, point)| InnerRect2i::from_outer(r).has_point(*point),
+        );
+
+    Differential::new("Rect2i::encloses")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.any::<Rect2i>()),
+            |(a, b)| a.encloses(*b),
+            |(a, b)| InnerRect2i::from_outer(a).encloses(*b),
+        );
+
+    Differential::new("Rect2i::intersects")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.any::<Rect2i>()),
+            |(a, b)| a.intersects(*b),
+            |(a, b)| InnerRect2i::from_outer(a).intersects(*b),
+        );
+
+    Differential::new("Rect2i::intersection")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.any::<Rect2i>()),
+            |(a, b)| a.intersection(*b).unwrap_or_default(),
+            |(a, b)| InnerRect2i::from_outer(a).intersection(*b),
+        );
+
+    Differential::new("Rect2i::merge")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.any::<Rect2i>()),
+            |(a, b)| a.merge(*b),
+            |(a, b)| InnerRect2i::from_outer(a).merge(*b),
+        );
+
+    Differential::new("Rect2i::expand")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.any::<Vector2i>()),
+            |(r, to)| r.expand(*to),
+            |(r, to)| InnerRect2i::from_outer(r).expand(*to),
+        );
+
+    Differential::new("Rect2i::grow")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Rect2i>(), gen.int()),
+            |&(r, amount)| r.grow(amount),
+            |(r, amount)| InnerRect2i::from_outer(r).grow(*amount as i64),
+        );
+
+    Differential::new("Rect2i::grow_individual")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| {
+                (
+                    gen.any::<Rect2i>(),
+                    [gen.int(), gen.int(), gen.int(), gen.int()],
+                )
+            },
+            |&(r, [left, top, right, bottom])| r.grow_individual(left, top, right, bottom),
+            |(r, [left, top, right, bottom])| {
+                InnerRect2i::from_outer(r).grow_individual(
+                    *left as i64,
+                    *top as i64,
+                    *right as i64,
+                    *bottom as i64,
+                )
+            },
+        );
+
+    Differential::new("Rect2i::grow_side")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| {
+                const SIDES: [RectSide; 4] = [
+                    RectSide::Left,
+                    RectSide::Top,
+                    RectSide::Right,
+                    RectSide::Bottom,
+                ];
+                let side = SIDES[gen.rng().next_u64() as usize % SIDES.len()];
+                (gen.any::<Rect2i>(), side, gen.int())
+            },
+            |&(r, side, amount)| r.grow_side(side, amount),
+            |(r, side, amount)| InnerRect2i::from_outer(r).grow_side(*side as i64, *amount as i64),
+        );
+}
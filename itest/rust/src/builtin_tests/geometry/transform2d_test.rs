@@ -3,8 +3,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::framework::itest;
+use crate::framework::{itest, Differential, EdgeCases, TestRng};
 
+use godot::builtin::real_consts::PI;
 use godot::prelude::{inner::InnerTransform2D, *};
 use godot::private::class_macros::assert_eq_approx;
 
@@ -90,3 +91,170 @@ fn transform2d_xform_equiv() {
         "operator: Transform2D * Rect2 (2)"
     );
 }
+
+#[itest]
+fn transform2d_looking_at_equiv() {
+    let mut rng = TestRng::new();
+
+    for _ in 0..100 {
+        let transform = Transform2D::from_angle_scale_skew_origin(
+            rng.real(-PI, PI),
+            rng.vector2(0.5, 2.0),
+            rng.real(-0.5, 0.5),
+            rng.vector2(-10.0, 10.0),
+        );
+        let target = rng.vector2(-10.0, 10.0);
+
+        assert_eq_approx!(
+            transform.looking_at(target),
+            InnerTransform2D::from_outer(&transform).looking_at(target),
+            "transform={transform} target={target}"
+        );
+    }
+}
+
+#[itest]
+fn transform2d_basis_xform_inv_scaled() {
+    // Godot multiplies with the transposed basis, which is only the inverse for orthonormal bases.
+    let transform = Transform2D::from_angle_scale_skew_origin(
+        0.6,
+        Vector2::new(2.0, 0.5),
+        0.3,
+        Vector2::new(1.0, 2.0),
+    );
+    let vec = Vector2::new(3.0, -4.0);
+
+    assert_eq_approx!(
+        transform.basis_xform_inv(vec),
+        InnerTransform2D::from_outer(&transform).basis_xform_inv(vec)
+    );
+    assert_ne!(
+        transform.basis_xform_inv(vec),
+        transform.affine_inverse().basis_xform(vec)
+    );
+}
+
+#[itest]
+fn transform2d_differential() {
+    Differential::new("Transform2D::affine_inverse").check(
+        |gen| gen.any::<Transform2D>(),
+        |t| t.affine_inverse(),
+        |t| InnerTransform2D::from_outer(t).affine_inverse(),
+    );
+
+    Differential::new("Transform2D::rotation").check(
+        |gen| gen.any::<Transform2D>(),
+        |t| t.rotation(),
+        |t| real::from_f64(InnerTransform2D::from_outer(t).get_rotation()),
+    );
+
+    Differential::new("Transform2D::scale").check(
+        |gen| gen.any::<Transform2D>(),
+        |t| t.scale(),
+        |t| InnerTransform2D::from_outer(t).get_scale(),
+    );
+
+    Differential::new("Transform2D::skew").check(
+        |gen| gen.any::<Transform2D>(),
+        |t| t.skew(),
+        |t| real::from_f64(InnerTransform2D::from_outer(t).get_skew()),
+    );
+
+    Differential::new("Transform2D::interpolate_with").check(
+        |gen| {
+            (
+                gen.any::<Transform2D>(),
+                gen.any::<Transform2D>(),
+                gen.weight(),
+            )
+        },
+        |&(from, to, weight)| from.interpolate_with(to, weight),
+        |(from, to, weight)| {
+            InnerTransform2D::from_outer(from).interpolate_with(*to, weight.as_f64())
+        },
+    );
+
+    Differential::new("Transform2D::orthonormalized").check(
+        |gen| gen.any::<Transform2D>(),
+        |t| t.orthonormalized(),
+        |t| InnerTransform2D::from_outer(t).orthonormalized(),
+    );
+
+    Differential::new("Transform2D::looking_at").check(
+        |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+        |&(t, target)| t.looking_at(target),
+        |(t, target)| InnerTransform2D::from_outer(t).looking_at(*target),
+    );
+
+    Differential::new("Transform2D::rotated")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.real()),
+            |&(t, angle)| t.rotated(angle),
+            |(t, angle)| InnerTransform2D::from_outer(t).rotated(angle.as_f64()),
+        );
+
+    Differential::new("Transform2D::rotated_local")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.real()),
+            |&(t, angle)| t.rotated_local(angle),
+            |(t, angle)| InnerTransform2D::from_outer(t).rotated_local(angle.as_f64()),
+        );
+
+    Differential::new("Transform2D::scaled")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+            |&(t, scale)| t.scaled(scale),
+            |(t, scale)| InnerTransform2D::from_outer(t).scaled(*scale),
+        );
+
+    Differential::new("Transform2D::scaled_local")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+            |&(t, scale)| t.scaled_local(scale),
+            |(t, scale)| InnerTransform2D::from_outer(t).scaled_local(*scale),
+        );
+
+    Differential::new("Transform2D::translated")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+            |&(t, offset)| t.translated(offset),
+            |(t, offset)| InnerTransform2D::from_outer(t).translated(*offset),
+        );
+
+    Differential::new("Transform2D::translated_local")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+            |&(t, offset)| t.translated_local(offset),
+            |(t, offset)| InnerTransform2D::from_outer(t).translated_local(*offset),
+        );
+
+    Differential::new("Transform2D::basis_xform")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+            |&(t, v)| t.basis_xform(v),
+            |(t, v)| InnerTransform2D::from_outer(t).basis_xform(*v),
+        );
+
+    Differential::new("Transform2D::basis_xform_inv")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform2D>(), gen.any::<Vector2>()),
+            |&(t, v)| t.basis_xform_inv(v),
+            |(t, v)| InnerTransform2D::from_outer(t).basis_xform_inv(*v),
+        );
+
+    Differential::new("Transform2D::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Transform2D>(),
+            |t| t.is_finite(),
+            |t| InnerTransform2D::from_outer(t).is_finite(),
+        );
+}
@@ -3,7 +3,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::framework::itest;
+use crate::framework::{itest, Differential, EdgeCases, Gen};
 
 use godot::prelude::{inner::InnerTransform3D, *};
 use godot::private::class_macros::assert_eq_approx;
@@ -78,3 +78,116 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_differential() {
+    fn invertible(gen: &mut Gen) -> Transform3D {
+        Transform3D::new(gen.scaled_rotation(), gen.any())
+    }
+
+    Differential::new("Transform3D::affine_inverse").check(
+        invertible,
+        |t| t.affine_inverse(),
+        |t| InnerTransform3D::from_outer(t).affine_inverse(),
+    );
+
+    Differential::new("Transform3D::orthonormalized").check(
+        invertible,
+        |t| t.orthonormalized(),
+        |t| InnerTransform3D::from_outer(t).orthonormalized(),
+    );
+
+    Differential::new("Transform3D::interpolate_with").check(
+        |gen| (invertible(gen), invertible(gen), gen.weight()),
+        |&(from, to, weight)| from.interpolate_with(to, weight),
+        |(from, to, weight)| {
+            InnerTransform3D::from_outer(from).interpolate_with(*to, weight.as_f64())
+        },
+    );
+
+    Differential::new("Transform3D::rotated")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform3D>(), gen.unit_vector3(), gen.real()),
+            |&(t, axis, angle)| t.rotated(axis, angle),
+            |(t, axis, angle)| InnerTransform3D::from_outer(t).rotated(*axis, angle.as_f64()),
+        );
+
+    Differential::new("Transform3D::rotated_local")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform3D>(), gen.unit_vector3(), gen.real()),
+            |&(t, axis, angle)| t.rotated_local(axis, angle),
+            |(t, axis, angle)| InnerTransform3D::from_outer(t).rotated_local(*axis, angle.as_f64()),
+        );
+
+    Differential::new("Transform3D::scaled")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform3D>(), gen.any::<Vector3>()),
+            |&(t, scale)| t.scaled(scale),
+            |(t, scale)| InnerTransform3D::from_outer(t).scaled(*scale),
+        );
+
+    Differential::new("Transform3D::scaled_local")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform3D>(), gen.any::<Vector3>()),
+            |&(t, scale)| t.scaled_local(scale),
+            |(t, scale)| InnerTransform3D::from_outer(t).scaled_local(*scale),
+        );
+
+    Differential::new("Transform3D::translated")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform3D>(), gen.any::<Vector3>()),
+            |&(t, offset)| t.translated(offset),
+            |(t, offset)| InnerTransform3D::from_outer(t).translated(*offset),
+        );
+
+    Differential::new("Transform3D::translated_local")
+        .edge_cases(EdgeCases::Degenerate)
+        .check(
+            |gen| (gen.any::<Transform3D>(), gen.any::<Vector3>()),
+            |&(t, offset)| t.translated_local(offset),
+            |(t, offset)| InnerTransform3D::from_outer(t).translated_local(*offset),
+        );
+
+    #[cfg(before_api = "4.1")]
+    Differential::new("Transform3D::looking_at").check(
+        |gen| {
+            let t = invertible(gen);
+            let target = gen.any_where(
+                |gen| gen.any::<Vector3>(),
+                |&target| (target - t.origin).length() > 0.1,
+            );
+            (t, target, gen.unit_vector3())
+        },
+        |&(t, target, up)| t.looking_at(target, up),
+        |(t, target, up)| InnerTransform3D::from_outer(t).looking_at(*target, *up),
+    );
+
+    #[cfg(since_api = "4.1")]
+    Differential::new("Transform3D::looking_at").check(
+        |gen| {
+            let t = invertible(gen);
+            let target = gen.any_where(
+                |gen| gen.any::<Vector3>(),
+                |&target| (target - t.origin).length() > 0.1,
+            );
+            (t, target, gen.unit_vector3(), gen.bool())
+        },
+        |&(t, target, up, use_model_front)| t.looking_at(target, up, use_model_front),
+        |(t, target, up, use_model_front)| {
+            InnerTransform3D::from_outer(t).looking_at(*target, *up, *use_model_front)
+        },
+    );
+
+    Differential::new("Transform3D::is_finite")
+        .edge_cases(EdgeCases::NonFinite)
+        .check(
+            |gen| gen.any::<Transform3D>(),
+            |t| t.is_finite(),
+            |t| InnerTransform3D::from_outer(t).is_finite(),
+        );
+}
@@ -5,7 +5,9 @@
  */
 
 mod geometry {
+    mod aabb_test;
     mod basis_test;
+    mod geometry_test;
     mod plane_test;
     mod projection_test;
     mod quaternion_test;
@@ -20,6 +22,7 @@ mod containers {
     mod array_test;
     mod callable_test;
     mod dictionary_test;
+    mod packed_array_test;
     mod rid_test;
     mod signal_test;
     mod variant_test;
@@ -32,3 +35,5 @@ mod string {
 }
 
 mod color_test;
+#[cfg(feature = "serde")]
+mod serde_test;
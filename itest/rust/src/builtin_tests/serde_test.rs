@@ -0,0 +1,165 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Debug;
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{
+    array, dict, varray, Aabb, Array, Basis, Callable, Color, Dictionary, GString, NodePath,
+    PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt64Array, PackedStringArray,
+    PackedVector2Array, Projection, Quaternion, Rect2i, Rid, StringName, Transform3D, Variant,
+    VariantType, Vector2, Vector3, Vector4i,
+};
+use godot::engine::{load, Node, PackedScene, Resource};
+use godot::obj::Gd;
+use serde::{Deserialize, Serialize};
+
+use crate::framework::{itest, suppress_godot_print};
+
+fn roundtrip<T>(value: &T, expected_json: &str)
+where
+    T: for<'a> Deserialize<'a> + Serialize + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let back: T = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(back, *value, "serde round-trip changes value");
+    assert_eq!(
+        json, expected_json,
+        "value does not conform to expected JSON"
+    );
+}
+
+#[itest]
+fn serde_strings() {
+    roundtrip(&GString::from("hello \"world\""), r#""hello \"world\"""#);
+    roundtrip(&StringName::from("name"), r#""name""#);
+    roundtrip(
+        &NodePath::from("../Sibling:position"),
+        r#""../Sibling:position""#,
+    );
+}
+
+#[itest]
+fn serde_array() {
+    roundtrip(&array![1, 2, 3], "[1,2,3]");
+    roundtrip(&Array::<GString>::new(), "[]");
+    roundtrip(&varray![1, "two"], r#"[{"Int":1},{"String":"two"}]"#);
+
+    let typed: Array<i64> = serde_json::from_str("[4,5]").unwrap();
+    assert_eq!(typed, array![4, 5]);
+    assert!(serde_json::from_str::<Array<i64>>(r#"["four"]"#).is_err());
+}
+
+#[itest]
+fn serde_dictionary() {
+    roundtrip(&Dictionary::new(), "[]");
+    roundtrip(
+        &dict! { "key": 1, 2: true },
+        r#"[[{"String":"key"},{"Int":1}],[{"Int":2},{"Bool":true}]]"#,
+    );
+}
+
+#[itest]
+fn serde_packed_arrays() {
+    roundtrip(&PackedByteArray::from(&[1, 2, 255]), "[1,2,255]");
+    roundtrip(&PackedInt64Array::from(&[-1, 0, 1]), "[-1,0,1]");
+    roundtrip(&PackedFloat32Array::from(&[0.5, 1.0]), "[0.5,1.0]");
+    roundtrip(
+        &PackedStringArray::from(&[GString::from("a"), GString::from("b")]),
+        r#"["a","b"]"#,
+    );
+    roundtrip(
+        &PackedVector2Array::from(&[Vector2::new(1.0, 2.0)]),
+        r#"[{"x":1.0,"y":2.0}]"#,
+    );
+    roundtrip(
+        &PackedColorArray::from(&[Color::from_rgba(1.0, 0.5, 0.0, 1.0)]),
+        r#"[{"r":1.0,"g":0.5,"b":0.0,"a":1.0}]"#,
+    );
+}
+
+#[itest]
+fn serde_variant_json() {
+    roundtrip(&Variant::nil(), r#""Nil""#);
+    roundtrip(&true.to_variant(), r#"{"Bool":true}"#);
+    roundtrip(&(-7).to_variant(), r#"{"Int":-7}"#);
+    roundtrip(&2.5.to_variant(), r#"{"Float":2.5}"#);
+    roundtrip(&"text".to_variant(), r#"{"String":"text"}"#);
+    roundtrip(
+        &StringName::from("name").to_variant(),
+        r#"{"StringName":"name"}"#,
+    );
+    roundtrip(
+        &Vector2::new(1.0, -2.0).to_variant(),
+        r#"{"Vector2":{"x":1.0,"y":-2.0}}"#,
+    );
+    roundtrip(
+        &PackedByteArray::from(&[7]).to_variant(),
+        r#"{"PackedByteArray":[7]}"#,
+    );
+    roundtrip(&Rid::new(42).to_variant(), r#"{"Rid":42}"#);
+}
+
+#[itest]
+fn serde_variant_all_types() {
+    let values = [
+        Vector3::new(1.0, 2.0, 3.0).to_variant(),
+        Vector4i::new(1, -2, 3, -4).to_variant(),
+        Rect2i::default().to_variant(),
+        Aabb::default().to_variant(),
+        Quaternion::default().to_variant(),
+        Basis::IDENTITY.to_variant(),
+        Transform3D::IDENTITY.to_variant(),
+        Projection::IDENTITY.to_variant(),
+        Color::from_rgba(0.25, 0.5, 0.75, 1.0).to_variant(),
+        NodePath::from("A/B").to_variant(),
+        varray![1, varray!["nested"], dict! { "inner": 3 }].to_variant(),
+        dict! { 1: Vector2::ZERO, "b": varray![] }.to_variant(),
+        PackedInt64Array::from(&[1, 2]).to_variant(),
+        PackedStringArray::from(&[GString::from("x")]).to_variant(),
+    ];
+
+    for value in values {
+        let json = serde_json::to_string(&value).unwrap();
+        let back: Variant = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back.get_type(), value.get_type(), "{json}");
+        assert_eq!(back, value, "{json}");
+    }
+}
+
+#[itest]
+fn serde_variant_resource() {
+    let scene = load::<PackedScene>("res://TestRunner.tscn");
+    let variant = scene.to_variant();
+
+    let json = serde_json::to_string(&variant).unwrap();
+    assert_eq!(json, r#"{"Object":"res://TestRunner.tscn"}"#);
+
+    let back: Variant = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.get_type(), VariantType::Object);
+    assert_eq!(
+        back.to::<Gd<Resource>>().get_path(),
+        GString::from("res://TestRunner.tscn")
+    );
+
+    suppress_godot_print(|| {
+        let missing = serde_json::from_str::<Variant>(r#"{"Object":"res://missing.tres"}"#);
+        assert!(missing.is_err());
+    });
+}
+
+#[itest]
+fn serde_variant_unsupported() {
+    let node = Node::new_alloc();
+    let err = serde_json::to_string(&node.to_variant()).unwrap_err();
+    assert!(err.to_string().contains("Node"), "{err}");
+    node.free();
+
+    assert!(serde_json::to_string(&Callable::invalid().to_variant()).is_err());
+    assert!(serde_json::from_str::<Variant>(r#"{"Unknown":1}"#).is_err());
+}
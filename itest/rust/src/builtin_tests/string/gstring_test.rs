@@ -7,7 +7,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::{dict, varray, GString};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -81,6 +81,27 @@ fn string_chars() {
     assert_eq!(godot_string_chars, string_chars);
 }
 
+#[itest]
+fn string_chars_iter() {
+    let string = GString::from("añ😎");
+    let chars: Vec<char> = string.chars().collect();
+
+    assert_eq!(chars, vec!['a', 'ñ', '😎']);
+    assert_eq!(GString::new().chars().count(), 0);
+}
+
+#[itest]
+fn string_str_equality() {
+    let string = GString::from("emoji 😎");
+
+    assert_eq!(string, "emoji 😎");
+    assert_eq!("emoji 😎", string);
+    assert_eq!(string, *"emoji 😎");
+    assert_ne!(string, "emoji");
+    assert_ne!(string, "emoji 😎 ");
+    assert_eq!(GString::new(), "");
+}
+
 #[itest]
 fn string_hash() {
     let set: HashSet<GString> = [
@@ -95,3 +116,99 @@ fn string_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn string_find() {
+    let s = GString::from("Hello, World! Hello!");
+
+    assert_eq!(s.find("Hello"), Some(0));
+    assert_eq!(s.find_from("Hello", 1), Some(14));
+    assert_eq!(s.rfind('o'), Some(18));
+    assert_eq!(s.find("Godot"), None);
+    assert_eq!(s.rfind(String::from("Godot")), None);
+
+    assert!(s.contains(&GString::from("World")));
+    assert!(!s.contains("world"));
+    assert!(s.starts_with("Hello,"));
+    assert!(s.ends_with('!'));
+    assert_eq!(s.count("Hello"), 2);
+}
+
+#[itest]
+fn string_find_counts_chars() {
+    // Indices refer to characters, not UTF-8 bytes.
+    let s = GString::from("äöü-xyz");
+
+    assert_eq!(s.len(), 7);
+    assert_eq!(s.find('-'), Some(3));
+    assert_eq!(s.substr(4..), GString::from("xyz"));
+}
+
+#[itest]
+fn string_split_replace() {
+    let s = GString::from("a,b,,c");
+
+    let parts: Vec<String> = s.split(',').to_vec().iter().map(String::from).collect();
+    assert_eq!(parts, vec!["a", "b", "", "c"]);
+
+    assert_eq!(s.replace(",,", ","), GString::from("a,b,c"));
+    assert_eq!(s.replace(',', ""), GString::from("abc"));
+}
+
+#[itest]
+fn string_substr() {
+    let s = GString::from("abcdef");
+
+    assert_eq!(s.substr(1..3), GString::from("bc"));
+    assert_eq!(s.substr(1..=3), GString::from("bcd"));
+    assert_eq!(s.substr(..2), GString::from("ab"));
+    assert_eq!(s.substr(..), s);
+    assert_eq!(s.substr(3..3), GString::new());
+}
+
+#[itest]
+fn string_case_trim_format() {
+    let s = GString::from("  Mixed Case\t\n");
+
+    assert_eq!(s.trim(), GString::from("Mixed Case"));
+    assert_eq!(s.trim().to_upper(), GString::from("MIXED CASE"));
+    assert_eq!(s.trim().to_lower(), GString::from("mixed case"));
+
+    let template = GString::from("{name} has {hp} HP");
+    let formatted = template.format(dict! { "name": "Player", "hp": 42 });
+    assert_eq!(formatted, GString::from("Player has 42 HP"));
+
+    let template = GString::from("{0} + {1}");
+    assert_eq!(template.format(varray![1, 2]), GString::from("1 + 2"));
+}
+
+#[itest]
+fn string_path_helpers() {
+    let path = GString::from("res://sprites/icon.png");
+
+    assert_eq!(path.extension(), GString::from("png"));
+    assert_eq!(path.base_name(), GString::from("res://sprites/icon"));
+    assert_eq!(path.base_dir(), GString::from("res://sprites"));
+    assert_eq!(path.file_name(), GString::from("icon.png"));
+    assert!(path.is_absolute_path());
+    assert!(!path.is_relative_path());
+
+    let dir = GString::from("res://sprites");
+    assert_eq!(dir.path_join("icon.png"), path);
+    assert!(GString::from("sprites/icon.png").is_relative_path());
+}
+
+#[itest]
+fn string_number_parsing() {
+    assert!(GString::from("-12").is_valid_int());
+    assert!(!GString::from("12abc").is_valid_int());
+    assert!(!GString::from("").is_valid_int());
+
+    assert_eq!(GString::from("-12").to_int(), Some(-12));
+    assert_eq!(GString::from("+7").to_int(), Some(7));
+    assert_eq!(GString::from("12abc").to_int(), None);
+
+    assert_eq!(GString::from("1.5e3").to_float(), Some(1500.0));
+    assert_eq!(GString::from("7").to_float(), Some(7.0));
+    assert_eq!(GString::from("seven").to_float(), None);
+}
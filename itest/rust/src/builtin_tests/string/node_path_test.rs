@@ -7,7 +7,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::{GString, NodePath};
+use godot::builtin::{GString, NodePath, NodePathError, StringName};
 
 #[itest]
 fn node_path_default() {
@@ -63,3 +63,186 @@ fn node_path_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn node_path_is_empty() {
+    assert!(NodePath::default().is_empty());
+    assert!(!NodePath::from("Player").is_empty());
+}
+
+#[itest]
+fn node_path_as_property_path() {
+    let path = NodePath::from("Sprite:modulate");
+
+    assert_eq!(path.as_property_path(), NodePath::from(":Sprite:modulate"));
+    assert_eq!(NodePath::default().as_property_path(), NodePath::default());
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn node_path_subpath() {
+    let path = NodePath::from("Player/Sprite:modulate:r");
+
+    assert_eq!(path.subpath(1..), NodePath::from("Sprite:modulate:r"));
+    assert_eq!(path.subpath(..2), NodePath::from("Player/Sprite"));
+    assert_eq!(path.subpath(2..=2), NodePath::from(":modulate"));
+}
+
+#[itest]
+fn node_path_as_string_arg() {
+    let path = NodePath::from("Player/Sprite");
+    let string = GString::from("/root/Player/Sprite");
+
+    assert!(string.ends_with(&path));
+    assert_eq!(string.find(path), Some(6));
+}
+
+#[itest]
+fn node_path_str_equality() {
+    let path = NodePath::from("Player/Sprite:position");
+
+    assert_eq!(path, "Player/Sprite:position");
+    assert_eq!("Player/Sprite:position", path);
+    assert_ne!(path, "Player/Sprite");
+}
+
+#[itest]
+fn node_path_introspection() {
+    let path = NodePath::from("../Player/Sprite:modulate:r");
+
+    assert!(!path.is_absolute());
+    assert!(!path.is_empty());
+    assert_eq!(path.names(), ["..", "Player", "Sprite"]);
+    assert_eq!(path.subnames(), ["modulate", "r"]);
+    assert_eq!(path.concatenated_names(), "../Player/Sprite");
+    assert_eq!(path.concatenated_subnames(), "modulate:r");
+
+    let absolute = NodePath::from("/root/Main");
+    assert!(absolute.is_absolute());
+    assert_eq!(absolute.names(), ["root", "Main"]);
+    assert!(absolute.subnames().is_empty());
+
+    assert!(NodePath::default().is_empty());
+}
+
+#[itest]
+fn node_path_builder() {
+    let path = NodePath::builder()
+        .absolute()
+        .name("root")
+        .name("Player")
+        .name(StringName::from("Sprite"))
+        .subname("modulate")
+        .build()
+        .expect("valid path");
+
+    assert_eq!(path, "/root/Player/Sprite:modulate");
+    assert!(path.is_absolute());
+
+    let relative = NodePath::builder()
+        .name("..")
+        .name("%Unique")
+        .build()
+        .expect("valid path");
+    assert_eq!(relative, "../%Unique");
+
+    let err = NodePath::builder().name("Player/Sprite").build();
+    assert_eq!(
+        err,
+        Err(NodePathError::InvalidCharacter {
+            index: 0,
+            name: "Player/Sprite".to_string(),
+            character: '/',
+        })
+    );
+
+    let err = NodePath::builder().name("Player").subname("").build();
+    assert_eq!(err, Err(NodePathError::EmptySubname { index: 0 }));
+}
+
+#[itest]
+fn node_path_parse() {
+    let path = NodePath::parse("Player/Sprite:position:x").expect("valid path");
+    assert_eq!(path.names(), ["Player", "Sprite"]);
+    assert_eq!(path.subnames(), ["position", "x"]);
+
+    assert_eq!(NodePath::parse(""), Ok(NodePath::default()));
+    assert_eq!(NodePath::parse("/"), Ok(NodePath::from("/")));
+    assert_eq!(
+        NodePath::parse(":position"),
+        Ok(NodePath::from(":position"))
+    );
+
+    assert_eq!(
+        NodePath::parse("Player//Sprite"),
+        Err(NodePathError::EmptyName { index: 1 })
+    );
+    assert_eq!(
+        NodePath::parse("Player/"),
+        Err(NodePathError::EmptyName { index: 1 })
+    );
+    assert_eq!(
+        NodePath::parse("Sprite:"),
+        Err(NodePathError::EmptySubname { index: 0 })
+    );
+    assert_eq!(
+        NodePath::parse("Player/Spr@ite"),
+        Err(NodePathError::InvalidCharacter {
+            index: 1,
+            name: "Spr@ite".to_string(),
+            character: '@',
+        })
+    );
+}
+
+#[itest]
+fn node_path_join() {
+    let base = NodePath::from("Level/Player");
+
+    assert_eq!(
+        base.join(&NodePath::from("Sprite")),
+        Ok(NodePath::from("Level/Player/Sprite"))
+    );
+    assert_eq!(
+        base.join(&NodePath::from("../Enemy:position")),
+        Ok(NodePath::from("Level/Player/../Enemy:position"))
+    );
+    assert_eq!(
+        base.join(&NodePath::from("/root/Main")),
+        Ok(NodePath::from("/root/Main"))
+    );
+    assert_eq!(
+        NodePath::default().join(&NodePath::from("Sprite")),
+        Ok(NodePath::from("Sprite"))
+    );
+
+    let property = NodePath::from("Sprite:modulate");
+    assert_eq!(
+        property.join(&NodePath::from(":r")),
+        Ok(NodePath::from("Sprite:modulate:r"))
+    );
+}
+
+#[itest]
+fn node_path_join_error() {
+    let property = NodePath::from("Sprite:modulate");
+
+    assert_eq!(
+        property.join(&NodePath::from("Child")),
+        Err(NodePathError::NameAfterSubname {
+            name: "Child".to_string()
+        })
+    );
+    assert_eq!(
+        NodePath::from(":position").join(&NodePath::from("Child/Grandchild:x")),
+        Err(NodePathError::NameAfterSubname {
+            name: "Child".to_string()
+        })
+    );
+
+    // Absolute paths replace the base instead.
+    assert_eq!(
+        property.join(&NodePath::from("/root/Child")),
+        Ok(NodePath::from("/root/Child"))
+    );
+}
@@ -4,10 +4,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::{GString, NodePath, StringName};
+use godot::builtin::{sname, Callable, GString, NodePath, StringName};
+use godot::engine::Node;
 
 #[itest]
 fn string_name_default() {
@@ -57,17 +59,42 @@ fn string_name_equality() {
     assert_ne!(string, different);
 }
 
-// TODO: add back in when ordering StringNames is fixed
-#[itest(skip)]
+#[itest]
 fn string_name_ordering() {
-    let _low = StringName::from("Alpha");
-    let _high = StringName::from("Beta");
-    /*
+    let low = StringName::from("Alpha");
+    let high = StringName::from("Beta");
+
     assert!(low < high);
     assert!(low <= high);
     assert!(high > low);
     assert!(high >= low);
-     */
+    assert_eq!(low.cmp(&StringName::from("Alpha")), Ordering::Equal);
+
+    let mut names: Vec<StringName> = ["gamma", "alpha", "beta"].map(StringName::from).to_vec();
+    names.sort();
+    assert_eq!(names, ["alpha", "beta", "gamma"]);
+}
+
+#[itest]
+fn string_name_str_equality() {
+    let name = StringName::from("ui_accept");
+
+    assert_eq!(name, "ui_accept");
+    assert_eq!("ui_accept", name);
+    assert_ne!(name, "ui_cancel");
+    assert_ne!(name, "ui_accept ");
+    assert_eq!(StringName::default(), "");
+}
+
+#[itest]
+fn string_name_hash_consistent_with_eq() {
+    let set: HashSet<StringName> = ["idle", "run", "idle"]
+        .into_iter()
+        .map(StringName::from)
+        .collect();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&StringName::from("run")));
 }
 
 #[itest]
@@ -136,3 +163,42 @@ fn string_name_from_latin1_with_nul() {
         assert_eq!(a, b);
     }
 }
+
+#[itest]
+fn string_name_string_api() {
+    let name = StringName::from("player_health");
+
+    assert_eq!(name.len(), 13);
+    assert!(!name.is_empty());
+    assert_eq!(name.find('_'), Some(6));
+    assert_eq!(name.find("mana"), None);
+    assert!(name.starts_with("player"));
+    assert!(name.ends_with(&StringName::from("health")));
+    assert_eq!(name.to_upper(), GString::from("PLAYER_HEALTH"));
+    assert_eq!(name.replace('_', " "), GString::from("player health"));
+}
+
+#[itest]
+fn string_name_static_literal() {
+    let names: Vec<StringName> = (0..3).map(|_| sname!("static_name")).collect();
+
+    for name in &names {
+        assert_eq!(name, &StringName::from("static_name"));
+        assert_eq!(name, "static_name");
+    }
+
+    // Different call sites with the same literal refer to the same interned string.
+    assert_eq!(sname!("static_name"), names[0]);
+    assert_ne!(sname!("other_name"), names[0]);
+}
+
+#[itest]
+fn string_name_static_literal_as_argument() {
+    let node = Node::new_alloc();
+    assert!(node.has_method(sname!("queue_free")));
+
+    let callable = Callable::from_object_method(node.clone(), sname!("get_name"));
+    assert_eq!(callable.method_name(), Some(sname!("get_name")));
+
+    node.free();
+}
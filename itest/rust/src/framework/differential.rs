@@ -0,0 +1,571 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Differential testing of the Rust re-implementations of builtin types (see design note in `godot::builtin`) against the engine.
+//
+// A check generates random inputs, feeds each one to both the Rust method and its `Inner*` counterpart, and collects all inputs for
+// which the results differ beyond `ApproxEq` tolerance. Panics on the Rust side are caught and reported as mismatches as well.
+// Generation is deterministic (fixed seed), so every reported input can be reproduced.
+
+use std::any::Any;
+use std::fmt::{self, Debug};
+use std::panic;
+
+use godot::builtin::math::{ApproxEq, FloatExt};
+use godot::builtin::{
+    real, Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D,
+    Transform3D, Vector2, Vector2i, Vector3, Vector3Axis, Vector3i, Vector4,
+};
+use godot::engine::Engine;
+
+use super::TestRng;
+
+/// How many iterations a check runs by default.
+const DEFAULT_ITERATIONS: usize = 200;
+
+/// How many mismatches are listed in the failure message; the rest is only counted.
+const MAX_REPORTED_MISMATCHES: usize = 5;
+
+/// Roughly every n-th generated scalar is an edge case, if enabled.
+const EDGE_CASE_PERIOD: u64 = 8;
+
+/// Which kinds of edge cases the input generator mixes into regular values.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub enum EdgeCases {
+    /// Only regular values in moderate ranges.
+    None,
+
+    /// Zero, negative zero, ±1, values near `CMP_EPSILON` and large magnitudes.
+    Degenerate,
+
+    /// Everything from `Degenerate`, plus infinities and NaN.
+    NonFinite,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Input generation
+
+/// Input generator passed to the closures of [`Differential::check()`].
+pub struct Gen {
+    rng: TestRng,
+    edge_cases: EdgeCases,
+}
+
+impl Gen {
+    fn new(seed: u64, edge_cases: EdgeCases) -> Self {
+        Self {
+            rng: TestRng::with_seed(seed),
+            edge_cases,
+        }
+    }
+
+    /// Access to the underlying random generator, for values that should never be edge cases.
+    pub fn rng(&mut self) -> &mut TestRng {
+        &mut self.rng
+    }
+
+    /// Generates an arbitrary value of type `T`, respecting the configured edge cases.
+    pub fn any<T: Arbitrary>(&mut self) -> T {
+        T::arbitrary(self)
+    }
+
+    /// Generates values with `generate` until one satisfies `predicate`.
+    ///
+    /// Use this for preconditions of the method under test, e.g. invertible matrices.
+    pub fn any_where<T>(
+        &mut self,
+        mut generate: impl FnMut(&mut Gen) -> T,
+        predicate: impl Fn(&T) -> bool,
+    ) -> T {
+        for _ in 0..1000 {
+            let value = generate(self);
+            if predicate(&value) {
+                return value;
+            }
+        }
+
+        panic!("predicate rejected 1000 generated inputs in a row; generator and precondition do not match");
+    }
+
+    /// Scalar in `[-100, 100)`, or an edge case.
+    pub fn real(&mut self) -> real {
+        self.edge_real()
+            .unwrap_or_else(|| self.rng.real(-100.0, 100.0))
+    }
+
+    /// Non-negative scalar in `[0, 100)`, or a non-negative edge case.
+    pub fn size(&mut self) -> real {
+        self.real().abs()
+    }
+
+    /// Interpolation weight in `[0, 1)`, or an edge case.
+    pub fn weight(&mut self) -> real {
+        self.edge_real().unwrap_or_else(|| self.rng.real(0.0, 1.0))
+    }
+
+    /// Integer in `[-1000, 1000)`, or 0/±1 as degenerate values.
+    pub fn int(&mut self) -> i32 {
+        if self.edge_cases >= EdgeCases::Degenerate && self.is_edge_case() {
+            const EDGES: [i32; 3] = [0, 1, -1];
+            return EDGES[self.rng.next_u64() as usize % EDGES.len()];
+        }
+
+        (self.rng.next_u64() % 2000) as i32 - 1000
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.rng.bool()
+    }
+
+    /// Unit vector; never an edge case, as most APIs require normalized vectors.
+    pub fn unit_vector3(&mut self) -> Vector3 {
+        self.rng.unit_vector3()
+    }
+
+    /// Normalized quaternion representing a random rotation.
+    pub fn rotation(&mut self) -> Quaternion {
+        self.rng.quaternion()
+    }
+
+    /// Rotation combined with a positive, non-uniform scale. Always invertible.
+    pub fn scaled_rotation(&mut self) -> Basis {
+        let scale = Vector3::new(
+            self.rng.real(0.1, 10.0),
+            self.rng.real(0.1, 10.0),
+            self.rng.real(0.1, 10.0),
+        );
+
+        Basis::from_quat(self.rotation()).scaled(scale)
+    }
+
+    fn is_edge_case(&mut self) -> bool {
+        self.rng.next_u64() % EDGE_CASE_PERIOD == 0
+    }
+
+    fn edge_real(&mut self) -> Option<real> {
+        const DEGENERATE: [real; 8] = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            real::CMP_EPSILON,
+            -real::CMP_EPSILON,
+            1e-10,
+            1e6,
+        ];
+        const NON_FINITE: [real; 3] = [real::INFINITY, real::NEG_INFINITY, real::NAN];
+
+        let candidates: &[real] = match self.edge_cases {
+            EdgeCases::None => return None,
+            EdgeCases::Degenerate => &DEGENERATE,
+            EdgeCases::NonFinite if self.rng.bool() => &NON_FINITE,
+            EdgeCases::NonFinite => &DEGENERATE,
+        };
+
+        if self.is_edge_case() {
+            Some(candidates[self.rng.next_u64() as usize % candidates.len()])
+        } else {
+            None
+        }
+    }
+}
+
+/// Types that can be randomly generated as inputs for differential checks.
+pub trait Arbitrary {
+    fn arbitrary(gen: &mut Gen) -> Self;
+}
+
+impl Arbitrary for real {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        gen.real()
+    }
+}
+
+impl Arbitrary for i32 {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        gen.int()
+    }
+}
+
+impl Arbitrary for Vector2 {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Vector2::new(gen.real(), gen.real())
+    }
+}
+
+impl Arbitrary for Vector3 {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Vector3::new(gen.real(), gen.real(), gen.real())
+    }
+}
+
+impl Arbitrary for Vector4 {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Vector4::new(gen.real(), gen.real(), gen.real(), gen.real())
+    }
+}
+
+impl Arbitrary for Vector2i {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Vector2i::new(gen.int(), gen.int())
+    }
+}
+
+impl Arbitrary for Quaternion {
+    /// Normalized rotation; with edge cases enabled, occasionally the identity or a sign-flipped rotation.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        let quat = gen.rotation();
+
+        if gen.edge_cases >= EdgeCases::Degenerate && gen.is_edge_case() {
+            if gen.bool() {
+                Quaternion::default()
+            } else {
+                -quat
+            }
+        } else {
+            quat
+        }
+    }
+}
+
+impl Arbitrary for Basis {
+    /// Mostly scaled rotations; with edge cases enabled, also arbitrary (possibly singular) matrices.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        if gen.edge_cases >= EdgeCases::Degenerate && gen.rng.bool() {
+            Basis::from_cols(gen.any(), gen.any(), gen.any())
+        } else {
+            gen.scaled_rotation()
+        }
+    }
+}
+
+impl Arbitrary for Transform2D {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        let angle = gen.real();
+        let scale = gen.any();
+        let skew = gen.rng.real(-1.0, 1.0);
+        let origin = gen.any();
+
+        Transform2D::from_angle_scale_skew_origin(angle, scale, skew, origin)
+    }
+}
+
+impl Arbitrary for Transform3D {
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Transform3D::new(gen.any(), gen.any())
+    }
+}
+
+impl Arbitrary for Plane {
+    /// Plane with normalized normal, as required by most plane operations.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Plane::new(gen.unit_vector3(), gen.real())
+    }
+}
+
+impl Arbitrary for Rect2 {
+    /// Rectangle with non-negative size.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        Rect2::new(gen.any(), Vector2::new(gen.size(), gen.size()))
+    }
+}
+
+impl Arbitrary for Rect2i {
+    /// Rectangle with non-negative size.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        let size = Vector2i::new(gen.int().abs(), gen.int().abs());
+        Rect2i::new(gen.any(), size)
+    }
+}
+
+impl Arbitrary for Aabb {
+    /// Box with non-negative size.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        let size = Vector3::new(gen.size(), gen.size(), gen.size());
+        Aabb::new(gen.any(), size)
+    }
+}
+
+impl Arbitrary for Projection {
+    /// Mostly typical camera projections (perspective, asymmetric frustum, orthogonal); with edge cases enabled, also
+    /// arbitrary (possibly singular) matrices.
+    fn arbitrary(gen: &mut Gen) -> Self {
+        if gen.edge_cases >= EdgeCases::Degenerate && gen.rng.bool() {
+            return Projection::from_cols(gen.any(), gen.any(), gen.any(), gen.any());
+        }
+
+        let rng = gen.rng();
+        let near = rng.real(0.05, 2.0);
+        let far = near + rng.real(1.0, 100.0);
+        let aspect = rng.real(0.5, 2.0);
+
+        match rng.next_u64() % 3 {
+            0 => Projection::create_perspective(rng.real(30.0, 120.0), aspect, near, far, false),
+            1 => {
+                let size = rng.real(0.5, 4.0);
+                let offset = rng.vector2(-1.0, 1.0);
+                Projection::create_frustum_aspect(size, aspect, offset, near, far, false)
+            }
+            _ => {
+                let size = rng.real(1.0, 10.0);
+                Projection::create_orthogonal_aspect(size, aspect, near, far, false)
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Result comparison
+
+/// Equality used to compare Rust and engine results.
+///
+/// Floating-point types use [`ApproxEq`]; additionally, two values are considered equal if they have the same representation, so that
+/// NaN and infinity components in the same places count as a match.
+pub trait DiffEq: Debug {
+    fn diff_eq(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_diff_eq_approx {
+    ($($Ty:ty),* $(,)?) => {
+        $(
+            impl DiffEq for $Ty {
+                fn diff_eq(&self, other: &Self) -> bool {
+                    self.approx_eq(other) || format!("{self:?}") == format!("{other:?}")
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_diff_eq_exact {
+    ($($Ty:ty),* $(,)?) => {
+        $(
+            impl DiffEq for $Ty {
+                fn diff_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_diff_eq_approx!(
+    real,
+    Vector2,
+    Vector3,
+    Vector4,
+    Quaternion,
+    Basis,
+    Transform2D,
+    Transform3D,
+    Projection,
+    Plane,
+    Rect2,
+    Aabb,
+    Color,
+);
+
+impl_diff_eq_exact!(bool, i32, i64, Vector2i, Vector3i, Vector3Axis, Rect2i);
+
+impl<T: DiffEq> DiffEq for Option<T> {
+    fn diff_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.diff_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: DiffEq> DiffEq for Vec<T> {
+    fn diff_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.diff_eq(b))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Runner
+
+/// Compares a Rust implementation against its engine counterpart over many generated inputs.
+///
+/// ```ignore
+/// Differential::new("Basis::transposed").check(
+///     |gen| gen.any::<Basis>(),
+///     |basis| basis.transposed(),
+///     |basis| InnerBasis::from_outer(basis).transposed(),
+/// );
+/// ```
+pub struct Differential {
+    name: String,
+    iterations: usize,
+    edge_cases: EdgeCases,
+    seed: u64,
+}
+
+impl Differential {
+    /// New check with default iteration count, no edge cases and a fixed seed.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            iterations: DEFAULT_ITERATIONS,
+            edge_cases: EdgeCases::None,
+            seed: TestRng::DEFAULT_SEED,
+        }
+    }
+
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Which edge cases to mix into generated inputs. Only enable those that are within the method's contract.
+    pub fn edge_cases(mut self, edge_cases: EdgeCases) -> Self {
+        self.edge_cases = edge_cases;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Runs the check and panics with a report of all mismatching inputs, if any.
+    pub fn check<I, R>(
+        self,
+        mut generate: impl FnMut(&mut Gen) -> I,
+        rust: impl Fn(&I) -> R,
+        godot: impl Fn(&I) -> R,
+    ) where
+        I: Debug,
+        R: DiffEq,
+    {
+        let mut gen = Gen::new(self.seed, self.edge_cases);
+
+        let outcome = {
+            // Edge cases may legitimately cause engine error messages; Rust panics are reported below instead of printed.
+            let _quiet = QuietGuard::new();
+
+            // Also catch panics outside the Rust method (generator, engine side), so the guard is not dropped while unwinding.
+            panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                let mut mismatches = Vec::new();
+
+                for _ in 0..self.iterations {
+                    let input = generate(&mut gen);
+                    let godot_result = godot(&input);
+                    let rust_result = panic::catch_unwind(panic::AssertUnwindSafe(|| rust(&input)));
+
+                    match rust_result {
+                        Ok(rust_result) if rust_result.diff_eq(&godot_result) => {}
+                        Ok(rust_result) => mismatches.push(Mismatch {
+                            input: format!("{input:?}"),
+                            rust: format!("{rust_result:?}"),
+                            godot: format!("{godot_result:?}"),
+                        }),
+                        Err(payload) => mismatches.push(Mismatch {
+                            input: format!("{input:?}"),
+                            rust: format!("panic: {}", panic_message(&*payload)),
+                            godot: format!("{godot_result:?}"),
+                        }),
+                    }
+                }
+
+                mismatches
+            }))
+        };
+
+        let mismatches = outcome.unwrap_or_else(|payload| panic::resume_unwind(payload));
+
+        if !mismatches.is_empty() {
+            panic!("{}", self.report(&mismatches));
+        }
+    }
+
+    fn report(&self, mismatches: &[Mismatch]) -> String {
+        let mut report = format!(
+            "{name}: {count} of {total} inputs differ between Rust and Godot (seed {seed:#x})",
+            name = self.name,
+            count = mismatches.len(),
+            total = self.iterations,
+            seed = self.seed,
+        );
+
+        for mismatch in mismatches.iter().take(MAX_REPORTED_MISMATCHES) {
+            report.push_str(&format!("\n{mismatch}"));
+        }
+
+        if mismatches.len() > MAX_REPORTED_MISMATCHES {
+            let remaining = mismatches.len() - MAX_REPORTED_MISMATCHES;
+            report.push_str(&format!("\n  ... and {remaining} more"));
+        }
+
+        report
+    }
+}
+
+/// Silences the panic hook and engine error messages, until dropped.
+///
+/// Restores the previous hook and print setting on drop, also if a generator or the engine side panics.
+struct QuietGuard {
+    restore_hook: Option<Box<dyn FnOnce()>>,
+    prev_print_error_messages: bool,
+}
+
+impl QuietGuard {
+    fn new() -> Self {
+        let prev_hook = panic::take_hook();
+        panic::set_hook(Box::new(|_panic_info| {}));
+
+        let mut engine = Engine::singleton();
+        let prev_print_error_messages = engine.is_printing_error_messages();
+        engine.set_print_error_messages(false);
+
+        Self {
+            restore_hook: Some(Box::new(move || panic::set_hook(prev_hook))),
+            prev_print_error_messages,
+        }
+    }
+}
+
+impl Drop for QuietGuard {
+    fn drop(&mut self) {
+        Engine::singleton().set_print_error_messages(self.prev_print_error_messages);
+
+        // Panic hooks cannot be changed during unwinding.
+        if std::thread::panicking() {
+            return;
+        }
+
+        if let Some(restore_hook) = self.restore_hook.take() {
+            restore_hook();
+        }
+    }
+}
+
+struct Mismatch {
+    input: String,
+    rust: String,
+    godot: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "  input: {}\n    rust:  {}\n    godot: {}",
+            self.input, self.rust, self.godot
+        )
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "(non-string payload)".to_string()
+    }
+}
@@ -10,9 +10,13 @@ use godot::sys;
 use std::collections::HashSet;
 
 mod bencher;
+mod differential;
+mod random;
 mod runner;
 
 pub use bencher::*;
+pub use differential::*;
+pub use random::*;
 pub use runner::*;
 
 /// Allow re-import as `crate::framework::itest`.
@@ -0,0 +1,91 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Small deterministic random generator for tests that compare Rust implementations against Godot over many inputs.
+// We deliberately avoid a dependency like `rand`: reproducibility matters more than statistical quality, and a fixed
+// seed makes every failure reproducible with the same inputs.
+
+use godot::builtin::{real, real_consts::PI, Quaternion, Vector2, Vector3};
+
+/// Pseudo-random generator based on SplitMix64.
+#[derive(Clone, Debug)]
+pub struct TestRng {
+    state: u64,
+}
+
+impl TestRng {
+    /// Default seed, so that test runs are reproducible.
+    pub const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+    pub fn new() -> Self {
+        Self::with_seed(Self::DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed value in `[0, 1)`.
+    pub fn unit(&mut self) -> f64 {
+        // Use the upper 53 bits, which fit exactly into the f64 mantissa.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniformly distributed value in `[min, max)`.
+    pub fn real(&mut self, min: real, max: real) -> real {
+        min + (max - min) * self.unit() as real
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn vector2(&mut self, min: real, max: real) -> Vector2 {
+        Vector2::new(self.real(min, max), self.real(min, max))
+    }
+
+    pub fn vector3(&mut self, min: real, max: real) -> Vector3 {
+        Vector3::new(
+            self.real(min, max),
+            self.real(min, max),
+            self.real(min, max),
+        )
+    }
+
+    /// Random unit vector; never zero.
+    pub fn unit_vector3(&mut self) -> Vector3 {
+        loop {
+            let v = self.vector3(-1.0, 1.0);
+            let length = v.length();
+            if length > 0.01 && length <= 1.0 {
+                return v / length;
+            }
+        }
+    }
+
+    /// Random rotation, as a normalized quaternion.
+    pub fn quaternion(&mut self) -> Quaternion {
+        let axis = self.unit_vector3();
+        let angle = self.real(-PI, PI);
+
+        Quaternion::from_angle_axis(axis, angle)
+    }
+}
+
+impl Default for TestRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -9,7 +9,7 @@ use std::rc::Rc;
 
 use godot::bind::{godot_api, GodotClass};
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{GString, StringName, Variant, VariantConversionError, Vector3};
+use godot::builtin::{ConversionErrorKind, GString, StringName, Variant, Vector3};
 use godot::engine::{
     file_access, Area2D, Camera3D, FileAccess, IRefCounted, Node, Node3D, Object, RefCounted,
 };
@@ -451,8 +451,8 @@ fn object_engine_convert_variant_nil() {
     let nil = Variant::nil();
 
     assert_eq!(
-        Gd::<Area2D>::try_from_variant(&nil),
-        Err(VariantConversionError::BadValue),
+        Gd::<Area2D>::try_from_variant(&nil).map_err(|err| err.kind()),
+        Err(ConversionErrorKind::BadValue),
         "try_from_variant(&nil)"
     );
 
@@ -5,7 +5,7 @@
  */
 
 use godot::{
-    bind::property::PropertyHintInfo,
+    bind::property::{FlagSet, PropertyHintInfo},
     engine::{
         global::{PropertyHint, PropertyUsageFlags},
         Texture,
@@ -14,6 +14,8 @@ use godot::{
     test::itest,
 };
 
+use crate::framework::suppress_godot_print;
+
 // No tests currently, tests using these classes are in Godot scripts.
 
 #[derive(GodotClass)]
@@ -366,6 +368,118 @@ fn derive_export() {
     );
 }
 
+#[derive(Property, Export, Debug, PartialEq, Eq)]
+#[godot(via = GString)]
+pub enum StringEnum {
+    Sword,
+    Bow,
+}
+
+#[repr(u32)]
+#[derive(Property, Export, Debug, PartialEq, Eq)]
+#[godot(flags)]
+pub enum FlagsEnum {
+    Fire = 1,
+    Water = 1 << 1,
+    Steam = 0b11,
+    Earth = 1 << 2,
+}
+
+#[derive(GodotClass)]
+pub struct DeriveExportRepr {
+    #[export]
+    pub weapon: StringEnum,
+
+    #[export]
+    pub element: FlagsEnum,
+
+    #[export]
+    pub elements: FlagSet<FlagsEnum>,
+
+    #[base]
+    pub base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl DeriveExportRepr {}
+
+#[godot_api]
+impl IRefCounted for DeriveExportRepr {
+    fn init(base: godot::obj::Base<Self::Base>) -> Self {
+        Self {
+            weapon: StringEnum::Sword,
+            element: FlagsEnum::Water,
+            elements: FlagSet::empty(),
+            base,
+        }
+    }
+}
+
+#[itest]
+fn derive_export_repr() {
+    let mut class: Gd<DeriveExportRepr> = Gd::new_default();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "weapon".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::String as i32);
+    check_property(&property, "hint", PropertyHint::PROPERTY_HINT_ENUM.ord());
+    check_property(&property, "hint_string", "Sword,Bow");
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "element".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::Int as i32);
+    check_property(&property, "hint", PropertyHint::PROPERTY_HINT_FLAGS.ord());
+    check_property(&property, "hint_string", "Fire:1,Water:2,Steam:3,Earth:4");
+
+    assert_eq!(class.get("weapon".into()), "Sword".to_variant());
+    class.set("weapon".into(), "Bow".to_variant());
+    assert_eq!(class.bind().weapon, StringEnum::Bow);
+
+    assert_eq!(class.get("element".into()), 2.to_variant());
+    class.set("element".into(), 3.to_variant());
+    assert_eq!(class.bind().element, FlagsEnum::Steam);
+
+    // Values without a matching variant keep the previous one.
+    suppress_godot_print(|| {
+        class.set("weapon".into(), "Axe".to_variant());
+        class.set("element".into(), 0.to_variant());
+        class.set("element".into(), 5.to_variant());
+    });
+    assert_eq!(class.bind().weapon, StringEnum::Bow);
+    assert_eq!(class.bind().element, FlagsEnum::Steam);
+}
+
+#[itest]
+fn derive_export_flag_set() {
+    let mut class: Gd<DeriveExportRepr> = Gd::new_default();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "elements".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::Int as i32);
+    check_property(&property, "hint", PropertyHint::PROPERTY_HINT_FLAGS.ord());
+    check_property(&property, "hint_string", "Fire:1,Water:2,Steam:3,Earth:4");
+
+    // Fire | Earth has no variant, but is kept by the set.
+    class.set("elements".into(), 5.to_variant());
+    assert_eq!(
+        class.bind().elements,
+        FlagSet::from(FlagsEnum::Fire) | FlagsEnum::Earth
+    );
+    assert_eq!(class.get("elements".into()), 5.to_variant());
+
+    class.bind_mut().elements.insert(FlagsEnum::Water);
+    assert_eq!(class.get("elements".into()), 7.to_variant());
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -449,6 +563,157 @@ fn export_resource() {
     class.free();
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportUsagePresets {
+    #[export(storage)]
+    pub storage: i64,
+
+    #[var(no_editor)]
+    pub no_editor: i64,
+
+    #[export(range = (0.0, 10.0), read_only)]
+    pub read_only: f64,
+
+    #[export(editor_only)]
+    pub editor_only: GString,
+}
+
+#[godot_api]
+impl ExportUsagePresets {}
+
+#[itest]
+fn export_usage_presets() {
+    let class: Gd<ExportUsagePresets> = Gd::new_default();
+    let property_list = class.get_property_list();
+    let find_property = |name: &str| {
+        property_list
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find_property("storage");
+    check_property(&property, "type", VariantType::Int as i32);
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::PROPERTY_USAGE_STORAGE.ord(),
+    );
+
+    let property = find_property("no_editor");
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::PROPERTY_USAGE_NO_EDITOR.ord(),
+    );
+
+    let property = find_property("read_only");
+    check_property(&property, "hint", PropertyHint::PROPERTY_HINT_RANGE.ord());
+    check_property(&property, "hint_string", "0,10");
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::PROPERTY_USAGE_DEFAULT.ord()
+            | PropertyUsageFlags::PROPERTY_USAGE_READ_ONLY.ord(),
+    );
+
+    let property = find_property("editor_only");
+    check_property(&property, "type", VariantType::String as i32);
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::PROPERTY_USAGE_EDITOR.ord(),
+    );
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportArrayElements {
+    #[export(range = (0.0, 1.0), each)]
+    pub weights: Array<f64>,
+
+    #[export(enum = (Small, Medium, Large = 5), each)]
+    pub sizes: Array<i64>,
+
+    #[export(range = (0.0, 10.0, or_greater), each)]
+    pub packed: PackedFloat32Array,
+
+    #[export]
+    pub resources: Array<Gd<CustomResource>>,
+}
+
+#[godot_api]
+impl ExportArrayElements {}
+
+#[itest]
+fn export_array_elements() {
+    let class: Gd<ExportArrayElements> = Gd::new_default();
+    let property_list = class.get_property_list();
+    let find_property = |name: &str| {
+        property_list
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let type_string_hint = PropertyHint::PROPERTY_HINT_TYPE_STRING.ord();
+
+    let property = find_property("weights");
+    check_property(&property, "type", VariantType::Array as i32);
+    check_property(&property, "hint", type_string_hint);
+    check_property(
+        &property,
+        "hint_string",
+        format!(
+            "{}/{}:0,1",
+            VariantType::Float as i32,
+            PropertyHint::PROPERTY_HINT_RANGE.ord()
+        ),
+    );
+
+    let property = find_property("sizes");
+    check_property(&property, "hint", type_string_hint);
+    check_property(
+        &property,
+        "hint_string",
+        format!(
+            "{}/{}:Small,Medium,Large:5",
+            VariantType::Int as i32,
+            PropertyHint::PROPERTY_HINT_ENUM.ord()
+        ),
+    );
+
+    let property = find_property("packed");
+    check_property(&property, "type", VariantType::PackedFloat32Array as i32);
+    check_property(&property, "hint", type_string_hint);
+    check_property(
+        &property,
+        "hint_string",
+        format!(
+            "{}/{}:0,10,or_greater",
+            VariantType::Float as i32,
+            PropertyHint::PROPERTY_HINT_RANGE.ord()
+        ),
+    );
+
+    let property = find_property("resources");
+    check_property(&property, "hint", type_string_hint);
+    check_property(
+        &property,
+        "hint_string",
+        format!(
+            "{}/{}:CustomResource",
+            VariantType::Object as i32,
+            PropertyHint::PROPERTY_HINT_RESOURCE_TYPE.ord()
+        ),
+    );
+
+    class.free();
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
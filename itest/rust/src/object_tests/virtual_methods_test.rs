@@ -22,7 +22,7 @@ use godot::engine::{
     InputEvent, InputEventAction, Node, Node2D, PrimitiveMesh, RefCounted, ResourceFormatLoader,
     ResourceLoader, Viewport, Window,
 };
-use godot::obj::{Base, Gd};
+use godot::obj::{Base, EditorAware, Gd};
 use godot::private::class_macros::assert_eq_approx;
 
 /// Simple class, that deliberately has no constructor accessible from GDScript
@@ -206,6 +206,31 @@ impl IResourceFormatLoader for FormatLoaderTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass, Debug)]
+#[class(tool, init, base=Node2D)]
+struct EditorOnlyTest {
+    #[base]
+    base: Base<Node2D>,
+    editor_tree_enters: i32,
+    ready_in_game: i32,
+}
+
+#[godot_api]
+impl INode2D for EditorOnlyTest {
+    #[godot_api(editor)]
+    fn enter_tree(&mut self) {
+        self.editor_tree_enters += 1;
+    }
+
+    fn ready(&mut self) {
+        if self.is_in_game() {
+            self.ready_in_game += 1;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(Eq, PartialEq, Debug)]
 enum ReceivedEvent {
     Notification(NodeNotification),
@@ -325,6 +350,24 @@ fn test_tree_enters_exits(test_context: &TestContext) {
     assert_eq!(obj.bind().tree_exits, 1);
 }
 
+#[itest]
+fn test_editor_only_callbacks(test_context: &TestContext) {
+    let obj = Gd::<EditorOnlyTest>::new_default();
+    assert!(!obj.bind().is_in_editor());
+    assert!(<EditorOnlyTest as EditorAware>::IS_TOOL);
+    assert!(!<IReadyTest as EditorAware>::IS_TOOL);
+
+    let mut test_node = test_context.scene_tree.clone();
+    test_node.add_child(obj.clone().upcast());
+
+    // Tests run in-game, so the editor-only enter_tree() must not be called, while regular callbacks are.
+    assert_eq!(obj.bind().editor_tree_enters, 0);
+    assert_eq!(obj.bind().ready_in_game, 1);
+
+    test_node.remove_child(obj.clone().upcast());
+    obj.free();
+}
+
 #[itest]
 fn test_virtual_method_with_return() {
     let obj = Gd::<IReturnTest>::new_default();
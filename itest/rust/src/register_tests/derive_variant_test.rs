@@ -6,9 +6,10 @@
 
 use std::fmt::Debug;
 
-use godot::bind::{FromGodot, GodotConvert, ToGodot};
+use godot::bind::property::FlagSet;
+use godot::bind::{FromGodot, GodotConvert, Property, ToGodot};
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Variant};
+use godot::builtin::{dict, varray, ConversionErrorKind, Dictionary, Variant, VariantType};
 
 use crate::common::roundtrip;
 use crate::framework::itest;
@@ -264,3 +265,143 @@ fn struct_with_skip_from_variant() {
         )
     );
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Enums with custom representation
+
+#[derive(Debug, PartialEq, ToGodot, FromGodot, GodotConvert)]
+#[godot(via = GString)]
+enum EnumViaGString {
+    First,
+    Second,
+}
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, ToGodot, FromGodot, GodotConvert, Property)]
+#[godot(flags)]
+enum EnumFlags {
+    A = 1,
+    B = 1 << 1,
+    AB = 0b11,
+    C = 1 << 2,
+}
+
+#[itest]
+fn enum_via_gstring() {
+    roundtrip(EnumViaGString::First);
+    roundtrip(EnumViaGString::Second);
+
+    assert_eq!(EnumViaGString::Second.to_variant(), "Second".to_variant());
+    assert_eq!(
+        EnumViaGString::try_from_variant(&"Third".to_variant()).map_err(|err| err.kind()),
+        Err(godot::builtin::ConversionErrorKind::BadValue)
+    );
+}
+
+#[itest]
+fn enum_flags() {
+    roundtrip(EnumFlags::A);
+    roundtrip(EnumFlags::B);
+    roundtrip(EnumFlags::AB);
+
+    assert_eq!(EnumFlags::B.to_variant(), 2.to_variant());
+    assert_eq!(EnumFlags::from_variant(&3.to_variant()), EnumFlags::AB);
+    assert_eq!(
+        EnumFlags::try_from_variant(&5.to_variant()).map_err(|err| err.kind()),
+        Err(godot::builtin::ConversionErrorKind::BadValue)
+    );
+}
+
+#[itest]
+fn enum_flag_set() {
+    // A | C has no variant, but can be held by a FlagSet.
+    let set = FlagSet::from(EnumFlags::A) | EnumFlags::C;
+    roundtrip(set);
+    roundtrip(FlagSet::<EnumFlags>::empty());
+    roundtrip(FlagSet::<EnumFlags>::from_bits(0b1000_0111));
+
+    assert_eq!(set.to_variant(), 5.to_variant());
+    assert_eq!(FlagSet::<EnumFlags>::from_variant(&5.to_variant()), set);
+    assert!(set.contains(EnumFlags::A));
+    assert!(set.contains(EnumFlags::C));
+    assert!(!set.contains(EnumFlags::B));
+    assert!(!set.contains(EnumFlags::AB));
+
+    let mut set = set | FlagSet::from(EnumFlags::B);
+    assert!(set.contains(EnumFlags::AB));
+
+    set.remove(EnumFlags::AB);
+    assert_eq!(set, FlagSet::from(EnumFlags::C));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion errors
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct SaveGame {
+    player: SavePlayer,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct SavePlayer {
+    inventory: SaveSlots,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct SaveSlots(SaveItem, SaveItem);
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct SaveItem {
+    count: i32,
+}
+
+fn save_game(second_item: Dictionary) -> Variant {
+    let slots = varray![dict! { "SaveItem": dict! { "count": 1 } }, second_item];
+    let player = dict! { "inventory": dict! { "SaveSlots": slots } };
+
+    dict! { "SaveGame": dict! { "player": dict! { "SavePlayer": player } } }.to_variant()
+}
+
+#[itest]
+fn nested_struct_error_path() {
+    let valid = save_game(dict! { "SaveItem": dict! { "count": 2 } });
+    assert_eq!(
+        SaveGame::from_variant(&valid).player.inventory.1,
+        SaveItem { count: 2 }
+    );
+
+    let invalid = save_game(dict! { "SaveItem": dict! { "count": "many" } });
+    let err = SaveGame::try_from_variant(&invalid).unwrap_err();
+
+    assert_eq!(err.kind(), ConversionErrorKind::BadType);
+    assert_eq!(err.expected_type(), "i32");
+    assert_eq!(err.actual_type(), VariantType::String);
+    assert_eq!(err.value(), "many");
+    assert_eq!(err.path(), "player.inventory[1].count");
+}
+
+#[itest]
+fn nested_struct_missing_field() {
+    let invalid = save_game(dict! { "SaveItem": dict! { "amount": 2 } });
+    let err = SaveGame::try_from_variant(&invalid).unwrap_err();
+
+    assert_eq!(err.kind(), ConversionErrorKind::MissingValue);
+    assert_eq!(err.expected_type(), "i32");
+    assert_eq!(err.path(), "player.inventory[1].count");
+}
+
+#[itest]
+fn enum_error_path() {
+    let variant = dict! { "Enum": dict! { "Named": dict! { "data": 5 } } }.to_variant();
+    let err = Enum::try_from_variant(&variant).unwrap_err();
+
+    assert_eq!(err.kind(), ConversionErrorKind::BadType);
+    assert_eq!(err.path(), "Named.data");
+
+    let variant = dict! { "Enum": "Unknown" }.to_variant();
+    let err = Enum::try_from_variant(&variant).unwrap_err();
+
+    assert_eq!(err.kind(), ConversionErrorKind::BadValue);
+    assert_eq!(err.expected_type(), "Enum");
+    assert_eq!(err.path(), "");
+}
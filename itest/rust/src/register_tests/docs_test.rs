@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Class with doc comments.
+///
+/// Only used to check the generated class reference.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DocumentedClass {
+    /// Current health.
+    #[var]
+    health: i64,
+}
+
+#[godot_api]
+impl DocumentedClass {
+    /// Restores `amount` health.
+    #[func]
+    fn heal(&mut self, amount: i64) -> bool {
+        self.health += amount;
+        true
+    }
+
+    /// Emitted when health reaches zero.
+    #[signal]
+    fn died();
+}
+
+// The XML is assembled independently of the Godot version, so this test also runs before 4.3.
+#[cfg(feature = "register-docs")]
+#[itest]
+fn docs_class_reference_xml() {
+    let docs = godot::private::gather_xml_docs();
+    let xml = docs
+        .iter()
+        .find(|xml| xml.contains(r#"<class name="DocumentedClass" inherits="RefCounted""#))
+        .expect("docs of DocumentedClass are collected");
+
+    assert!(xml.contains("<brief_description>Class with doc comments.</brief_description>"));
+    assert!(xml.contains(r#"<member name="health" type="int">Current health.</member>"#));
+    assert!(xml.contains(concat!(
+        r#"<method name="heal"><return type="bool" /><param index="0" name="amount" type="int" />"#,
+        r#"<description>Restores `amount` health.</description></method>"#,
+    )));
+    assert!(xml.contains(
+        r#"<signal name="died"><description>Emitted when health reaches zero.</description></signal>"#
+    ));
+}